@@ -0,0 +1,331 @@
+use super::FrameResampler;
+use crate::audio_toolkit::constants;
+use anyhow::{anyhow, Context, Result};
+use audiopus::coder::Decoder as OpusDecoder;
+use audiopus::packet::Packet as OpusPacket;
+use audiopus::{Channels as OpusChannels, MutSignals, SampleRate as OpusSampleRate};
+use log::{debug, warn};
+use std::fs::File;
+use std::path::Path;
+use std::time::Duration;
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::{
+    CodecParameters, Decoder, DecoderOptions, CODEC_TYPE_NULL, CODEC_TYPE_OPUS,
+};
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::{FormatOptions, Packet};
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+/// Opus always decodes at 48 kHz
+const OPUS_SAMPLE_RATE: u32 = 48000;
+
+/// Longest Opus frame (120 ms at 48 kHz), per channel
+const OPUS_MAX_FRAME_SAMPLES: usize = 5760;
+
+/// Resampler output frame; only affects how output is batched
+const RESAMPLE_FRAME_DURATION: Duration = Duration::from_millis(30);
+
+/// Decode an audio file (WAV, FLAC, MP3, OGG/Vorbis or OGG/Opus) to 16 kHz mono f32 samples
+///
+/// Multi-channel audio is downmixed and everything is resampled through the
+/// same `FrameResampler` the recorder uses.
+pub fn decode_audio_file<P: AsRef<Path>>(file_path: P) -> Result<Vec<f32>> {
+    let path = file_path.as_ref();
+    let file = File::open(path).with_context(|| format!("Failed to open {:?}", path))?;
+    let stream = MediaSourceStream::new(Box::new(file), Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(extension) = path.extension().and_then(|e| e.to_str()) {
+        hint.with_extension(extension);
+    }
+
+    let probed = symphonia::default::get_probe()
+        .format(
+            &hint,
+            stream,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .map_err(|e| anyhow!("Unsupported audio file {:?}: {}", path, e))?;
+    let mut format = probed.format;
+
+    let track = format
+        .tracks()
+        .iter()
+        .find(|t| t.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or_else(|| anyhow!("No audio track in {:?}", path))?;
+    let track_id = track.id;
+    let mut decoder = PacketDecoder::new(&track.codec_params)?;
+
+    let mut resampler = FrameResampler::new(
+        decoder.sample_rate() as usize,
+        constants::WHISPER_SAMPLE_RATE as usize,
+        RESAMPLE_FRAME_DURATION,
+    );
+    let mut samples = Vec::new();
+
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
+                break
+            }
+            Err(e) => return Err(anyhow!("Failed to read {:?}: {}", path, e)),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let mono = decoder.decode(&packet)?;
+        resampler.push(&mono, |frame| samples.extend_from_slice(frame));
+    }
+    resampler.finish(|frame| samples.extend_from_slice(frame));
+
+    debug!(
+        "Decoded {:?}: {:.1}s of audio",
+        path,
+        samples.len() as f32 / constants::WHISPER_SAMPLE_RATE as f32
+    );
+    Ok(samples)
+}
+
+/// Per-track decoder producing mono samples at the track's sample rate.
+///
+/// Symphonia has no Opus decoder, so Opus packets (demuxed from OGG by
+/// symphonia) go through libopus instead.
+enum PacketDecoder {
+    Symphonia {
+        decoder: Box<dyn Decoder>,
+        sample_rate: u32,
+        buffer: Option<SampleBuffer<f32>>,
+    },
+    Opus {
+        decoder: OpusDecoder,
+        channels: usize,
+        buffer: Vec<f32>,
+        /// Samples per channel still to drop at the start (the Opus pre-skip)
+        skip: usize,
+    },
+}
+
+impl PacketDecoder {
+    fn new(params: &CodecParameters) -> Result<Self> {
+        let channels = params.channels.map(|c| c.count()).unwrap_or(1);
+
+        if params.codec == CODEC_TYPE_OPUS {
+            let opus_channels = match channels {
+                1 => OpusChannels::Mono,
+                2 => OpusChannels::Stereo,
+                n => return Err(anyhow!("Unsupported Opus channel count: {}", n)),
+            };
+            let decoder = OpusDecoder::new(OpusSampleRate::Hz48000, opus_channels)
+                .map_err(|e| anyhow!("Failed to create Opus decoder: {}", e))?;
+            return Ok(PacketDecoder::Opus {
+                decoder,
+                channels,
+                buffer: vec![0.0; OPUS_MAX_FRAME_SAMPLES * channels],
+                skip: params.delay.unwrap_or(0) as usize,
+            });
+        }
+
+        let sample_rate = params
+            .sample_rate
+            .ok_or_else(|| anyhow!("Audio track has no sample rate"))?;
+        let decoder = symphonia::default::get_codecs()
+            .make(params, &DecoderOptions::default())
+            .map_err(|e| anyhow!("Unsupported audio codec: {}", e))?;
+        Ok(PacketDecoder::Symphonia {
+            decoder,
+            sample_rate,
+            buffer: None,
+        })
+    }
+
+    fn sample_rate(&self) -> u32 {
+        match self {
+            PacketDecoder::Symphonia { sample_rate, .. } => *sample_rate,
+            PacketDecoder::Opus { .. } => OPUS_SAMPLE_RATE,
+        }
+    }
+
+    /// Decode one packet to mono samples; corrupt packets yield no samples
+    fn decode(&mut self, packet: &Packet) -> Result<Vec<f32>> {
+        match self {
+            PacketDecoder::Symphonia {
+                decoder, buffer, ..
+            } => {
+                let decoded = match decoder.decode(packet) {
+                    Ok(decoded) => decoded,
+                    Err(SymphoniaError::DecodeError(e)) => {
+                        warn!("Skipping undecodable audio packet: {}", e);
+                        return Ok(Vec::new());
+                    }
+                    Err(e) => return Err(anyhow!("Audio decoding failed: {}", e)),
+                };
+
+                let spec = *decoded.spec();
+                let needed = decoded.capacity() * spec.channels.count();
+                if buffer.as_ref().is_none_or(|b| b.capacity() < needed) {
+                    *buffer = Some(SampleBuffer::new(decoded.capacity() as u64, spec));
+                }
+                let buffer = buffer.as_mut().unwrap();
+                buffer.copy_interleaved_ref(decoded);
+                Ok(downmix_to_mono(buffer.samples(), spec.channels.count()))
+            }
+            PacketDecoder::Opus {
+                decoder,
+                channels,
+                buffer,
+                skip,
+            } => {
+                let packet = OpusPacket::try_from(packet.buf())
+                    .map_err(|e| anyhow!("Invalid Opus packet: {}", e))?;
+                let signals = MutSignals::try_from(&mut buffer[..])
+                    .map_err(|e| anyhow!("Invalid Opus output buffer: {}", e))?;
+                let frames = match decoder.decode_float(Some(packet), signals, false) {
+                    Ok(frames) => frames,
+                    Err(e) => {
+                        warn!("Skipping undecodable Opus packet: {}", e);
+                        return Ok(Vec::new());
+                    }
+                };
+
+                let skipped = (*skip).min(frames);
+                *skip -= skipped;
+                let interleaved = &buffer[skipped * *channels..frames * *channels];
+                Ok(downmix_to_mono(interleaved, *channels))
+            }
+        }
+    }
+}
+
+/// Average interleaved channels into a single channel
+fn downmix_to_mono(interleaved: &[f32], channels: usize) -> Vec<f32> {
+    if channels <= 1 {
+        return interleaved.to_vec();
+    }
+    interleaved
+        .chunks_exact(channels)
+        .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hound::{SampleFormat, WavSpec, WavWriter};
+    use std::path::PathBuf;
+
+    /// Audio file checked in under `tests/fixtures/audio` (see its README)
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/audio")
+            .join(name)
+    }
+
+    /// Write one second of a 440 Hz tone as a 16-bit WAV file
+    fn write_tone(name: &str, sample_rate: u32, channels: u16) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("voyc-{}-{}.wav", name, std::process::id()));
+        let spec = WavSpec {
+            channels,
+            sample_rate,
+            bits_per_sample: 16,
+            sample_format: SampleFormat::Int,
+        };
+        let mut writer = WavWriter::create(&path, spec).unwrap();
+        for i in 0..sample_rate {
+            let t = i as f32 / sample_rate as f32;
+            let value = (t * 440.0 * std::f32::consts::TAU).sin() * 0.5;
+            for _ in 0..channels {
+                writer
+                    .write_sample((value * i16::MAX as f32) as i16)
+                    .unwrap();
+            }
+        }
+        writer.finalize().unwrap();
+        path
+    }
+
+    fn assert_about_seconds(samples: &[f32], seconds: f32) {
+        let expected = (constants::WHISPER_SAMPLE_RATE as f32 * seconds) as usize;
+        let tolerance = expected / 10;
+        assert!(
+            samples.len().abs_diff(expected) < tolerance,
+            "expected ~{} samples, got {}",
+            expected,
+            samples.len()
+        );
+    }
+
+    fn peak(samples: &[f32]) -> f32 {
+        samples.iter().fold(0.0f32, |m, s| m.max(s.abs()))
+    }
+
+    #[test]
+    fn test_downmix_to_mono() {
+        assert_eq!(downmix_to_mono(&[0.5, -0.5, 1.0], 1), vec![0.5, -0.5, 1.0]);
+        assert_eq!(downmix_to_mono(&[0.5, 0.1, 1.0, 0.0], 2), vec![0.3, 0.5]);
+    }
+
+    #[test]
+    fn test_decode_wav_at_target_rate() {
+        let path = write_tone("decode-16k", 16000, 1);
+        let samples = decode_audio_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_about_seconds(&samples, 1.0);
+        let peak = peak(&samples);
+        assert!((peak - 0.5).abs() < 0.05, "peak was {}", peak);
+    }
+
+    #[test]
+    fn test_decode_resamples_and_downmixes() {
+        let path = write_tone("decode-44k-stereo", 44100, 2);
+        let samples = decode_audio_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_about_seconds(&samples, 1.0);
+    }
+
+    #[test]
+    fn test_decode_mp3() {
+        // One second of a tone peaking at 0.5, 48 kHz mono
+        let samples = decode_audio_file(fixture("tone.mp3")).unwrap();
+
+        assert_about_seconds(&samples, 1.0);
+        let peak = peak(&samples);
+        assert!((peak - 0.5).abs() < 0.05, "peak was {}", peak);
+    }
+
+    #[test]
+    fn test_decode_flac_resamples_and_downmixes() {
+        // One second of a 440 Hz tone peaking at 0.5 on both channels, 8 kHz
+        let samples = decode_audio_file(fixture("tone-stereo.flac")).unwrap();
+
+        assert_about_seconds(&samples, 1.0);
+        let peak = peak(&samples);
+        assert!((peak - 0.5).abs() < 0.05, "peak was {}", peak);
+    }
+
+    #[test]
+    fn test_decode_ogg_opus_drops_pre_skip() {
+        // One second of stereo 120 ms and 20 ms frames without payload, which
+        // decode to silence; the header's pre-skip drops the first half
+        let samples = decode_audio_file(fixture("silence-stereo.opus")).unwrap();
+
+        assert_about_seconds(&samples, 0.5);
+        assert_eq!(peak(&samples), 0.0);
+    }
+
+    #[test]
+    fn test_decode_rejects_non_audio() {
+        let path = std::env::temp_dir().join(format!("voyc-not-audio-{}.txt", std::process::id()));
+        std::fs::write(&path, "definitely not audio").unwrap();
+        let result = decode_audio_file(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(result.is_err());
+    }
+}
@@ -1,12 +1,14 @@
 // Re-export all audio components
+mod decoder;
 mod device;
 mod recorder;
 mod resampler;
 mod utils;
 mod visualizer;
 
+pub use decoder::decode_audio_file;
 pub use device::{list_input_devices, list_output_devices, CpalDeviceInfo};
-pub use recorder::AudioRecorder;
+pub use recorder::{AudioRecorder, RecordingOptions};
 pub use resampler::FrameResampler;
-pub use utils::save_wav_file;
+pub use utils::{read_wav_file, save_wav_file};
 pub use visualizer::AudioVisualiser;
@@ -12,13 +12,26 @@ use cpal::{
 use crate::audio_toolkit::{
     audio::{AudioVisualiser, FrameResampler},
     constants,
-    vad::{self, VadFrame},
+    vad::{self, VadFrame, VadParams},
     VoiceActivityDetector,
 };
 
+/// Duration of a single VAD frame produced by the resampler
+const FRAME_DURATION: Duration = Duration::from_millis(30);
+
+/// Per-recording options for [`AudioRecorder::start_with_options`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RecordingOptions {
+    /// Trailing silence after which the end-of-utterance callback fires
+    pub end_of_utterance: Option<Duration>,
+    /// How much new speech to collect before handing it to the speech callback
+    pub speech_interval: Option<Duration>,
+}
+
 enum Cmd {
-    Start,
+    Start(RecordingOptions),
     Stop(mpsc::Sender<Vec<f32>>),
+    UpdateVad(VadParams),
     Shutdown,
 }
 
@@ -28,6 +41,8 @@ pub struct AudioRecorder {
     worker_handle: Option<std::thread::JoinHandle<()>>,
     vad: Option<Arc<Mutex<Box<dyn vad::VoiceActivityDetector>>>>,
     level_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
+    end_of_utterance_cb: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
+    speech_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
 }
 
 impl AudioRecorder {
@@ -38,6 +53,8 @@ impl AudioRecorder {
             worker_handle: None,
             vad: None,
             level_cb: None,
+            end_of_utterance_cb: None,
+            speech_cb: None,
         })
     }
 
@@ -54,6 +71,28 @@ impl AudioRecorder {
         self
     }
 
+    /// Called once per recording when speech has been followed by the configured
+    /// amount of silence (see [`AudioRecorder::start_with_end_of_utterance`]).
+    /// Requires a VAD; without one every frame counts as speech.
+    pub fn with_end_of_utterance_callback<F>(mut self, cb: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.end_of_utterance_cb = Some(Arc::new(cb));
+        self
+    }
+
+    /// Receives the speech kept since the previous call, every `speech_interval`
+    /// of new speech (see [`RecordingOptions`]). Runs on the audio worker, so it
+    /// must hand the samples off rather than process them.
+    pub fn with_speech_callback<F>(mut self, cb: F) -> Self
+    where
+        F: Fn(Vec<f32>) + Send + Sync + 'static,
+    {
+        self.speech_cb = Some(Arc::new(cb));
+        self
+    }
+
     pub fn open(&mut self, device: Option<Device>) -> Result<(), Box<dyn std::error::Error>> {
         if self.worker_handle.is_some() {
             return Ok(()); // already open
@@ -72,8 +111,10 @@ impl AudioRecorder {
 
         let thread_device = device.clone();
         let vad = self.vad.clone();
-        // Move the optional level callback into the worker thread
+        // Move the optional callbacks into the worker thread
         let level_cb = self.level_cb.clone();
+        let end_of_utterance_cb = self.end_of_utterance_cb.clone();
+        let speech_cb = self.speech_cb.clone();
 
         let worker = std::thread::spawn(move || {
             let config = AudioRecorder::get_preferred_config(&thread_device)
@@ -117,7 +158,15 @@ impl AudioRecorder {
             stream.play().expect("failed to start stream");
 
             // keep the stream alive while we process samples
-            run_consumer(sample_rate, vad, sample_rx, cmd_rx, level_cb);
+            run_consumer(
+                sample_rate,
+                vad,
+                sample_rx,
+                cmd_rx,
+                level_cb,
+                end_of_utterance_cb,
+                speech_cb,
+            );
             // stream is dropped here, after run_consumer returns
         });
 
@@ -129,8 +178,27 @@ impl AudioRecorder {
     }
 
     pub fn start(&self) -> Result<(), Box<dyn std::error::Error>> {
+        self.start_with_end_of_utterance(None)
+    }
+
+    /// Start recording and fire the end-of-utterance callback once `silence`
+    /// has elapsed after the last detected speech frame.
+    pub fn start_with_end_of_utterance(
+        &self,
+        silence: Option<Duration>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.start_with_options(RecordingOptions {
+            end_of_utterance: silence,
+            ..Default::default()
+        })
+    }
+
+    pub fn start_with_options(
+        &self,
+        options: RecordingOptions,
+    ) -> Result<(), Box<dyn std::error::Error>> {
         if let Some(tx) = &self.cmd_tx {
-            tx.send(Cmd::Start)?;
+            tx.send(Cmd::Start(options))?;
         }
         Ok(())
     }
@@ -143,6 +211,17 @@ impl AudioRecorder {
         Ok(resp_rx.recv()?) // wait for the samples
     }
 
+    /// Retune the VAD. While the stream is open the change is applied by the
+    /// worker between frames, so the microphone doesn't need to be reopened.
+    pub fn update_vad_params(&self, params: VadParams) -> Result<(), Box<dyn std::error::Error>> {
+        match (&self.cmd_tx, &self.vad) {
+            (Some(tx), _) => tx.send(Cmd::UpdateVad(params))?,
+            (None, Some(vad)) => vad.lock().unwrap().set_params(&params),
+            (None, None) => {}
+        }
+        Ok(())
+    }
+
     pub fn close(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         if let Some(tx) = self.cmd_tx.take() {
             let _ = tx.send(Cmd::Shutdown);
@@ -239,21 +318,75 @@ impl AudioRecorder {
     }
 }
 
+/// Tracks trailing silence after speech to detect the end of an utterance
+struct UtteranceTracker {
+    silence_limit: Option<Duration>,
+    heard_speech: bool,
+    trailing_silence: Duration,
+    fired: bool,
+}
+
+impl UtteranceTracker {
+    fn new(silence_limit: Option<Duration>) -> Self {
+        Self {
+            silence_limit,
+            heard_speech: false,
+            trailing_silence: Duration::ZERO,
+            fired: false,
+        }
+    }
+
+    /// Feed one frame's VAD decision. Returns true exactly once, on the frame
+    /// where the trailing silence reaches the limit.
+    fn observe(&mut self, is_speech: bool) -> bool {
+        let Some(limit) = self.silence_limit else {
+            return false;
+        };
+        if self.fired {
+            return false;
+        }
+
+        if is_speech {
+            self.heard_speech = true;
+            self.trailing_silence = Duration::ZERO;
+            return false;
+        }
+
+        // Leading silence never ends an utterance
+        if !self.heard_speech {
+            return false;
+        }
+
+        self.trailing_silence += FRAME_DURATION;
+        if self.trailing_silence >= limit {
+            self.fired = true;
+            return true;
+        }
+        false
+    }
+}
+
 fn run_consumer(
     in_sample_rate: u32,
     vad: Option<Arc<Mutex<Box<dyn vad::VoiceActivityDetector>>>>,
     sample_rx: mpsc::Receiver<Vec<f32>>,
     cmd_rx: mpsc::Receiver<Cmd>,
     level_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
+    end_of_utterance_cb: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
+    speech_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
 ) {
     let mut frame_resampler = FrameResampler::new(
         in_sample_rate as usize,
         constants::WHISPER_SAMPLE_RATE as usize,
-        Duration::from_millis(30),
+        FRAME_DURATION,
     );
 
     let mut processed_samples = Vec::<f32>::new();
     let mut recording = false;
+    let mut utterance = UtteranceTracker::new(None);
+    // Speech handed to the speech callback: samples per hand-off and how far we got
+    let mut speech_interval: Option<usize> = None;
+    let mut speech_sent = 0;
 
     // ---------- spectrum visualisation setup ---------------------------- //
     const BUCKETS: usize = 16;
@@ -266,24 +399,31 @@ fn run_consumer(
         4000.0, // vocal_max_hz
     );
 
+    /// Returns whether the frame was voiced. This is the detector's raw
+    /// decision, so hangover frames kept after speech count as silence.
     fn handle_frame(
         samples: &[f32],
         recording: bool,
         vad: &Option<Arc<Mutex<Box<dyn vad::VoiceActivityDetector>>>>,
         out_buf: &mut Vec<f32>,
-    ) {
+    ) -> bool {
         if !recording {
-            return;
+            return false;
         }
 
         if let Some(vad_arc) = vad {
             let mut det = vad_arc.lock().unwrap();
-            match det.push_frame(samples).unwrap_or(VadFrame::Speech(samples)) {
-                VadFrame::Speech(buf) => out_buf.extend_from_slice(buf),
-                VadFrame::Noise => {}
-            }
+            let kept = match det.push_frame(samples).unwrap_or(VadFrame::Speech(samples)) {
+                VadFrame::Speech(buf) => {
+                    out_buf.extend_from_slice(buf);
+                    true
+                }
+                VadFrame::Noise => false,
+            };
+            det.last_raw_decision().unwrap_or(kept)
         } else {
             out_buf.extend_from_slice(samples);
+            true
         }
     }
 
@@ -301,16 +441,39 @@ fn run_consumer(
         }
 
         // ---------- existing pipeline ------------------------------------ //
+        let mut utterance_ended = false;
         frame_resampler.push(&raw, &mut |frame: &[f32]| {
-            handle_frame(frame, recording, &vad, &mut processed_samples)
+            let is_speech = handle_frame(frame, recording, &vad, &mut processed_samples);
+            if recording && utterance.observe(is_speech) {
+                utterance_ended = true;
+            }
         });
 
+        if utterance_ended {
+            log::debug!("End of utterance detected");
+            if let Some(cb) = &end_of_utterance_cb {
+                cb();
+            }
+        }
+
+        if let (Some(cb), Some(interval)) = (&speech_cb, speech_interval) {
+            if recording && processed_samples.len() - speech_sent >= interval {
+                cb(processed_samples[speech_sent..].to_vec());
+                speech_sent = processed_samples.len();
+            }
+        }
+
         // non-blocking check for a command
         while let Ok(cmd) = cmd_rx.try_recv() {
             match cmd {
-                Cmd::Start => {
+                Cmd::Start(options) => {
                     processed_samples.clear();
                     recording = true;
+                    utterance = UtteranceTracker::new(options.end_of_utterance);
+                    speech_interval = options.speech_interval.map(|interval| {
+                        (interval.as_secs_f32() * constants::WHISPER_SAMPLE_RATE as f32) as usize
+                    });
+                    speech_sent = 0;
                     visualizer.reset(); // Reset visualization buffer
                     if let Some(v) = &vad {
                         v.lock().unwrap().reset();
@@ -321,13 +484,80 @@ fn run_consumer(
 
                     frame_resampler.finish(&mut |frame: &[f32]| {
                         // we still want to process the last few frames
-                        handle_frame(frame, true, &vad, &mut processed_samples)
+                        handle_frame(frame, true, &vad, &mut processed_samples);
                     });
 
                     let _ = reply_tx.send(std::mem::take(&mut processed_samples));
                 }
+                Cmd::UpdateVad(params) => {
+                    if let Some(v) = &vad {
+                        v.lock().unwrap().set_params(&params);
+                        log::debug!("VAD parameters updated: {:?}", params);
+                    }
+                }
                 Cmd::Shutdown => return,
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Frames needed to cover `ms` of audio
+    fn frames(ms: u64) -> usize {
+        (Duration::from_millis(ms).as_micros() / FRAME_DURATION.as_micros()) as usize
+    }
+
+    #[test]
+    fn test_fires_when_silence_reaches_limit() {
+        let mut tracker = UtteranceTracker::new(Some(Duration::from_millis(300)));
+        assert!(!tracker.observe(true));
+        for _ in 1..frames(300) {
+            assert!(!tracker.observe(false));
+        }
+        assert!(tracker.observe(false));
+    }
+
+    #[test]
+    fn test_speech_resets_silence() {
+        let mut tracker = UtteranceTracker::new(Some(Duration::from_millis(300)));
+        tracker.observe(true);
+        for _ in 1..frames(300) {
+            assert!(!tracker.observe(false));
+        }
+        assert!(!tracker.observe(true));
+        for _ in 1..frames(300) {
+            assert!(!tracker.observe(false));
+        }
+        assert!(tracker.observe(false));
+    }
+
+    #[test]
+    fn test_leading_silence_never_fires() {
+        let mut tracker = UtteranceTracker::new(Some(Duration::from_millis(300)));
+        for _ in 0..frames(3000) {
+            assert!(!tracker.observe(false));
+        }
+    }
+
+    #[test]
+    fn test_fires_once() {
+        let mut tracker = UtteranceTracker::new(Some(Duration::from_millis(300)));
+        tracker.observe(true);
+        let fired = (0..frames(3000)).filter(|_| tracker.observe(false)).count();
+        assert_eq!(fired, 1);
+        // Speech after firing doesn't re-arm the tracker
+        tracker.observe(true);
+        let fired = (0..frames(3000)).filter(|_| tracker.observe(false)).count();
+        assert_eq!(fired, 0);
+    }
+
+    #[test]
+    fn test_disabled_without_limit() {
+        let mut tracker = UtteranceTracker::new(None);
+        tracker.observe(true);
+        assert!((0..frames(3000)).all(|_| !tracker.observe(false)));
+    }
+}
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use hound::{WavSpec, WavWriter};
+use hound::{WavReader, WavSpec, WavWriter};
 use log::debug;
 use std::path::Path;
 
@@ -24,3 +24,28 @@ pub async fn save_wav_file<P: AsRef<Path>>(file_path: P, samples: &[f32]) -> Res
     debug!("Saved WAV file: {:?}", file_path.as_ref());
     Ok(())
 }
+
+/// Load a 16 kHz mono WAV file written by `save_wav_file` as f32 samples
+pub fn read_wav_file<P: AsRef<Path>>(file_path: P) -> Result<Vec<f32>> {
+    let mut reader = WavReader::open(file_path.as_ref())?;
+    let spec = reader.spec();
+
+    if spec.channels != 1 || spec.sample_rate != 16000 {
+        return Err(anyhow::anyhow!(
+            "Unsupported WAV format: {} channels at {} Hz (expected mono 16 kHz)",
+            spec.channels,
+            spec.sample_rate
+        ));
+    }
+
+    let samples = match spec.sample_format {
+        hound::SampleFormat::Int => reader
+            .samples::<i16>()
+            .map(|s| s.map(|s| s as f32 / i16::MAX as f32))
+            .collect::<Result<Vec<_>, _>>()?,
+        hound::SampleFormat::Float => reader.samples::<f32>().collect::<Result<Vec<_>, _>>()?,
+    };
+
+    debug!("Loaded WAV file: {:?}", file_path.as_ref());
+    Ok(samples)
+}
@@ -0,0 +1,142 @@
+//! Simplified/Traditional Chinese output via OpenCC
+//!
+//! Speech engines only take "zh" and answer in whichever script they like, so
+//! the script the user asked for is applied to the transcript afterwards.
+
+use ferrous_opencc::config::BuiltinConfig;
+use ferrous_opencc::OpenCC;
+use log::warn;
+use once_cell::sync::Lazy;
+
+/// Chinese script requested through the selected language
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChineseScript {
+    Simplified,
+    Traditional,
+    /// Traditional with Taiwan variants and vocabulary (軟體, 滑鼠)
+    Taiwan,
+    /// Traditional with Hong Kong variants
+    HongKong,
+}
+
+impl ChineseScript {
+    /// Script for a language code; None for plain "zh" and other languages
+    pub fn for_language(language: &str) -> Option<Self> {
+        match language {
+            "zh-Hans" => Some(Self::Simplified),
+            "zh-Hant" => Some(Self::Traditional),
+            "zh-TW" | "zh-Hant-TW" => Some(Self::Taiwan),
+            "zh-HK" | "zh-Hant-HK" => Some(Self::HongKong),
+            _ => None,
+        }
+    }
+
+    /// Converter from any mix of scripts to this one. Characters already in
+    /// the target script pass through unchanged.
+    fn converter(self) -> Option<&'static OpenCC> {
+        static SIMPLIFIED: Lazy<Option<OpenCC>> = Lazy::new(|| load(BuiltinConfig::T2s, "t2s"));
+        static TRADITIONAL: Lazy<Option<OpenCC>> = Lazy::new(|| load(BuiltinConfig::S2t, "s2t"));
+        static TAIWAN: Lazy<Option<OpenCC>> = Lazy::new(|| load(BuiltinConfig::S2twp, "s2twp"));
+        static HONG_KONG: Lazy<Option<OpenCC>> = Lazy::new(|| load(BuiltinConfig::S2hk, "s2hk"));
+
+        match self {
+            Self::Simplified => SIMPLIFIED.as_ref(),
+            Self::Traditional => TRADITIONAL.as_ref(),
+            Self::Taiwan => TAIWAN.as_ref(),
+            Self::HongKong => HONG_KONG.as_ref(),
+        }
+    }
+}
+
+fn load(config: BuiltinConfig, name: &str) -> Option<OpenCC> {
+    OpenCC::from_config(config)
+        .map_err(|e| warn!("Failed to load OpenCC config {}: {}", name, e))
+        .ok()
+}
+
+/// Whisper and the cloud APIs take "zh" for every Chinese variant
+pub fn engine_language(language: &str) -> &str {
+    if language.starts_with("zh-") {
+        "zh"
+    } else {
+        language
+    }
+}
+
+/// Convert a transcript to the script requested by `language`. Text is
+/// returned unchanged for other languages or if OpenCC fails to load.
+pub fn convert_chinese_script(text: &str, language: &str) -> String {
+    let converter = ChineseScript::for_language(language).and_then(ChineseScript::converter);
+    match converter {
+        Some(converter) if !text.is_empty() => converter.convert(text),
+        _ => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_for_language() {
+        assert_eq!(
+            ChineseScript::for_language("zh-Hans"),
+            Some(ChineseScript::Simplified)
+        );
+        assert_eq!(
+            ChineseScript::for_language("zh-Hant"),
+            Some(ChineseScript::Traditional)
+        );
+        assert_eq!(
+            ChineseScript::for_language("zh-TW"),
+            Some(ChineseScript::Taiwan)
+        );
+        assert_eq!(
+            ChineseScript::for_language("zh-Hant-HK"),
+            Some(ChineseScript::HongKong)
+        );
+        assert_eq!(ChineseScript::for_language("zh"), None);
+        assert_eq!(ChineseScript::for_language("ja"), None);
+    }
+
+    #[test]
+    fn test_engine_language() {
+        assert_eq!(engine_language("zh-Hant-TW"), "zh");
+        assert_eq!(engine_language("zh"), "zh");
+        assert_eq!(engine_language("de"), "de");
+    }
+
+    #[test]
+    fn test_mixed_script_to_simplified() {
+        assert_eq!(
+            convert_chinese_script("这是繁體字和简体字", "zh-Hans"),
+            "这是繁体字和简体字"
+        );
+    }
+
+    #[test]
+    fn test_mixed_script_to_traditional() {
+        assert_eq!(
+            convert_chinese_script("这是繁體字和简体字", "zh-Hant"),
+            "這是繁體字和簡體字"
+        );
+        assert_eq!(convert_chinese_script("这是繁體字", "zh-HK"), "這是繁體字");
+    }
+
+    #[test]
+    fn test_taiwan_vocabulary() {
+        assert_eq!(convert_chinese_script("软件和鼠標", "zh-TW"), "軟體和滑鼠");
+        assert_eq!(convert_chinese_script("软件", "zh-Hant"), "軟件");
+    }
+
+    #[test]
+    fn test_other_text_unchanged() {
+        assert_eq!(
+            convert_chinese_script("Voyc 软件 2.0", "zh-Hant"),
+            "Voyc 軟件 2.0"
+        );
+        assert_eq!(convert_chinese_script("软件", "zh"), "软件");
+        assert_eq!(convert_chinese_script("软件", "auto"), "软件");
+        assert_eq!(convert_chinese_script("", "zh-Hans"), "");
+    }
+}
@@ -0,0 +1,452 @@
+//! Long-form chunking
+//!
+//! Recordings longer than a model's window are split into bounded chunks,
+//! preferably in the middle of a pause so no word is cut. When speech runs
+//! without a usable pause, the chunk is cut hard and the next one starts a
+//! little earlier; the words repeated in that overlap are removed again when
+//! the chunk texts are stitched together.
+
+use std::ops::Range;
+
+/// A slice of a long recording to transcribe on its own
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AudioChunk {
+    /// Sample range within the recording
+    pub range: Range<usize>,
+    /// Starts inside the previous chunk (hard cut), so its first words may repeat
+    pub overlaps_previous: bool,
+}
+
+/// Bounds for `plan_chunks`, all in samples except `min_pause_frames`
+#[derive(Debug, Clone, Copy)]
+pub struct ChunkParams {
+    /// Longest chunk handed to the engine
+    pub max_samples: usize,
+    /// Shortest chunk worth cutting; shorter tails join the previous chunk
+    pub min_samples: usize,
+    /// Audio repeated across a hard cut
+    pub overlap_samples: usize,
+    /// Non-speech frames in a row that count as a pause
+    pub min_pause_frames: usize,
+}
+
+/// Split a recording into chunks of at most `max_samples`, cutting at pauses.
+///
+/// `speech` holds one VAD decision per frame of `frame_samples`. Within each
+/// window the longest pause is used as the cut point; without one the audio is
+/// cut at the window end and the next chunk repeats `overlap_samples`. Chunks
+/// without any speech are dropped.
+pub fn plan_chunks(
+    speech: &[bool],
+    frame_samples: usize,
+    total_samples: usize,
+    params: &ChunkParams,
+) -> Vec<AudioChunk> {
+    let frame_samples = frame_samples.max(1);
+    let mut chunks = split_at_pauses(speech, frame_samples, total_samples, params);
+    chunks.retain(|chunk| has_speech(speech, frame_samples, &chunk.range));
+    chunks
+}
+
+/// `plan_chunks` without dropping the chunks that have no speech
+fn split_at_pauses(
+    speech: &[bool],
+    frame_samples: usize,
+    total_samples: usize,
+    params: &ChunkParams,
+) -> Vec<AudioChunk> {
+    let max_samples = params.max_samples.max(1);
+    let min_samples = params.min_samples.min(max_samples);
+    let overlap_samples = params.overlap_samples.min(max_samples / 2);
+    let pauses = find_pauses(speech, frame_samples, params.min_pause_frames.max(1));
+
+    let mut chunks: Vec<AudioChunk> = Vec::new();
+    let mut start = 0;
+    let mut overlaps_previous = false;
+
+    while total_samples - start > max_samples {
+        let window_end = start + max_samples;
+        // Longest pause reaching into the window, cut as close to its middle as allowed
+        let cut = pauses
+            .iter()
+            .filter_map(|pause| {
+                let earliest = pause.start.max(start + min_samples + 1);
+                let latest = pause.end.min(window_end);
+                (earliest <= latest).then(|| (pause.frames, pause.middle().clamp(earliest, latest)))
+            })
+            .max()
+            .map(|(_, position)| position);
+
+        match cut {
+            Some(position) => {
+                chunks.push(AudioChunk {
+                    range: start..position,
+                    overlaps_previous,
+                });
+                start = position;
+                overlaps_previous = false;
+            }
+            None => {
+                chunks.push(AudioChunk {
+                    range: start..window_end,
+                    overlaps_previous,
+                });
+                start = window_end - overlap_samples;
+                overlaps_previous = overlap_samples > 0;
+            }
+        }
+    }
+
+    if start < total_samples {
+        match chunks.last_mut() {
+            Some(last) if total_samples - start < min_samples && !overlaps_previous => {
+                last.range.end = total_samples;
+            }
+            _ => chunks.push(AudioChunk {
+                range: start..total_samples,
+                overlaps_previous,
+            }),
+        }
+    }
+    chunks
+}
+
+/// Chunk planning for a recording that is still growing (streaming transcription).
+///
+/// Feed one VAD decision per frame as audio arrives. Once the open part of the
+/// recording no longer fits in `max_samples`, every chunk before the last is
+/// final and won't be revisited; the last one stays open.
+pub struct StreamingChunker {
+    frame_samples: usize,
+    params: ChunkParams,
+    speech: Vec<bool>,
+    /// Start of the open chunk, always on a frame boundary
+    open_start: usize,
+    open_overlaps: bool,
+}
+
+impl StreamingChunker {
+    pub fn new(frame_samples: usize, params: ChunkParams) -> Self {
+        Self {
+            frame_samples: frame_samples.max(1),
+            params,
+            speech: Vec::new(),
+            open_start: 0,
+            open_overlaps: false,
+        }
+    }
+
+    /// Record the VAD decision for the next frame
+    pub fn push_frame(&mut self, is_speech: bool) {
+        self.speech.push(is_speech);
+    }
+
+    /// Samples covered by the frames pushed so far
+    pub fn analyzed_samples(&self) -> usize {
+        self.speech.len() * self.frame_samples
+    }
+
+    /// Finalize the chunks that can no longer change, given the recording length so far.
+    ///
+    /// Returned ranges are absolute; chunks without speech are skipped.
+    pub fn take_final_chunks(&mut self, total_samples: usize) -> Vec<AudioChunk> {
+        let open_len = total_samples.saturating_sub(self.open_start);
+        if open_len <= self.params.max_samples {
+            return Vec::new();
+        }
+
+        let frame_samples = self.frame_samples;
+        let speech = &self.speech[(self.open_start / frame_samples).min(self.speech.len())..];
+        let mut chunks = split_at_pauses(speech, frame_samples, open_len, &self.params);
+        let Some(open) = chunks.pop() else {
+            return Vec::new();
+        };
+
+        let open_overlaps = self.open_overlaps;
+        let offset = self.open_start;
+        let finished = chunks
+            .into_iter()
+            .filter(|chunk| has_speech(speech, frame_samples, &chunk.range))
+            .map(|chunk| AudioChunk {
+                overlaps_previous: if chunk.range.start == 0 {
+                    open_overlaps
+                } else {
+                    chunk.overlaps_previous
+                },
+                range: chunk.range.start + offset..chunk.range.end + offset,
+            })
+            .collect();
+
+        if open.range.start > 0 {
+            self.open_start = (offset + open.range.start) / frame_samples * frame_samples;
+            self.open_overlaps = open.overlaps_previous;
+        }
+        finished
+    }
+
+    /// The part of the recording that isn't final yet
+    pub fn open_chunk(&self, total_samples: usize) -> AudioChunk {
+        AudioChunk {
+            range: self.open_start..total_samples.max(self.open_start),
+            overlaps_previous: self.open_overlaps,
+        }
+    }
+}
+
+/// A run of non-speech frames
+struct Pause {
+    /// First sample of the run
+    start: usize,
+    /// Sample after the run
+    end: usize,
+    frames: usize,
+}
+
+impl Pause {
+    fn middle(&self) -> usize {
+        self.start + (self.end - self.start) / 2
+    }
+}
+
+/// Runs of at least `min_frames` non-speech frames
+fn find_pauses(speech: &[bool], frame_samples: usize, min_frames: usize) -> Vec<Pause> {
+    let mut pauses = Vec::new();
+    let mut run_start = None;
+
+    for (index, is_speech) in speech.iter().chain(std::iter::once(&true)).enumerate() {
+        match (is_speech, run_start) {
+            (false, None) => run_start = Some(index),
+            (true, Some(first)) => {
+                if index - first >= min_frames {
+                    pauses.push(Pause {
+                        start: first * frame_samples,
+                        end: index * frame_samples,
+                        frames: index - first,
+                    });
+                }
+                run_start = None;
+            }
+            _ => {}
+        }
+    }
+    pauses
+}
+
+fn has_speech(speech: &[bool], frame_samples: usize, range: &Range<usize>) -> bool {
+    let first = range.start / frame_samples;
+    let last = range.end.div_ceil(frame_samples).min(speech.len());
+    speech
+        .get(first..last)
+        .is_some_and(|frames| frames.contains(&true))
+}
+
+/// Remove the words at the start of `next` that repeat the end of `previous`.
+///
+/// Words are compared ignoring case and punctuation; at most `max_words` are
+/// considered. Returns `next` unchanged when nothing repeats.
+pub fn stitch_overlap(previous: &str, next: &str, max_words: usize) -> String {
+    let previous_words: Vec<String> = previous.split_whitespace().map(normalize_word).collect();
+    let next_words: Vec<&str> = next.split_whitespace().collect();
+    let longest = max_words.min(previous_words.len()).min(next_words.len());
+
+    let repeated = (1..=longest)
+        .rev()
+        .find(|&count| {
+            let tail = &previous_words[previous_words.len() - count..];
+            tail.iter()
+                .zip(&next_words[..count])
+                .all(|(a, b)| !a.is_empty() && *a == normalize_word(b))
+        })
+        .unwrap_or(0);
+
+    if repeated == 0 {
+        next.trim().to_string()
+    } else {
+        next_words[repeated..].join(" ")
+    }
+}
+
+fn normalize_word(word: &str) -> String {
+    word.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: ChunkParams = ChunkParams {
+        max_samples: 100,
+        min_samples: 20,
+        overlap_samples: 10,
+        min_pause_frames: 2,
+    };
+
+    /// Frames of 10 samples: `#` is speech, `.` is silence
+    fn frames(pattern: &str) -> Vec<bool> {
+        pattern.chars().map(|c| c == '#').collect()
+    }
+
+    fn chunk(range: Range<usize>, overlaps_previous: bool) -> AudioChunk {
+        AudioChunk {
+            range,
+            overlaps_previous,
+        }
+    }
+
+    #[test]
+    fn test_short_audio_is_one_chunk() {
+        let speech = frames("#####");
+        assert_eq!(
+            plan_chunks(&speech, 10, 50, &PARAMS),
+            vec![chunk(0..50, false)]
+        );
+    }
+
+    #[test]
+    fn test_empty_audio() {
+        assert!(plan_chunks(&[], 10, 0, &PARAMS).is_empty());
+    }
+
+    #[test]
+    fn test_cuts_in_the_middle_of_a_pause() {
+        // Pause over frames 6..10, midpoint at frame 8
+        let speech = frames("######....##########");
+        assert_eq!(
+            plan_chunks(&speech, 10, 200, &PARAMS),
+            vec![
+                chunk(0..80, false),
+                chunk(80..180, false),
+                chunk(170..200, true)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_prefers_the_longest_pause() {
+        let speech = frames("##..##.....#########");
+        let chunks = plan_chunks(&speech, 10, 200, &PARAMS);
+        // Long pause over frames 6..11 wins over the short one at 2..4
+        assert_eq!(chunks[0], chunk(0..85, false));
+    }
+
+    #[test]
+    fn test_ignores_pauses_too_early_or_too_short() {
+        // Leading pause ends before min_samples, single-frame gaps are too short
+        let speech = frames("..#####.#.##########");
+        let chunks = plan_chunks(&speech, 10, 200, &PARAMS);
+        assert_eq!(chunks[0], chunk(0..100, false));
+        assert_eq!(chunks[1], chunk(90..190, true));
+    }
+
+    #[test]
+    fn test_hard_cuts_overlap_in_continuous_speech() {
+        let speech = frames("##########################");
+        assert_eq!(
+            plan_chunks(&speech, 10, 260, &PARAMS),
+            vec![
+                chunk(0..100, false),
+                chunk(90..190, true),
+                chunk(180..260, true)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_short_tail_joins_previous_chunk() {
+        // Pause at frame 9 (midpoint 90), leaving a 15 sample tail after it
+        let speech = frames("########..#");
+        assert_eq!(
+            plan_chunks(&speech, 10, 105, &PARAMS),
+            vec![chunk(0..105, false)]
+        );
+    }
+
+    #[test]
+    fn test_drops_silent_chunks() {
+        let speech = frames("######..............#####");
+        let chunks = plan_chunks(&speech, 10, 250, &PARAMS);
+        assert!(chunks.iter().all(|c| has_speech(&speech, 10, &c.range)));
+        assert_eq!(chunks.first().unwrap().range.start, 0);
+        assert_eq!(chunks.last().unwrap().range.end, 250);
+    }
+
+    fn streaming_chunker(pattern: &str) -> StreamingChunker {
+        let mut chunker = StreamingChunker::new(10, PARAMS);
+        for is_speech in frames(pattern) {
+            chunker.push_frame(is_speech);
+        }
+        chunker
+    }
+
+    #[test]
+    fn test_streaming_keeps_short_recording_open() {
+        let mut chunker = streaming_chunker("#####");
+        assert!(chunker.take_final_chunks(50).is_empty());
+        assert_eq!(chunker.open_chunk(50), chunk(0..50, false));
+    }
+
+    #[test]
+    fn test_streaming_finalizes_before_the_open_chunk() {
+        let mut chunker = streaming_chunker("######....##########");
+        assert_eq!(
+            chunker.take_final_chunks(200),
+            vec![chunk(0..80, false), chunk(80..180, false)]
+        );
+        assert_eq!(chunker.open_chunk(200), chunk(170..200, true));
+        // Finalized audio is never revisited
+        assert!(chunker.take_final_chunks(200).is_empty());
+    }
+
+    #[test]
+    fn test_streaming_carries_overlap_into_next_chunk() {
+        let mut chunker = streaming_chunker("##########################");
+        assert_eq!(
+            chunker.take_final_chunks(260),
+            vec![chunk(0..100, false), chunk(90..190, true)]
+        );
+
+        for _ in 0..10 {
+            chunker.push_frame(true);
+        }
+        assert_eq!(chunker.analyzed_samples(), 360);
+        assert_eq!(chunker.take_final_chunks(360), vec![chunk(180..280, true)]);
+        assert_eq!(chunker.open_chunk(360), chunk(270..360, true));
+    }
+
+    #[test]
+    fn test_streaming_skips_silent_chunks() {
+        let mut chunker = streaming_chunker("##########....................");
+        assert_eq!(chunker.take_final_chunks(300), vec![chunk(0..100, false)]);
+        assert_eq!(chunker.open_chunk(300), chunk(200..300, false));
+    }
+
+    #[test]
+    fn test_stitch_removes_repeated_words() {
+        assert_eq!(
+            stitch_overlap("we should ship it on", "Ship it on Friday.", 8),
+            "Friday."
+        );
+    }
+
+    #[test]
+    fn test_stitch_keeps_text_without_overlap() {
+        assert_eq!(
+            stitch_overlap("see you tomorrow.", " Bring the slides", 8),
+            "Bring the slides"
+        );
+    }
+
+    #[test]
+    fn test_stitch_respects_max_words() {
+        assert_eq!(stitch_overlap("a b c d", "a b c d e", 3), "a b c d e");
+        assert_eq!(stitch_overlap("x b c d", "b c d e", 3), "e");
+    }
+
+    #[test]
+    fn test_stitch_ignores_punctuation_only_words() {
+        assert_eq!(stitch_overlap("well -", "- okay", 4), "- okay");
+    }
+}
@@ -0,0 +1,315 @@
+//! Per-language filler words and known Whisper hallucinations
+//!
+//! Fillers are removed from transcripts. Hallucinations are the phrases
+//! Whisper produces from silence, usually subtitle credits from its training
+//! data. They only lower the confidence estimate, most of all for a transcript
+//! consisting of nothing else, so cloud fallback can take a second look;
+//! transcripts are never dropped for them, since a user may say them.
+//! Only list phrases nobody would dictate as a whole message.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+struct LanguageFilters {
+    language: &'static str,
+    fillers: &'static [&'static str],
+    hallucinations: &'static [&'static str],
+}
+
+/// Only sounds that are never words in the language belong in `fillers`
+const LANGUAGE_FILTERS: &[LanguageFilters] = &[
+    LanguageFilters {
+        language: "en",
+        fillers: &[
+            "uh", "um", "uhm", "umm", "uhh", "uhhh", "ah", "eh", "hmm", "hm", "mmm", "mm", "mh",
+            "ha", "ehh",
+        ],
+        hallucinations: &["thank you for watching", "like and share"],
+    },
+    LanguageFilters {
+        language: "de",
+        fillers: &["äh", "ähm", "äm", "öhm", "ehm", "hm", "hmm", "mhm"],
+        hallucinations: &[
+            "untertitel im auftrag des zdf",
+            "untertitelung des zdf für funk",
+            "untertitel der amara.org-community",
+            "untertitel von stephanie geiges",
+            "vielen dank fürs zuschauen",
+        ],
+    },
+    LanguageFilters {
+        language: "fr",
+        fillers: &["euh", "heu", "euhm", "hum", "hmm", "mmh"],
+        hallucinations: &[
+            "sous-titrage société radio-canada",
+            "sous-titres réalisés par la communauté d'amara.org",
+            "sous-titrage st' 501",
+            "merci d'avoir regardé",
+        ],
+    },
+    LanguageFilters {
+        language: "es",
+        fillers: &["eh", "em", "ehm", "mmm", "hmm"],
+        hallucinations: &[
+            "subtítulos realizados por la comunidad de amara.org",
+            "gracias por ver el video",
+        ],
+    },
+    LanguageFilters {
+        language: "it",
+        fillers: &["ehm", "ehh", "mmm", "hmm"],
+        hallucinations: &[
+            "sottotitoli creati dalla comunità amara.org",
+            "sottotitoli a cura di qtss",
+            "grazie per la visione",
+        ],
+    },
+    LanguageFilters {
+        language: "pt",
+        fillers: &["hã", "ahn", "hum", "hmm"],
+        hallucinations: &[
+            "legendas pela comunidade amara.org",
+            "obrigado por assistir",
+        ],
+    },
+    LanguageFilters {
+        language: "nl",
+        fillers: &["eh", "ehm", "uhm", "hmm"],
+        hallucinations: &[
+            "ondertiteld door de amara.org gemeenschap",
+            "ondertiteling door de amara.org gemeenschap",
+            "bedankt voor het kijken",
+        ],
+    },
+    LanguageFilters {
+        language: "pl",
+        fillers: &["yyy", "yyyy", "eee", "eeee", "hmm", "mhm"],
+        hallucinations: &[
+            "napisy stworzone przez społeczność amara.org",
+            "napisy wykonane przez społeczność amara.org",
+        ],
+    },
+    LanguageFilters {
+        language: "ru",
+        fillers: &["э", "ээ", "эм", "ммм", "хм"],
+        hallucinations: &[
+            "субтитры сделал dimatorzok",
+            "редактор субтитров а.семкин корректор а.егорова",
+        ],
+    },
+    LanguageFilters {
+        language: "zh",
+        fillers: &[],
+        hallucinations: &[
+            "请不吝点赞 订阅 转发 打赏支持明镜与点点栏目",
+            "字幕由amara.org社区提供",
+        ],
+    },
+    LanguageFilters {
+        language: "ja",
+        fillers: &[],
+        hallucinations: &["ご視聴ありがとうございました"],
+    },
+    LanguageFilters {
+        language: "ko",
+        fillers: &[],
+        hallucinations: &["시청해주셔서 감사합니다"],
+    },
+];
+
+/// Table entry for a language code ("de", "zh-Hans"); None for unlisted languages
+fn table_for(language: &str) -> Option<&'static LanguageFilters> {
+    let base = language.split('-').next().unwrap_or(language);
+    LANGUAGE_FILTERS.iter().find(|table| table.language == base)
+}
+
+/// Built-in filler words for a language. Auto-detect uses the English list,
+/// since fillers in one language can be words in another.
+pub fn builtin_fillers(language: &str) -> &'static [&'static str] {
+    let language = if language == "auto" { "en" } else { language };
+    table_for(language).map_or(&[], |table| table.fillers)
+}
+
+/// Built-in hallucination phrases for a language. Auto-detect checks every
+/// language's phrases; they are too specific to clash with real speech.
+pub fn builtin_hallucinations(language: &str) -> Vec<&'static str> {
+    if language == "auto" {
+        return LANGUAGE_FILTERS
+            .iter()
+            .flat_map(|table| table.hallucinations.iter().copied())
+            .collect();
+    }
+    table_for(language).map_or_else(Vec::new, |table| table.hallucinations.to_vec())
+}
+
+/// User settings for cleaning up transcripts
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+#[serde(default)]
+pub struct OutputFilters {
+    pub remove_filler_words: bool,
+    /// Collapse 3+ repetitions of a 1-2 letter word ("I I I think")
+    pub collapse_stutters: bool,
+    pub detect_hallucinations: bool,
+    /// Added to the built-in fillers of every language
+    pub extra_filler_words: Vec<String>,
+    /// Added to the built-in hallucination phrases of every language
+    pub extra_hallucinations: Vec<String>,
+}
+
+impl Default for OutputFilters {
+    fn default() -> Self {
+        Self {
+            remove_filler_words: true,
+            collapse_stutters: true,
+            detect_hallucinations: true,
+            extra_filler_words: Vec::new(),
+            extra_hallucinations: Vec::new(),
+        }
+    }
+}
+
+impl OutputFilters {
+    /// Filler words to remove for a language (none when removal is off)
+    pub fn filler_words(&self, language: &str) -> Vec<String> {
+        if !self.remove_filler_words {
+            return Vec::new();
+        }
+        merge(builtin_fillers(language), &self.extra_filler_words)
+    }
+
+    /// Hallucination phrases for a language (none when detection is off)
+    pub fn hallucinations(&self, language: &str) -> Vec<String> {
+        if !self.detect_hallucinations {
+            return Vec::new();
+        }
+        merge(
+            &builtin_hallucinations(language),
+            &self.extra_hallucinations,
+        )
+    }
+}
+
+/// Lowercase letters and spaces only, so punctuation and years don't matter
+fn normalize(text: &str) -> String {
+    let letters: String = text
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphabetic() || c.is_whitespace())
+        .collect();
+    letters.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Whether the whole transcript is one of `phrases`, e.g. "Untertitel im
+/// Auftrag des ZDF, 2017". Transcripts merely containing a phrase are kept.
+pub fn is_hallucination(text: &str, phrases: &[String]) -> bool {
+    let text = normalize(text);
+    !text.is_empty() && phrases.iter().any(|phrase| normalize(phrase) == text)
+}
+
+/// Lowercase union of built-in and user entries, without blanks or duplicates
+fn merge(builtin: &[&str], extra: &[String]) -> Vec<String> {
+    let mut merged: Vec<String> = Vec::new();
+    for entry in builtin
+        .iter()
+        .copied()
+        .chain(extra.iter().map(String::as_str))
+    {
+        let entry = entry.trim().to_lowercase();
+        if !entry.is_empty() && !merged.contains(&entry) {
+            merged.push(entry);
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_lists_by_language() {
+        assert!(builtin_fillers("en").contains(&"um"));
+        assert!(builtin_fillers("de").contains(&"ähm"));
+        assert!(!builtin_fillers("de").contains(&"um"));
+        assert_eq!(builtin_fillers("auto"), builtin_fillers("en"));
+        assert!(builtin_fillers("sw").is_empty());
+
+        assert!(builtin_hallucinations("de").contains(&"untertitel im auftrag des zdf"));
+        assert!(!builtin_hallucinations("en").contains(&"untertitel im auftrag des zdf"));
+        assert!(builtin_hallucinations("zh-Hant").contains(&"字幕由amara.org社区提供"));
+    }
+
+    #[test]
+    fn test_auto_checks_every_language_for_hallucinations() {
+        let all = builtin_hallucinations("auto");
+        assert!(all.contains(&"thank you for watching"));
+        assert!(all.contains(&"sous-titrage société radio-canada"));
+        assert!(all.contains(&"ご視聴ありがとうございました"));
+    }
+
+    #[test]
+    fn test_user_entries_extend_builtins() {
+        let filters = OutputFilters {
+            extra_filler_words: vec![" Like ".to_string(), "um".to_string(), "".to_string()],
+            extra_hallucinations: vec!["Transcribed by ACME".to_string()],
+            ..Default::default()
+        };
+        let fillers = filters.filler_words("en");
+        assert!(fillers.contains(&"like".to_string()));
+        assert_eq!(fillers.iter().filter(|w| *w == "um").count(), 1);
+        assert!(!fillers.contains(&String::new()));
+        // User entries apply to languages without built-in lists too
+        assert_eq!(
+            filters.filler_words("sw"),
+            vec!["like".to_string(), "um".to_string()]
+        );
+        assert!(filters
+            .hallucinations("fr")
+            .contains(&"transcribed by acme".to_string()));
+    }
+
+    #[test]
+    fn test_is_hallucination_matches_whole_transcript() {
+        let phrases = OutputFilters::default().hallucinations("de");
+        assert!(is_hallucination(
+            "Untertitel im Auftrag des ZDF, 2017",
+            &phrases
+        ));
+        assert!(is_hallucination(
+            "  untertitel der Amara.org-Community ",
+            &phrases
+        ));
+        assert!(!is_hallucination(
+            "Die Untertitel im Auftrag des ZDF sind fertig",
+            &phrases
+        ));
+        assert!(!is_hallucination("", &phrases));
+        assert!(!is_hallucination("2017", &phrases));
+    }
+
+    #[test]
+    fn test_generic_phrases_are_not_hallucinations() {
+        // Ordinary things to dictate, even though Whisper invents them too
+        let phrases = OutputFilters::default().hallucinations("auto");
+        for text in [
+            "Subscribe",
+            "Thanks for watching!",
+            "See you next time.",
+            "Продолжение следует",
+        ] {
+            assert!(!is_hallucination(text, &phrases), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_disabled_filters_are_empty() {
+        let filters = OutputFilters {
+            remove_filler_words: false,
+            detect_hallucinations: false,
+            extra_filler_words: vec!["like".to_string()],
+            ..Default::default()
+        };
+        assert!(filters.filler_words("en").is_empty());
+        assert!(filters.hallucinations("en").is_empty());
+    }
+}
@@ -0,0 +1,1001 @@
+//! Inverse text normalization: spoken numbers to written form
+//!
+//! Moonshine and Parakeet often spell numbers out ("twenty five dollars on
+//! march third"); this rewrites them the way people type them ("$25 on
+//! March 3"). The rules are a small hand-written grammar per language, with
+//! English the only one so far.
+//!
+//! Following common style guides, standalone numbers below ten stay spelled
+//! out ("one of them", "first place") unless they carry a currency, unit,
+//! percent sign or decimals.
+
+/// Languages with ITN rules
+pub const ITN_LANGUAGES: &[&str] = &["en"];
+
+/// Longest spoken pattern looked at from one position
+const MAX_SPAN: usize = 24;
+
+/// Rewrite spoken numbers, dates, times, money, percentages, phone numbers
+/// and units in `text` as written text.
+///
+/// `language` is the selected language ("auto" uses English rules); text is
+/// returned unchanged unless its language is in `enabled_languages`.
+pub fn inverse_text_normalization(
+    text: &str,
+    language: &str,
+    enabled_languages: &[String],
+) -> String {
+    let language = if language == "auto" {
+        "en"
+    } else {
+        language.split('-').next().unwrap_or(language)
+    };
+    if !enabled_languages.iter().any(|enabled| enabled == language) {
+        return text.to_string();
+    }
+
+    match language {
+        "en" => text
+            .split('\n')
+            .map(english_line)
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => text.to_string(),
+    }
+}
+
+/// A transcript word split from its surrounding punctuation
+struct Token<'a> {
+    prefix: &'a str,
+    core: &'a str,
+    suffix: &'a str,
+    /// Lowercase core, for matching
+    word: String,
+    /// Second half of a split hyphenated number ("twenty-five")
+    after_hyphen: bool,
+}
+
+fn tokenize(line: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    for raw in line.split(' ').filter(|raw| !raw.is_empty()) {
+        let start = raw.find(char::is_alphanumeric).unwrap_or(raw.len());
+        let end = raw
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_alphanumeric())
+            .map_or(start, |(i, c)| i + c.len_utf8());
+        if start >= end {
+            tokens.push(Token {
+                prefix: raw,
+                core: "",
+                suffix: "",
+                word: String::new(),
+                after_hyphen: false,
+            });
+            continue;
+        }
+
+        let (prefix, core, suffix) = (&raw[..start], &raw[start..end], &raw[end..]);
+        let parts: Vec<&str> = core.split('-').collect();
+        let is_hyphenated_number = parts.len() > 1
+            && parts
+                .iter()
+                .all(|part| number_word(&part.to_lowercase()).is_some());
+        if !is_hyphenated_number {
+            tokens.push(Token {
+                prefix,
+                core,
+                suffix,
+                word: core.to_lowercase(),
+                after_hyphen: false,
+            });
+            continue;
+        }
+
+        let last = parts.len() - 1;
+        for (index, part) in parts.into_iter().enumerate() {
+            tokens.push(Token {
+                prefix: if index == 0 { prefix } else { "" },
+                core: part,
+                suffix: if index == last { suffix } else { "" },
+                word: part.to_lowercase(),
+                after_hyphen: index > 0,
+            });
+        }
+    }
+    tokens
+}
+
+/// End of the span from `start` that contains no punctuation between words
+fn run_end(tokens: &[Token], start: usize) -> usize {
+    let mut end = start + 1;
+    while end < tokens.len()
+        && end - start < MAX_SPAN
+        && tokens[end - 1].suffix.is_empty()
+        && tokens[end].prefix.is_empty()
+    {
+        end += 1;
+    }
+    end
+}
+
+enum Match {
+    /// Replace this many words with the text
+    Replace(String, usize),
+    /// Leave this many words as spoken
+    Keep(usize),
+}
+
+fn english_line(line: &str) -> String {
+    let tokens = tokenize(line);
+    let mut output = String::new();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let end = run_end(&tokens, i);
+        let words: Vec<&str> = tokens[i..end].iter().map(|t| t.word.as_str()).collect();
+        let after_at = i > 0
+            && tokens[i - 1].word == "at"
+            && tokens[i - 1].suffix.is_empty()
+            && tokens[i].prefix.is_empty();
+
+        match match_english(&words, after_at) {
+            Some(Match::Replace(text, len)) => {
+                if !output.is_empty() {
+                    output.push(' ');
+                }
+                output.push_str(tokens[i].prefix);
+                output.push_str(&text);
+                output.push_str(tokens[i + len - 1].suffix);
+                i += len;
+            }
+            matched => {
+                let len = match matched {
+                    Some(Match::Keep(len)) => len,
+                    _ => 1,
+                };
+                for token in &tokens[i..i + len] {
+                    if !output.is_empty() {
+                        output.push(if token.after_hyphen { '-' } else { ' ' });
+                    }
+                    output.push_str(token.prefix);
+                    output.push_str(token.core);
+                    output.push_str(token.suffix);
+                }
+                i += len;
+            }
+        }
+    }
+
+    output
+}
+
+fn match_english(words: &[&str], after_at: bool) -> Option<Match> {
+    phone_number(words)
+        .or_else(|| date(words))
+        .or_else(|| time(words, after_at))
+        .or_else(|| quantity(words))
+        .or_else(|| plain_number(words))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NumberWord {
+    Unit(u64),
+    Teen(u64),
+    Tens(u64),
+    Hundred,
+    Scale(u64),
+}
+
+/// Classify a number word, and whether it is the ordinal form
+fn number_word(word: &str) -> Option<(NumberWord, bool)> {
+    const UNITS: [&str; 10] = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    const UNIT_ORDINALS: [&str; 10] = [
+        "zeroth", "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth",
+        "ninth",
+    ];
+    const TEENS: [&str; 10] = [
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TEEN_ORDINALS: [&str; 10] = [
+        "tenth",
+        "eleventh",
+        "twelfth",
+        "thirteenth",
+        "fourteenth",
+        "fifteenth",
+        "sixteenth",
+        "seventeenth",
+        "eighteenth",
+        "nineteenth",
+    ];
+    const TENS: [&str; 8] = [
+        "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+    const TENS_ORDINALS: [&str; 8] = [
+        "twentieth",
+        "thirtieth",
+        "fortieth",
+        "fiftieth",
+        "sixtieth",
+        "seventieth",
+        "eightieth",
+        "ninetieth",
+    ];
+    const SCALES: [(&str, &str, u64); 3] = [
+        ("thousand", "thousandth", 1_000),
+        ("million", "millionth", 1_000_000),
+        ("billion", "billionth", 1_000_000_000),
+    ];
+
+    let position = |list: &[&str]| list.iter().position(|w| *w == word).map(|i| i as u64);
+
+    if let Some(n) = position(&UNITS) {
+        return Some((NumberWord::Unit(n), false));
+    }
+    if let Some(n) = position(&UNIT_ORDINALS) {
+        return Some((NumberWord::Unit(n), true));
+    }
+    if let Some(n) = position(&TEENS) {
+        return Some((NumberWord::Teen(10 + n), false));
+    }
+    if let Some(n) = position(&TEEN_ORDINALS) {
+        return Some((NumberWord::Teen(10 + n), true));
+    }
+    if let Some(n) = position(&TENS) {
+        return Some((NumberWord::Tens(20 + 10 * n), false));
+    }
+    if let Some(n) = position(&TENS_ORDINALS) {
+        return Some((NumberWord::Tens(20 + 10 * n), true));
+    }
+    match word {
+        "hundred" => return Some((NumberWord::Hundred, false)),
+        "hundredth" => return Some((NumberWord::Hundred, true)),
+        _ => {}
+    }
+    SCALES.iter().find_map(|&(cardinal, ordinal, scale)| {
+        if word == cardinal {
+            Some((NumberWord::Scale(scale), false))
+        } else if word == ordinal {
+            Some((NumberWord::Scale(scale), true))
+        } else {
+            None
+        }
+    })
+}
+
+/// A single spoken digit, for phone numbers and decimals
+fn digit(word: &str) -> Option<u64> {
+    match number_word(word) {
+        Some((NumberWord::Unit(n), false)) => Some(n),
+        _ if word == "oh" => Some(0),
+        _ => None,
+    }
+}
+
+/// Digits as typed ("25", "3.5", "1,000")
+fn is_numeric_literal(word: &str) -> bool {
+    word.starts_with(|c: char| c.is_ascii_digit())
+        && word
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == ',' || c == '.')
+}
+
+struct Cardinal {
+    value: u64,
+    len: usize,
+    ordinal: bool,
+}
+
+/// Parse the longest well-formed spoken number at the start of `words`.
+/// Parsing stops after an ordinal word ("twenty first").
+fn parse_cardinal(words: &[&str]) -> Option<Cardinal> {
+    use NumberWord::*;
+
+    let mut total = 0;
+    let mut group = 0;
+    let mut last: Option<NumberWord> = None;
+    let mut last_scale = u64::MAX;
+    let mut len = 0;
+    let mut ordinal = false;
+    let mut i = 0;
+
+    // "a hundred", "a thousand"
+    if words.first() == Some(&"a")
+        && matches!(
+            words.get(1).and_then(|w| number_word(w)),
+            Some((Hundred | Scale(_), _))
+        )
+    {
+        group = 1;
+        last = Some(Unit(1));
+        i = 1;
+    }
+
+    while i < words.len() {
+        let word = words[i];
+        if word == "and" && matches!(last, Some(Hundred | Scale(_))) {
+            i += 1;
+            continue;
+        }
+        let Some((kind, is_ordinal)) = number_word(word) else {
+            break;
+        };
+        let fits = match kind {
+            Unit(0) => last.is_none(),
+            Unit(_) => matches!(last, None | Some(Tens(_) | Hundred | Scale(_))),
+            Teen(_) | Tens(_) => matches!(last, None | Some(Hundred | Scale(_))),
+            Hundred => {
+                (1..100).contains(&group) && matches!(last, Some(Unit(_) | Teen(_) | Tens(_)))
+            }
+            Scale(scale) => group > 0 && scale < last_scale,
+        };
+        if !fits {
+            break;
+        }
+
+        match kind {
+            Unit(n) | Teen(n) | Tens(n) => group += n,
+            Hundred => group *= 100,
+            Scale(scale) => {
+                total += group * scale;
+                group = 0;
+                last_scale = scale;
+            }
+        }
+        last = Some(kind);
+        i += 1;
+        len = i;
+        if is_ordinal {
+            ordinal = true;
+            break;
+        }
+        if kind == Unit(0) {
+            break;
+        }
+    }
+
+    (len > 0).then_some(Cardinal {
+        value: total + group,
+        len,
+        ordinal,
+    })
+}
+
+struct Number {
+    /// Written form, without ordinal suffix
+    text: String,
+    /// Integer value; None for decimals and negatives
+    value: Option<u64>,
+    len: usize,
+    ordinal: bool,
+    /// A single word below ten, which stays spelled out on its own
+    small: bool,
+    literal: bool,
+}
+
+/// A cardinal with optional sign and decimals, or digits as typed
+fn parse_number(words: &[&str]) -> Option<Number> {
+    let first = *words.first()?;
+    if is_numeric_literal(first) {
+        return Some(Number {
+            text: first.to_string(),
+            value: first.parse().ok(),
+            len: 1,
+            ordinal: false,
+            small: false,
+            literal: true,
+        });
+    }
+
+    let negative = first == "negative";
+    let offset = usize::from(negative);
+    let cardinal = parse_cardinal(&words[offset..])?;
+    let mut len = offset + cardinal.len;
+    let mut text = format_integer(cardinal.value);
+    let mut value = (!negative).then_some(cardinal.value);
+
+    if !cardinal.ordinal && words.get(len) == Some(&"point") {
+        let decimals: String = words[len + 1..]
+            .iter()
+            .map_while(|w| digit(w))
+            .map(|d| char::from(b'0' + d as u8))
+            .collect();
+        if !decimals.is_empty() {
+            len += 1 + decimals.len();
+            text = format!("{}.{}", text, decimals);
+            value = None;
+        }
+    }
+    if negative {
+        text.insert(0, '-');
+    }
+
+    Some(Number {
+        text,
+        value,
+        len,
+        ordinal: cardinal.ordinal,
+        small: len == 1 && cardinal.value < 10,
+        literal: false,
+    })
+}
+
+/// Thousands separators from five digits up, so years and PINs stay plain
+fn format_integer(value: u64) -> String {
+    let digits = value.to_string();
+    if value < 10_000 {
+        return digits;
+    }
+    let groups: Vec<&str> = digits
+        .as_bytes()
+        .rchunks(3)
+        .rev()
+        .map(|group| std::str::from_utf8(group).unwrap_or_default())
+        .collect();
+    groups.join(",")
+}
+
+fn format_ordinal(value: u64) -> String {
+    let suffix = match (value % 10, value % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", format_integer(value), suffix)
+}
+
+/// Seven or more spoken digits: "five five five one two three four"
+fn phone_number(words: &[&str]) -> Option<Match> {
+    if words.first() == Some(&"oh") {
+        return None;
+    }
+    let digits: String = words
+        .iter()
+        .map_while(|w| digit(w))
+        .map(|d| char::from(b'0' + d as u8))
+        .collect();
+    if digits.len() < 7 {
+        return None;
+    }
+
+    let formatted = match digits.len() {
+        7 => format!("{}-{}", &digits[..3], &digits[3..]),
+        10 => format!("{}-{}-{}", &digits[..3], &digits[3..6], &digits[6..]),
+        11 if digits.starts_with('1') => {
+            format!("1-{}-{}-{}", &digits[1..4], &digits[4..7], &digits[7..])
+        }
+        _ => digits.clone(),
+    };
+    Some(Match::Replace(formatted, digits.len()))
+}
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+fn month_name(word: &str) -> Option<&'static str> {
+    MONTHS
+        .iter()
+        .find(|month| month.eq_ignore_ascii_case(word))
+        .copied()
+}
+
+/// "nineteen eighty four", "twenty oh five", "two thousand and five", "2024"
+fn parse_year(words: &[&str]) -> Option<(u64, usize)> {
+    use NumberWord::*;
+
+    let first = *words.first()?;
+    if first.len() == 4 && first.chars().all(|c| c.is_ascii_digit()) {
+        return Some((first.parse().ok()?, 1));
+    }
+
+    let century = match number_word(first) {
+        Some((Teen(19), false)) => Some(19),
+        Some((Tens(20), false)) => Some(20),
+        _ => None,
+    };
+    let next = |i: usize| words.get(i).and_then(|w| number_word(w));
+    let tail = match (century, next(1)) {
+        (Some(_), Some((Hundred, false))) => Some((0, 1)),
+        (Some(_), Some((Teen(n), false))) => Some((n, 1)),
+        (Some(_), Some((Tens(n), false))) => match next(2) {
+            Some((Unit(u), false)) if u > 0 => Some((n + u, 2)),
+            _ => Some((n, 1)),
+        },
+        (Some(_), None) if words.get(1) == Some(&"oh") => match next(2) {
+            Some((Unit(u), false)) if u > 0 => Some((u, 2)),
+            _ => None,
+        },
+        _ => None,
+    };
+    if let (Some(century), Some((tail, len))) = (century, tail) {
+        return Some((century * 100 + tail, 1 + len));
+    }
+
+    parse_cardinal(words)
+        .filter(|c| !c.ordinal && c.len > 1 && (1000..=2099).contains(&c.value))
+        .map(|c| (c.value, c.len))
+}
+
+/// "march third" -> "March 3", "the third of march" -> "the 3rd of March",
+/// "july fourth twenty twenty four" -> "July 4, 2024"
+fn date(words: &[&str]) -> Option<Match> {
+    if let Some(day) = parse_cardinal(words).filter(|c| c.ordinal && (1..=31).contains(&c.value)) {
+        let month = words.get(day.len + 1).and_then(|w| month_name(w));
+        if let (Some(&"of"), Some(month)) = (words.get(day.len), month) {
+            return Some(Match::Replace(
+                format!("{} of {}", format_ordinal(day.value), month),
+                day.len + 2,
+            ));
+        }
+    }
+
+    let month = month_name(words.first()?)?;
+    // "may" and "march" are also verbs, so they need an ordinal day
+    let ambiguous = matches!(words[0], "may" | "march");
+
+    if let Some((year, len)) = parse_year(&words[1..]) {
+        if !ambiguous {
+            return Some(Match::Replace(format!("{} {}", month, year), 1 + len));
+        }
+    }
+
+    let day = parse_cardinal(&words[1..])
+        .filter(|c| (1..=31).contains(&c.value) && (c.ordinal || !ambiguous))?;
+    let mut len = 1 + day.len;
+    let mut text = format!("{} {}", month, day.value);
+    if let Some((year, year_len)) = parse_year(&words[len..]) {
+        text = format!("{}, {}", text, year);
+        len += year_len;
+    }
+    Some(Match::Replace(text, len))
+}
+
+/// "pm", "p.m" (its final dot is punctuation), "p m"
+fn parse_meridiem(words: &[&str]) -> Option<(&'static str, usize)> {
+    match words {
+        ["am", ..] | ["a", "m", ..] => Some(("AM", if words[0] == "am" { 1 } else { 2 })),
+        ["pm", ..] | ["p", "m", ..] => Some(("PM", if words[0] == "pm" { 1 } else { 2 })),
+        ["a.m", ..] => Some(("a.m", 1)),
+        ["p.m", ..] => Some(("p.m", 1)),
+        _ => None,
+    }
+}
+
+/// Minutes after an hour: "oh five", "fifteen", "forty five"
+fn parse_minutes(words: &[&str]) -> Option<(u64, usize)> {
+    use NumberWord::*;
+
+    let next = |i: usize| words.get(i).and_then(|w| number_word(w));
+    match next(0) {
+        Some((Teen(n), false)) => Some((n, 1)),
+        Some((Tens(n), false)) if n <= 50 => match next(1) {
+            Some((Unit(u), false)) if u > 0 => Some((n + u, 2)),
+            _ => Some((n, 1)),
+        },
+        None if words.first() == Some(&"oh") => match next(1) {
+            Some((Unit(u), false)) if u > 0 => Some((u, 2)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// "three thirty pm" -> "3:30 PM", "six o'clock" -> "6:00". Without am/pm or
+/// o'clock, an hour and minutes only read as a time after "at".
+fn time(words: &[&str], after_at: bool) -> Option<Match> {
+    let first = *words.first()?;
+    let hour = match number_word(first) {
+        Some((NumberWord::Unit(n) | NumberWord::Teen(n), false)) => n,
+        _ if is_numeric_literal(first) => first.parse().ok()?,
+        _ => return None,
+    };
+    if !(1..=12).contains(&hour) {
+        return None;
+    }
+
+    let mut len = 1;
+    let mut minutes = None;
+    let oclock = matches!(words.get(1), Some(&"o'clock" | &"o’clock"));
+    if oclock {
+        minutes = Some(0);
+        len += 1;
+    } else if let Some((m, m_len)) = parse_minutes(&words[1..]) {
+        minutes = Some(m);
+        len += m_len;
+    }
+
+    let clock = match minutes {
+        Some(m) => format!("{}:{:02}", hour, m),
+        None => hour.to_string(),
+    };
+    match parse_meridiem(&words[len..]) {
+        Some((meridiem, m_len)) => Some(Match::Replace(
+            format!("{} {}", clock, meridiem),
+            len + m_len,
+        )),
+        None if minutes.is_some() && (oclock || after_at) => Some(Match::Replace(clock, len)),
+        None => None,
+    }
+}
+
+/// Spoken unit and its symbol. Symbols starting with "°" attach to the number.
+const UNITS: &[(&str, &str)] = &[
+    ("kilometers per hour", "km/h"),
+    ("kilometres per hour", "km/h"),
+    ("miles per hour", "mph"),
+    ("degrees celsius", "°C"),
+    ("degrees centigrade", "°C"),
+    ("degrees fahrenheit", "°F"),
+    ("degree celsius", "°C"),
+    ("degree fahrenheit", "°F"),
+    ("degrees", "°"),
+    ("degree", "°"),
+    ("kilometers", "km"),
+    ("kilometer", "km"),
+    ("kilometres", "km"),
+    ("kilometre", "km"),
+    ("centimeters", "cm"),
+    ("centimeter", "cm"),
+    ("centimetres", "cm"),
+    ("centimetre", "cm"),
+    ("millimeters", "mm"),
+    ("millimeter", "mm"),
+    ("millimetres", "mm"),
+    ("millimetre", "mm"),
+    ("meters", "m"),
+    ("meter", "m"),
+    ("metres", "m"),
+    ("metre", "m"),
+    ("kilograms", "kg"),
+    ("kilogram", "kg"),
+    ("milligrams", "mg"),
+    ("milligram", "mg"),
+    ("grams", "g"),
+    ("gram", "g"),
+    ("milliliters", "mL"),
+    ("milliliter", "mL"),
+    ("millilitres", "mL"),
+    ("millilitre", "mL"),
+    ("liters", "L"),
+    ("liter", "L"),
+    ("litres", "L"),
+    ("litre", "L"),
+    ("kilobytes", "KB"),
+    ("kilobyte", "KB"),
+    ("megabytes", "MB"),
+    ("megabyte", "MB"),
+    ("gigabytes", "GB"),
+    ("gigabyte", "GB"),
+    ("terabytes", "TB"),
+    ("terabyte", "TB"),
+    ("kilohertz", "kHz"),
+    ("megahertz", "MHz"),
+    ("gigahertz", "GHz"),
+    ("hertz", "Hz"),
+    ("milliseconds", "ms"),
+    ("millisecond", "ms"),
+];
+
+/// Currency names and symbols
+const CURRENCIES: &[(&str, &str, &str)] = &[
+    ("dollar", "dollars", "$"),
+    ("euro", "euros", "€"),
+    ("yen", "yen", "¥"),
+];
+
+/// A number followed by a currency, percent or unit
+fn quantity(words: &[&str]) -> Option<Match> {
+    let number = parse_number(words).filter(|n| !n.ordinal)?;
+    let rest = &words[number.len..];
+    let first = *rest.first()?;
+
+    if let Some(&(_, _, symbol)) = CURRENCIES
+        .iter()
+        .find(|(one, many, _)| first == *one || first == *many)
+    {
+        let mut text = format!("{}{}", symbol, number.text);
+        let mut len = number.len + 1;
+        // "and fifty cents"
+        if number.value.is_some() && rest.get(1) == Some(&"and") {
+            let cents = parse_cardinal(&rest[2..]).filter(|c| !c.ordinal && c.value < 100);
+            if let Some(cents) = cents {
+                if matches!(rest.get(2 + cents.len), Some(&"cent" | &"cents")) {
+                    text = format!("{}.{:02}", text, cents.value);
+                    len += 2 + cents.len;
+                }
+            }
+        }
+        return Some(Match::Replace(text, len));
+    }
+    if matches!(first, "cent" | "cents") && number.value.is_some() {
+        return Some(Match::Replace(format!("{}¢", number.text), number.len + 1));
+    }
+
+    if first == "percent" {
+        return Some(Match::Replace(format!("{}%", number.text), number.len + 1));
+    }
+    if rest.starts_with(&["per", "cent"]) {
+        return Some(Match::Replace(format!("{}%", number.text), number.len + 2));
+    }
+
+    UNITS.iter().find_map(|&(spoken, symbol)| {
+        let unit: Vec<&str> = spoken.split(' ').collect();
+        if !rest.starts_with(&unit) {
+            return None;
+        }
+        let separator = if symbol.starts_with('°') { "" } else { " " };
+        Some(Match::Replace(
+            format!("{}{}{}", number.text, separator, symbol),
+            number.len + unit.len(),
+        ))
+    })
+}
+
+/// A number on its own. Runs of numbers that don't form one ("two three",
+/// "eleven fifteen") are left as spoken.
+fn plain_number(words: &[&str]) -> Option<Match> {
+    if let Some((year, len)) = parse_year(words).filter(|&(_, len)| len > 1) {
+        return Some(Match::Replace(year.to_string(), len));
+    }
+
+    let number = parse_number(words)?;
+    if number.literal {
+        return None;
+    }
+    if words
+        .get(number.len)
+        .is_some_and(|w| number_word(w).is_some())
+    {
+        let run = words
+            .iter()
+            .take_while(|w| number_word(w).is_some() || matches!(**w, "a" | "and" | "point"))
+            .count();
+        return Some(Match::Keep(run));
+    }
+    if number.small {
+        return None;
+    }
+
+    let text = match (number.ordinal, number.value) {
+        (true, Some(value)) => format_ordinal(value),
+        _ => number.text,
+    };
+    Some(Match::Replace(text, number.len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn itn(text: &str) -> String {
+        inverse_text_normalization(text, "en", &["en".to_string()])
+    }
+
+    #[test]
+    fn test_request_example() {
+        assert_eq!(
+            itn("twenty five dollars on march third at three thirty pm"),
+            "$25 on March 3 at 3:30 PM"
+        );
+    }
+
+    #[test]
+    fn test_cardinals() {
+        assert_eq!(itn("twenty five"), "25");
+        assert_eq!(itn("one hundred and five"), "105");
+        assert_eq!(itn("two thousand three hundred forty one"), "2341");
+        assert_eq!(itn("twelve thousand five hundred"), "12,500");
+        assert_eq!(itn("three million"), "3,000,000");
+        assert_eq!(itn("one million two hundred thousand and one"), "1,200,001");
+        assert_eq!(itn("twenty five hundred"), "2500");
+        assert_eq!(itn("a hundred people"), "100 people");
+        assert_eq!(itn("ten of us went"), "10 of us went");
+        assert_eq!(itn("Twenty people came."), "20 people came.");
+        assert_eq!(itn("negative five"), "-5");
+    }
+
+    #[test]
+    fn test_small_numbers_stay_spelled_out() {
+        assert_eq!(itn("I have three cats"), "I have three cats");
+        assert_eq!(itn("one of them"), "one of them");
+        assert_eq!(itn("zero"), "zero");
+        assert_eq!(itn("Five"), "Five");
+    }
+
+    #[test]
+    fn test_number_sequences_left_as_spoken() {
+        assert_eq!(itn("press two three"), "press two three");
+        assert_eq!(itn("eleven fifteen"), "eleven fifteen");
+        assert_eq!(itn("one-two punch"), "one-two punch");
+    }
+
+    #[test]
+    fn test_hyphenated_numbers() {
+        assert_eq!(itn("twenty-five apples"), "25 apples");
+        assert_eq!(itn("forty-two."), "42.");
+        assert_eq!(itn("a well-known fact"), "a well-known fact");
+    }
+
+    #[test]
+    fn test_decimals() {
+        assert_eq!(itn("three point one four"), "3.14");
+        assert_eq!(itn("zero point five"), "0.5");
+        assert_eq!(itn("twelve point oh five"), "12.05");
+        assert_eq!(itn("at one point I left"), "at one point I left");
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_eq!(itn("the twenty first century"), "the 21st century");
+        assert_eq!(itn("the eleventh hour"), "the 11th hour");
+        assert_eq!(itn("twenty second"), "22nd");
+        assert_eq!(itn("thirty third"), "33rd");
+        assert_eq!(itn("twelfth"), "12th");
+        assert_eq!(itn("one hundredth"), "100th");
+        assert_eq!(itn("one hundred and first"), "101st");
+        assert_eq!(itn("first place"), "first place");
+        assert_eq!(itn("the third time"), "the third time");
+    }
+
+    #[test]
+    fn test_money() {
+        assert_eq!(itn("twenty five dollars"), "$25");
+        assert_eq!(itn("one dollar"), "$1");
+        assert_eq!(itn("twenty five dollars and fifty cents"), "$25.50");
+        assert_eq!(itn("three dollars and five cents"), "$3.05");
+        assert_eq!(itn("fifty cents"), "50¢");
+        assert_eq!(itn("ten euros"), "€10");
+        assert_eq!(itn("a thousand yen"), "¥1000");
+        assert_eq!(itn("a hundred dollars"), "$100");
+        assert_eq!(itn("5 dollars"), "$5");
+        assert_eq!(itn("two point five dollars"), "$2.5");
+        assert_eq!(itn("It costs twenty dollars."), "It costs $20.");
+        assert_eq!(itn("ten dollars and some change"), "$10 and some change");
+    }
+
+    #[test]
+    fn test_percentages() {
+        assert_eq!(itn("fifty percent"), "50%");
+        assert_eq!(itn("five per cent"), "5%");
+        assert_eq!(itn("zero point five percent"), "0.5%");
+        assert_eq!(itn("up twelve percent."), "up 12%.");
+        assert_eq!(itn("100 percent"), "100%");
+    }
+
+    #[test]
+    fn test_dates() {
+        assert_eq!(itn("march third"), "March 3");
+        assert_eq!(itn("on may fifth"), "on May 5");
+        assert_eq!(itn("december twenty fifth"), "December 25");
+        assert_eq!(itn("october twelve"), "October 12");
+        assert_eq!(itn("july fourth twenty twenty four"), "July 4, 2024");
+        assert_eq!(itn("June 1st 2023"), "June 1st 2023");
+        assert_eq!(itn("january nineteen ninety nine"), "January 1999");
+        assert_eq!(itn("the third of march"), "the 3rd of March");
+        assert_eq!(
+            itn("the twenty first of september"),
+            "the 21st of September"
+        );
+    }
+
+    #[test]
+    fn test_month_words_used_as_verbs() {
+        assert_eq!(itn("they march five miles"), "they march five miles");
+        assert_eq!(itn("it may two"), "it may two");
+        assert_eq!(itn("we may twenty twenty"), "we may 2020");
+    }
+
+    #[test]
+    fn test_years() {
+        assert_eq!(itn("in twenty twenty four"), "in 2024");
+        assert_eq!(itn("since nineteen eighty four"), "since 1984");
+        assert_eq!(itn("twenty oh five"), "2005");
+        assert_eq!(itn("nineteen hundred"), "1900");
+        assert_eq!(itn("two thousand and five"), "2005");
+    }
+
+    #[test]
+    fn test_times() {
+        assert_eq!(itn("three thirty pm"), "3:30 PM");
+        assert_eq!(itn("at seven am"), "at 7 AM");
+        assert_eq!(itn("ten oh five a m"), "10:05 AM");
+        assert_eq!(itn("at eleven fifteen"), "at 11:15");
+        assert_eq!(itn("at twelve forty five"), "at 12:45");
+        assert_eq!(itn("six o'clock"), "6:00");
+        assert_eq!(itn("at nine p.m. tomorrow"), "at 9 p.m. tomorrow");
+        assert_eq!(itn("at 3 pm"), "at 3 PM");
+        assert_eq!(itn("at five"), "at five");
+        assert_eq!(itn("thirteen pm"), "13 pm");
+    }
+
+    #[test]
+    fn test_phone_numbers() {
+        assert_eq!(itn("five five five one two three four"), "555-1234");
+        assert_eq!(
+            itn("call me at five five five one two three oh four five six"),
+            "call me at 555-123-0456"
+        );
+        assert_eq!(
+            itn("one eight zero zero five five five one two one two"),
+            "1-800-555-1212"
+        );
+        assert_eq!(itn("one two three four five"), "one two three four five");
+    }
+
+    #[test]
+    fn test_units() {
+        assert_eq!(itn("five kilometers"), "5 km");
+        assert_eq!(itn("one point five liters"), "1.5 L");
+        assert_eq!(itn("twenty degrees celsius"), "20°C");
+        assert_eq!(itn("ninety degrees"), "90°");
+        assert_eq!(itn("negative ten degrees"), "-10°");
+        assert_eq!(itn("sixty miles per hour"), "60 mph");
+        assert_eq!(itn("two hundred megabytes"), "200 MB");
+        assert_eq!(itn("one kilogram"), "1 kg");
+        assert_eq!(itn("five hundred milliseconds"), "500 ms");
+        assert_eq!(itn("five miles"), "five miles");
+    }
+
+    #[test]
+    fn test_punctuation_is_kept() {
+        assert_eq!(itn("(twenty five)"), "(25)");
+        assert_eq!(itn("twenty, five"), "20, five");
+        assert_eq!(itn("It was fifty percent, maybe."), "It was 50%, maybe.");
+        assert_eq!(itn("\"twelve\""), "\"12\"");
+    }
+
+    #[test]
+    fn test_text_without_numbers_unchanged() {
+        let text = "Hello, this is a normal sentence - nothing to see!";
+        assert_eq!(itn(text), text);
+        assert_eq!(itn(""), "");
+        assert_eq!(itn("twenty five\nthirty"), "25\n30");
+    }
+
+    #[test]
+    fn test_enabled_languages() {
+        let english = ["en".to_string()];
+        assert_eq!(
+            inverse_text_normalization("twenty five", "auto", &english),
+            "25"
+        );
+        assert_eq!(
+            inverse_text_normalization("twenty five", "en-GB", &english),
+            "25"
+        );
+        assert_eq!(
+            inverse_text_normalization("twenty five", "en", &[]),
+            "twenty five"
+        );
+        assert_eq!(
+            inverse_text_normalization("zwanzig", "de", &["de".to_string()]),
+            "zwanzig"
+        );
+    }
+}
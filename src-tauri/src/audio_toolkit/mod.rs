@@ -1,12 +1,25 @@
 pub mod audio;
+pub mod chinese;
+pub mod chunking;
 pub mod constants;
+pub mod filters;
+pub mod itn;
+pub mod phonetics;
 pub mod text;
 pub mod utils;
 pub mod vad;
 
 pub use audio::{
-    list_input_devices, list_output_devices, save_wav_file, AudioRecorder, CpalDeviceInfo,
+    decode_audio_file, list_input_devices, list_output_devices, read_wav_file, save_wav_file,
+    AudioRecorder, CpalDeviceInfo, RecordingOptions,
+};
+pub use chinese::{convert_chinese_script, engine_language};
+pub use filters::OutputFilters;
+pub use itn::{inverse_text_normalization, ITN_LANGUAGES};
+pub use text::{
+    apply_custom_words, apply_replacements, apply_spoken_commands, builtin_command_vocabulary,
+    filter_transcription_output, CommandAction, CommandVocabulary, ReplacementMatch, SpokenCommand,
+    SpokenCommands, TextReplacement,
 };
-pub use text::{apply_custom_words, filter_transcription_output};
 pub use utils::get_cpal_host;
-pub use vad::{SileroVad, SmoothedVad, VoiceActivityDetector};
+pub use vad::{SileroVad, SmoothedVad, VadParams, VoiceActivityDetector};
@@ -1,98 +1,239 @@
-use natural::phonetics::soundex;
+use crate::audio_toolkit::filters::OutputFilters;
+use crate::audio_toolkit::phonetics::PhoneticAlgorithm;
+use log::warn;
 use once_cell::sync::Lazy;
-use regex::Regex;
+use regex::{NoExpand, Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
 use strsim::levenshtein;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Applies custom word corrections to transcribed text using fuzzy matching
 ///
 /// This function corrects words in the input text by finding the best matches
 /// from a list of custom words using a combination of:
 /// - Levenshtein distance for string similarity
-/// - Soundex phonetic matching for pronunciation similarity
+/// - Phonetic matching for pronunciation similarity, with the algorithm picked
+///   by language (see `PhoneticAlgorithm::for_language`)
+///
+/// Custom entries may span several words ("Kubernetes operator"), so runs of
+/// consecutive words are compared too, with spaces ignored on both sides. A
+/// term can also be heard as more words than it has ("cooper netties
+/// operator"), so a run may have up to `MAX_EXTRA_WORDS` more words than the
+/// entry, but only if it sounds like the entry and none of its words is
+/// shorter than `MIN_FRAGMENT_CHARS`: merging everyday words ("any way" into
+/// "Anyway") would rewrite ordinary speech. Where candidate matches overlap,
+/// the best-scoring one wins.
 ///
 /// # Arguments
 /// * `text` - The input text to correct
 /// * `custom_words` - List of custom words to match against
 /// * `threshold` - Maximum similarity score to accept (0.0 = exact match, 1.0 = any match)
+/// * `language` - Transcription language code (`selected_language`)
 ///
 /// # Returns
 /// The corrected text with custom words applied
-pub fn apply_custom_words(text: &str, custom_words: &[String], threshold: f64) -> String {
+pub fn apply_custom_words(
+    text: &str,
+    custom_words: &[String],
+    threshold: f64,
+    language: &str,
+) -> String {
     if custom_words.is_empty() {
         return text.to_string();
     }
+    let phonetics = PhoneticAlgorithm::for_language(language);
 
-    // Pre-compute lowercase versions to avoid repeated allocations
-    let custom_words_lower: Vec<String> = custom_words.iter().map(|w| w.to_lowercase()).collect();
+    // Pre-compute comparison keys to avoid repeated allocations
+    let entries: Vec<CustomEntry> = custom_words
+        .iter()
+        .enumerate()
+        .filter_map(|(source, word)| CustomEntry::new(source, word))
+        .collect();
+    let max_words = entries.iter().map(|e| e.word_count).max().unwrap_or(1) + MAX_EXTRA_WORDS;
 
     let words: Vec<&str> = text.split_whitespace().collect();
-    let mut corrected_words = Vec::new();
+    let cleaned_words: Vec<String> = words.iter().map(|word| clean_word(word)).collect();
 
-    for word in words {
-        let cleaned_word = word
-            .trim_matches(|c: char| !c.is_alphabetic())
-            .to_lowercase();
+    let mut candidates = Vec::new();
+    for start in 0..words.len() {
+        let longest = max_words.min(words.len() - start);
+        let mut key = String::new();
+        let mut shortest_word = usize::MAX;
 
-        if cleaned_word.is_empty() {
-            corrected_words.push(word.to_string());
-            continue;
+        for len in 1..=longest {
+            let index = start + len - 1;
+            if cleaned_words[index].is_empty() {
+                break;
+            }
+            // Punctuation between words means they don't belong together
+            if len > 1 {
+                let (_, previous_suffix) = extract_punctuation(words[index - 1]);
+                let (prefix, _) = extract_punctuation(words[index]);
+                if !previous_suffix.is_empty() || !prefix.is_empty() {
+                    break;
+                }
+            }
+            key.push_str(&cleaned_words[index]);
+            shortest_word = shortest_word.min(cleaned_words[index].chars().count());
+
+            // Skip extremely long phrases to avoid performance issues
+            if key.chars().count() > 50 {
+                break;
+            }
+
+            for (entry_index, entry) in entries.iter().enumerate() {
+                let score = if len <= entry.word_count {
+                    match_score(&key, &entry.key, phonetics)
+                } else if len <= entry.word_count + MAX_EXTRA_WORDS
+                    && shortest_word >= MIN_FRAGMENT_CHARS
+                {
+                    split_match_score(&key, &entry.key, phonetics)
+                } else {
+                    None
+                };
+                if let Some(score) = score {
+                    // Accept if the score is good enough (configurable threshold)
+                    if score < threshold {
+                        candidates.push(WordMatch {
+                            start,
+                            len,
+                            entry: entry_index,
+                            score,
+                        });
+                    }
+                }
+            }
         }
+    }
 
-        // Skip extremely long words to avoid performance issues
-        if cleaned_word.len() > 50 {
-            corrected_words.push(word.to_string());
-            continue;
+    // Best scores first; on a tie the longer span explains more of the text
+    candidates.sort_by(|a, b| {
+        a.score
+            .total_cmp(&b.score)
+            .then(b.len.cmp(&a.len))
+            .then(a.start.cmp(&b.start))
+    });
+    let mut matched: Vec<Option<&WordMatch>> = vec![None; words.len()];
+    for candidate in &candidates {
+        let span = candidate.start..candidate.start + candidate.len;
+        if matched[span.clone()].iter().all(Option::is_none) {
+            matched[span].fill(Some(candidate));
         }
+    }
 
-        let mut best_match: Option<&String> = None;
-        let mut best_score = f64::MAX;
+    let mut corrected_words = Vec::new();
+    let mut i = 0;
+    while i < words.len() {
+        let Some(found) = matched[i] else {
+            corrected_words.push(words[i].to_string());
+            i += 1;
+            continue;
+        };
+        let first = words[found.start];
+        let last = words[found.start + found.len - 1];
 
-        for (i, custom_word_lower) in custom_words_lower.iter().enumerate() {
-            // Skip if lengths are too different (optimization)
-            let len_diff = (cleaned_word.len() as i32 - custom_word_lower.len() as i32).abs();
-            if len_diff > 5 {
-                continue;
-            }
+        // Preserve the original case pattern as much as possible
+        let corrected = preserve_case_pattern(first, &custom_words[entries[found.entry].source]);
 
-            // Calculate Levenshtein distance (normalized by length)
-            let levenshtein_dist = levenshtein(&cleaned_word, custom_word_lower);
-            let max_len = cleaned_word.len().max(custom_word_lower.len()) as f64;
-            let levenshtein_score = if max_len > 0.0 {
-                levenshtein_dist as f64 / max_len
-            } else {
-                1.0
-            };
+        // Preserve punctuation from original words
+        let (prefix, _) = extract_punctuation(first);
+        let (_, suffix) = extract_punctuation(last);
+        corrected_words.push(format!("{}{}{}", prefix, corrected, suffix));
+        i += found.len;
+    }
 
-            // Calculate phonetic similarity using Soundex
-            let phonetic_match = soundex(&cleaned_word, custom_word_lower);
+    corrected_words.join(" ")
+}
 
-            // Combine scores: favor phonetic matches, but also consider string similarity
-            let combined_score = if phonetic_match {
-                levenshtein_score * 0.3 // Give significant boost to phonetic matches
-            } else {
-                levenshtein_score
-            };
+/// Words a run may have beyond its entry's, for a term heard as several words
+const MAX_EXTRA_WORDS: usize = 2;
 
-            // Accept if the score is good enough (configurable threshold)
-            if combined_score < threshold && combined_score < best_score {
-                best_match = Some(&custom_words[i]);
-                best_score = combined_score;
-            }
-        }
+/// Shortest word allowed in a run longer than its entry. A misheard term
+/// breaks into fragments like "cooper netties"; short words like "any way" or
+/// "not booked" are more likely just what was said.
+const MIN_FRAGMENT_CHARS: usize = 4;
 
-        if let Some(replacement) = best_match {
-            // Preserve the original case pattern as much as possible
-            let corrected = preserve_case_pattern(word, replacement);
+/// A custom word or phrase prepared for comparison
+struct CustomEntry {
+    /// Index into the caller's custom word list
+    source: usize,
+    /// Lowercase with whitespace removed
+    key: String,
+    word_count: usize,
+}
 
-            // Preserve punctuation from original word
-            let (prefix, suffix) = extract_punctuation(word);
-            corrected_words.push(format!("{}{}{}", prefix, corrected, suffix));
-        } else {
-            corrected_words.push(word.to_string());
+impl CustomEntry {
+    fn new(source: usize, word: &str) -> Option<Self> {
+        let words: Vec<&str> = word.split_whitespace().collect();
+        if words.is_empty() {
+            return None;
         }
+        Some(Self {
+            source,
+            key: words.concat().to_lowercase(),
+            word_count: words.len(),
+        })
     }
+}
 
-    corrected_words.join(" ")
+/// A run of transcript words that could be replaced by a custom entry
+#[derive(Clone, Copy)]
+struct WordMatch {
+    start: usize,
+    len: usize,
+    entry: usize,
+    score: f64,
+}
+
+/// Similarity score between a transcript key and a custom entry key
+/// (0.0 = identical); None when the lengths are too different to compare
+fn match_score(candidate: &str, custom: &str, phonetics: PhoneticAlgorithm) -> Option<f64> {
+    // Lengths in characters, like the edit distance
+    let candidate_len = candidate.chars().count();
+    let custom_len = custom.chars().count();
+
+    // Skip if lengths are too different (optimization)
+    if candidate_len.abs_diff(custom_len) > 5 {
+        return None;
+    }
+
+    let levenshtein_score = levenshtein_score(candidate, custom);
+
+    // Calculate phonetic similarity in the transcription language. Phonetic
+    // codes are truncated, so a much shorter word ("any" for "Anyway") can
+    // share one without sounding alike
+    let phonetic_match =
+        candidate_len.abs_diff(custom_len) <= 2 && phonetics.sounds_alike(candidate, custom);
+
+    // Combine scores: favor phonetic matches, but also consider string similarity
+    Some(if phonetic_match {
+        levenshtein_score * 0.3 // Give significant boost to phonetic matches
+    } else {
+        levenshtein_score
+    })
+}
+
+/// Like `match_score`, for a run with more words than the entry: only a run
+/// that sounds like the entry qualifies
+fn split_match_score(candidate: &str, custom: &str, phonetics: PhoneticAlgorithm) -> Option<f64> {
+    if candidate.chars().count().abs_diff(custom.chars().count()) > 5
+        || !phonetics.sounds_alike(candidate, custom)
+    {
+        return None;
+    }
+    Some(levenshtein_score(candidate, custom) * 0.3)
+}
+
+/// Levenshtein distance normalized by the longer length in characters
+fn levenshtein_score(candidate: &str, custom: &str) -> f64 {
+    let max_len = candidate.chars().count().max(custom.chars().count()) as f64;
+    if max_len > 0.0 {
+        levenshtein(candidate, custom) as f64 / max_len
+    } else {
+        1.0
+    }
 }
 
 /// Preserves the case pattern of the original word when applying a replacement
@@ -110,35 +251,34 @@ fn preserve_case_pattern(original: &str, replacement: &str) -> String {
     }
 }
 
-/// Extracts punctuation prefix and suffix from a word
+/// Extracts punctuation prefix and suffix from a word.
+///
+/// Works on grapheme clusters, so multibyte punctuation ("¿", "…", "「") and
+/// letters with combining accents stay intact.
 fn extract_punctuation(word: &str) -> (&str, &str) {
-    let prefix_end = word.chars().take_while(|c| !c.is_alphabetic()).count();
+    let is_letter = |grapheme: &str| grapheme.chars().next().is_some_and(char::is_alphabetic);
+
+    let prefix_end = word
+        .grapheme_indices(true)
+        .find(|(_, grapheme)| is_letter(grapheme))
+        .map_or(word.len(), |(i, _)| i);
     let suffix_start = word
-        .char_indices()
+        .grapheme_indices(true)
         .rev()
-        .take_while(|(_, c)| !c.is_alphabetic())
-        .count();
-
-    let prefix = if prefix_end > 0 {
-        &word[..prefix_end]
-    } else {
-        ""
-    };
+        .find(|(_, grapheme)| is_letter(grapheme))
+        .map_or(0, |(i, grapheme)| i + grapheme.len());
 
-    let suffix = if suffix_start > 0 {
-        &word[word.len() - suffix_start..]
-    } else {
-        ""
-    };
-
-    (prefix, suffix)
+    (&word[..prefix_end], &word[suffix_start..])
 }
 
-/// Filler words to remove from transcriptions
-const FILLER_WORDS: &[&str] = &[
-    "uh", "um", "uhm", "umm", "uhh", "uhhh", "ah", "eh", "hmm", "hm", "mmm", "mm", "mh", "ha",
-    "ehh",
-];
+/// Lowercase letters of a word without its surrounding punctuation
+fn clean_word(word: &str) -> String {
+    let (prefix, suffix) = extract_punctuation(word);
+    if prefix.len() == word.len() {
+        return String::new();
+    }
+    word[prefix.len()..word.len() - suffix.len()].to_lowercase()
+}
 
 static MULTI_SPACE_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s{2,}").unwrap());
 
@@ -182,39 +322,43 @@ fn collapse_stutters(text: &str) -> String {
     result.join(" ")
 }
 
-/// Pre-compiled filler word patterns (built lazily)
-static FILLER_PATTERNS: Lazy<Vec<Regex>> = Lazy::new(|| {
-    FILLER_WORDS
-        .iter()
-        .map(|word| {
-            // Match filler word with word boundaries, optionally followed by comma or period
-            Regex::new(&format!(r"(?i)\b{}\b[,.]?", regex::escape(word))).unwrap()
-        })
-        .collect()
-});
+/// Matches any of `words` as a whole word, optionally followed by a comma or period
+fn filler_pattern(words: &[String]) -> Option<Regex> {
+    if words.is_empty() {
+        return None;
+    }
+    let alternatives: Vec<String> = words.iter().map(|word| regex::escape(word)).collect();
+    Regex::new(&format!(r"(?i)\b(?:{})\b[,.]?", alternatives.join("|"))).ok()
+}
 
 /// Filters transcription output by removing filler words and stutter artifacts.
 ///
 /// This function cleans up raw transcription text by:
-/// 1. Removing filler words (uh, um, hmm, etc.)
+/// 1. Removing the language's filler words (uh, um, ähm, euh, etc.)
 /// 2. Collapsing repeated 1-2 letter stutters (e.g., "wh wh wh" -> "wh")
 /// 3. Cleaning up excess whitespace
 ///
+/// Known hallucinations are kept; they only lower `estimate_confidence`.
+///
 /// # Arguments
 /// * `text` - The raw transcription text to filter
+/// * `filters` - Which steps to run and the user's extra entries
+/// * `language` - Selected language code, or "auto"
 ///
 /// # Returns
 /// The filtered text with filler words and stutters removed
-pub fn filter_transcription_output(text: &str) -> String {
+pub fn filter_transcription_output(text: &str, filters: &OutputFilters, language: &str) -> String {
     let mut filtered = text.to_string();
 
     // Remove filler words
-    for pattern in FILLER_PATTERNS.iter() {
+    if let Some(pattern) = filler_pattern(&filters.filler_words(language)) {
         filtered = pattern.replace_all(&filtered, "").to_string();
     }
 
     // Collapse repeated 1-2 letter words (stutter artifacts like "wh wh wh wh")
-    filtered = collapse_stutters(&filtered);
+    if filters.collapse_stutters {
+        filtered = collapse_stutters(&filtered);
+    }
 
     // Clean up multiple spaces to single space
     filtered = MULTI_SPACE_PATTERN.replace_all(&filtered, " ").to_string();
@@ -223,6 +367,401 @@ pub fn filter_transcription_output(text: &str) -> String {
     filtered.trim().to_string()
 }
 
+/// How a replacement's pattern is matched against the transcript
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ReplacementMatch {
+    /// Literal phrase, case-sensitive
+    Exact,
+    /// Literal phrase, ignoring case
+    #[default]
+    CaseInsensitive,
+    /// Regular expression; the replacement may reference groups as `$1`
+    Regex,
+}
+
+/// A user rule turning a spoken phrase into written text ("voice see" → "Voyc")
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct TextReplacement {
+    pub pattern: String,
+    pub replacement: String,
+    #[serde(default)]
+    pub match_type: ReplacementMatch,
+    /// Don't match inside longer words (literal phrases only; regexes bring their own `\b`)
+    #[serde(default = "default_whole_word")]
+    pub whole_word: bool,
+}
+
+fn default_whole_word() -> bool {
+    true
+}
+
+impl TextReplacement {
+    /// Compile the rule; literal phrases match any whitespace between their words
+    pub fn to_regex(&self) -> Result<Regex, regex::Error> {
+        if self.pattern.trim().is_empty() {
+            return Err(regex::Error::Syntax("empty pattern".to_string()));
+        }
+        if self.match_type == ReplacementMatch::Regex {
+            return Regex::new(&self.pattern);
+        }
+
+        let phrase = self.pattern.trim();
+        let mut pattern = phrase
+            .split_whitespace()
+            .map(regex::escape)
+            .collect::<Vec<_>>()
+            .join(r"\s+");
+        // `\b` next to a non-word character would demand a word character there
+        if self.whole_word {
+            if phrase.starts_with(is_word_char) {
+                pattern.insert_str(0, r"\b");
+            }
+            if phrase.ends_with(is_word_char) {
+                pattern.push_str(r"\b");
+            }
+        }
+        RegexBuilder::new(&pattern)
+            .case_insensitive(self.match_type == ReplacementMatch::CaseInsensitive)
+            .build()
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Applies replacement rules in order, each to the output of the previous one.
+///
+/// Rules that fail to compile are skipped (they are validated when saved).
+pub fn apply_replacements(text: &str, replacements: &[TextReplacement]) -> String {
+    let mut result = text.to_string();
+    for rule in replacements {
+        let regex = match rule.to_regex() {
+            Ok(regex) => regex,
+            Err(e) => {
+                warn!("Skipping replacement '{}': {}", rule.pattern, e);
+                continue;
+            }
+        };
+        result = if rule.match_type == ReplacementMatch::Regex {
+            regex.replace_all(&result, rule.replacement.as_str())
+        } else {
+            regex.replace_all(&result, NoExpand(&rule.replacement))
+        }
+        .into_owned();
+    }
+    result
+}
+
+/// What a spoken formatting command does
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Type)]
+#[serde(tag = "type", content = "text", rename_all = "snake_case")]
+pub enum CommandAction {
+    /// Punctuation attached to the previous word, e.g. "," or "?"
+    Punctuation(String),
+    NewLine,
+    NewParagraph,
+    OpenQuote,
+    CloseQuote,
+    /// Starts a "• " item on a new line
+    BulletPoint,
+    /// Uppercase the following words until `CapsOff`
+    CapsOn,
+    CapsOff,
+}
+
+/// A spoken phrase ("new line") and the formatting it stands for
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Type)]
+pub struct SpokenCommand {
+    pub phrase: String,
+    pub action: CommandAction,
+}
+
+/// Formatting commands of one language
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Type)]
+pub struct CommandVocabulary {
+    /// Word that makes the following command literal ("literal comma"); empty for none
+    pub escape_word: String,
+    pub commands: Vec<SpokenCommand>,
+}
+
+/// User settings for spoken formatting commands
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Type, Default)]
+#[serde(default)]
+pub struct SpokenCommands {
+    /// Off by default: "period" or "colon" are ordinary words in most dictation
+    pub enabled: bool,
+    /// Vocabularies by language code, replacing the built-in one
+    pub vocabularies: HashMap<String, CommandVocabulary>,
+}
+
+impl SpokenCommands {
+    /// Vocabulary for a language (auto-detect uses English); None when disabled
+    /// or no vocabulary exists for the language
+    pub fn vocabulary(&self, language: &str) -> Option<CommandVocabulary> {
+        if !self.enabled {
+            return None;
+        }
+        let language = if language == "auto" {
+            "en"
+        } else {
+            language.split('-').next().unwrap_or(language)
+        };
+        self.vocabularies
+            .get(language)
+            .cloned()
+            .or_else(|| builtin_command_vocabulary(language))
+    }
+}
+
+/// Built-in formatting commands for a language
+pub fn builtin_command_vocabulary(language: &str) -> Option<CommandVocabulary> {
+    use CommandAction::*;
+
+    let mark = |text: &str| Punctuation(text.to_string());
+    let (escape_word, commands) = match language {
+        "en" => (
+            "literal",
+            vec![
+                ("comma", mark(",")),
+                ("period", mark(".")),
+                ("full stop", mark(".")),
+                ("question mark", mark("?")),
+                ("exclamation mark", mark("!")),
+                ("exclamation point", mark("!")),
+                ("colon", mark(":")),
+                ("semicolon", mark(";")),
+                ("new line", NewLine),
+                ("new paragraph", NewParagraph),
+                ("open quote", OpenQuote),
+                ("close quote", CloseQuote),
+                ("end quote", CloseQuote),
+                ("bullet point", BulletPoint),
+                ("all caps", CapsOn),
+                ("end caps", CapsOff),
+            ],
+        ),
+        "de" => (
+            "wörtlich",
+            vec![
+                ("komma", mark(",")),
+                ("punkt", mark(".")),
+                ("fragezeichen", mark("?")),
+                ("ausrufezeichen", mark("!")),
+                ("doppelpunkt", mark(":")),
+                ("semikolon", mark(";")),
+                ("neue zeile", NewLine),
+                ("neuer absatz", NewParagraph),
+                ("anführungszeichen auf", OpenQuote),
+                ("anführungszeichen zu", CloseQuote),
+                ("aufzählungspunkt", BulletPoint),
+                ("großbuchstaben an", CapsOn),
+                ("großbuchstaben aus", CapsOff),
+            ],
+        ),
+        "fr" => (
+            "littéralement",
+            vec![
+                ("virgule", mark(",")),
+                ("point", mark(".")),
+                ("point d'interrogation", mark("?")),
+                ("point d'exclamation", mark("!")),
+                ("deux points", mark(":")),
+                ("point-virgule", mark(";")),
+                ("à la ligne", NewLine),
+                ("nouveau paragraphe", NewParagraph),
+                ("ouvrez les guillemets", OpenQuote),
+                ("fermez les guillemets", CloseQuote),
+                ("puce", BulletPoint),
+                ("tout en majuscules", CapsOn),
+                ("fin des majuscules", CapsOff),
+            ],
+        ),
+        "es" => (
+            "literal",
+            vec![
+                ("coma", mark(",")),
+                ("punto", mark(".")),
+                ("punto y coma", mark(";")),
+                ("dos puntos", mark(":")),
+                ("nueva línea", NewLine),
+                ("nuevo párrafo", NewParagraph),
+                ("abrir comillas", OpenQuote),
+                ("cerrar comillas", CloseQuote),
+                ("viñeta", BulletPoint),
+                ("todo en mayúsculas", CapsOn),
+                ("fin de mayúsculas", CapsOff),
+            ],
+        ),
+        _ => return None,
+    };
+
+    Some(CommandVocabulary {
+        escape_word: escape_word.to_string(),
+        commands: commands
+            .into_iter()
+            .map(|(phrase, action)| SpokenCommand {
+                phrase: phrase.to_string(),
+                action,
+            })
+            .collect(),
+    })
+}
+
+/// Output being assembled from words and formatting commands
+#[derive(Default)]
+struct Formatter {
+    output: String,
+    caps: bool,
+    capitalize_next: bool,
+    /// No space before the next word (after an opening quote or bullet)
+    attach_next: bool,
+}
+
+impl Formatter {
+    fn push_space(&mut self) {
+        if !self.output.is_empty() && !self.attach_next && !self.output.ends_with('\n') {
+            self.output.push(' ');
+        }
+    }
+
+    fn push_word(&mut self, word: &str) {
+        self.push_space();
+        if self.caps {
+            self.output.push_str(&word.to_uppercase());
+        } else if self.capitalize_next {
+            self.output.push_str(&capitalize_first(word));
+        } else {
+            self.output.push_str(word);
+        }
+        self.attach_next = false;
+        self.capitalize_next = false;
+    }
+
+    fn trim_trailing_spaces(&mut self) {
+        let len = self.output.trim_end_matches(' ').len();
+        self.output.truncate(len);
+    }
+
+    fn line_break(&mut self, breaks: &str) {
+        self.trim_trailing_spaces();
+        self.output.push_str(breaks);
+        self.attach_next = false;
+        self.capitalize_next = true;
+    }
+
+    fn apply(&mut self, action: &CommandAction) {
+        match action {
+            CommandAction::Punctuation(mark) => {
+                // Engines often punctuate around the spoken command already
+                let kept = self
+                    .output
+                    .trim_end_matches([',', '.', ';', ':', '!', '?'])
+                    .len();
+                self.output.truncate(kept);
+                self.output.push_str(mark);
+                self.capitalize_next = mark.ends_with(['.', '?', '!']);
+            }
+            CommandAction::NewLine => self.line_break("\n"),
+            CommandAction::NewParagraph => self.line_break("\n\n"),
+            CommandAction::OpenQuote => {
+                self.push_space();
+                self.output.push('"');
+                self.attach_next = true;
+            }
+            CommandAction::CloseQuote => self.output.push('"'),
+            CommandAction::BulletPoint => {
+                self.trim_trailing_spaces();
+                if !self.output.is_empty() && !self.output.ends_with('\n') {
+                    self.output.push('\n');
+                }
+                self.output.push_str("• ");
+                self.attach_next = true;
+                self.capitalize_next = true;
+            }
+            CommandAction::CapsOn => self.caps = true,
+            CommandAction::CapsOff => self.caps = false,
+        }
+    }
+}
+
+fn capitalize_first(word: &str) -> String {
+    match word.char_indices().find(|(_, c)| c.is_alphabetic()) {
+        Some((i, c)) => format!(
+            "{}{}{}",
+            &word[..i],
+            c.to_uppercase(),
+            &word[i + c.len_utf8()..]
+        ),
+        None => word.to_string(),
+    }
+}
+
+/// Longest command whose phrase starts `words`, with its length in words
+fn match_command<'a>(
+    commands: &'a [(Vec<String>, &'a CommandAction)],
+    words: &[String],
+) -> Option<(usize, &'a CommandAction)> {
+    commands
+        .iter()
+        .find(|(phrase, _)| words.starts_with(phrase))
+        .map(|(phrase, action)| (phrase.len(), *action))
+}
+
+/// Turns spoken formatting commands into punctuation, line breaks and casing.
+///
+/// Commands are matched case-insensitively, ignoring punctuation the engine put
+/// around them ("Hello, comma, world" -> "Hello, world"). The escape word keeps
+/// the next command as text ("literal comma" -> "comma").
+pub fn apply_spoken_commands(text: &str, vocabulary: &CommandVocabulary) -> String {
+    let mut commands: Vec<(Vec<String>, &CommandAction)> = vocabulary
+        .commands
+        .iter()
+        .map(|command| {
+            let phrase: Vec<String> = command
+                .phrase
+                .split_whitespace()
+                .map(str::to_lowercase)
+                .collect();
+            (phrase, &command.action)
+        })
+        .filter(|(phrase, _)| !phrase.is_empty())
+        .collect();
+    // Longest phrase first, so "punto y coma" wins over "punto"
+    commands.sort_by_key(|(phrase, _)| std::cmp::Reverse(phrase.len()));
+
+    let escape_word = vocabulary.escape_word.trim().to_lowercase();
+    let tokens: Vec<&str> = text.split(' ').filter(|t| !t.is_empty()).collect();
+    let words: Vec<String> = tokens.iter().map(|t| clean_word(t)).collect();
+
+    let mut formatter = Formatter::default();
+    let mut i = 0;
+    while i < tokens.len() {
+        if !escape_word.is_empty() && words[i] == escape_word {
+            if let Some((len, _)) = match_command(&commands, &words[i + 1..]) {
+                for token in &tokens[i + 1..i + 1 + len] {
+                    formatter.push_word(token);
+                }
+                i += 1 + len;
+                continue;
+            }
+        }
+        match match_command(&commands, &words[i..]) {
+            Some((len, action)) => {
+                formatter.apply(action);
+                i += len;
+            }
+            None => {
+                formatter.push_word(tokens[i]);
+                i += 1;
+            }
+        }
+    }
+
+    formatter.output
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -231,7 +770,7 @@ mod tests {
     fn test_apply_custom_words_exact_match() {
         let text = "hello world";
         let custom_words = vec!["Hello".to_string(), "World".to_string()];
-        let result = apply_custom_words(text, &custom_words, 0.5);
+        let result = apply_custom_words(text, &custom_words, 0.5, "en");
         assert_eq!(result, "Hello World");
     }
 
@@ -239,10 +778,84 @@ mod tests {
     fn test_apply_custom_words_fuzzy_match() {
         let text = "helo wrold";
         let custom_words = vec!["hello".to_string(), "world".to_string()];
-        let result = apply_custom_words(text, &custom_words, 0.5);
+        let result = apply_custom_words(text, &custom_words, 0.5, "en");
         assert_eq!(result, "hello world");
     }
 
+    #[test]
+    fn test_apply_custom_words_multi_word_entry() {
+        let custom_words = vec!["Kubernetes operator".to_string()];
+        let result = apply_custom_words(
+            "Deploy the kubernetis operater, then test.",
+            &custom_words,
+            0.3,
+            "en",
+        );
+        assert_eq!(result, "Deploy the Kubernetes operator, then test.");
+    }
+
+    #[test]
+    fn test_apply_custom_words_does_not_merge_words() {
+        // Each run would match once its spaces are dropped, but these are
+        // ordinary words, not a misheard custom word
+        let custom_words = vec!["Notebook".to_string()];
+        assert_eq!(
+            apply_custom_words("I have not booked it", &custom_words, 0.18, "en"),
+            "I have not booked it"
+        );
+        let custom_words = vec!["Anyway".to_string()];
+        assert_eq!(
+            apply_custom_words("in any way you like", &custom_words, 0.18, "en"),
+            "in any way you like"
+        );
+    }
+
+    #[test]
+    fn test_apply_custom_words_term_heard_as_more_words() {
+        let custom_words = vec!["Kubernetes operator".to_string()];
+        assert_eq!(
+            apply_custom_words("cooper netties operator", &custom_words, 0.18, "en"),
+            "Kubernetes operator"
+        );
+    }
+
+    #[test]
+    fn test_apply_custom_words_merged_and_split_words() {
+        let custom_words = vec!["Tauri Specta".to_string(), "Voyc".to_string()];
+        assert_eq!(
+            apply_custom_words("use tory specta here", &custom_words, 0.18, "en"),
+            "use Tauri Specta here"
+        );
+        assert_eq!(
+            apply_custom_words("Tourispecta bindings", &custom_words, 0.18, "en"),
+            "Tauri Specta bindings"
+        );
+    }
+
+    #[test]
+    fn test_apply_custom_words_punctuation_breaks_phrases() {
+        let custom_words = vec!["Tauri Specta".to_string()];
+        assert_eq!(
+            apply_custom_words("tory, specta", &custom_words, 0.18, "en"),
+            "tory, specta"
+        );
+    }
+
+    #[test]
+    fn test_apply_custom_words_overlaps_resolved_by_score() {
+        // Both entries match exactly; the phrase covers more words and wins
+        let custom_words = vec!["Specta".to_string(), "Tauri Specta".to_string()];
+        assert_eq!(
+            apply_custom_words("tauri specta", &custom_words, 0.18, "en"),
+            "Tauri Specta"
+        );
+        // The phrase scores worse than the exact "specta" and can't claim it
+        assert_eq!(
+            apply_custom_words("tory specta", &custom_words, 0.1, "en"),
+            "tory Specta"
+        );
+    }
+
     #[test]
     fn test_preserve_case_pattern() {
         assert_eq!(preserve_case_pattern("HELLO", "world"), "WORLD");
@@ -257,88 +870,474 @@ mod tests {
         assert_eq!(extract_punctuation("...hello..."), ("...", "..."));
     }
 
+    #[test]
+    fn test_extract_punctuation_multibyte() {
+        assert_eq!(extract_punctuation("¿qué?"), ("¿", "?"));
+        assert_eq!(extract_punctuation("«Straße»…"), ("«", "»…"));
+        assert_eq!(extract_punctuation("「東京」"), ("「", "」"));
+        // A combining accent belongs to its letter, not to the suffix
+        assert_eq!(extract_punctuation("cafe\u{301}!"), ("", "!"));
+        assert_eq!(clean_word("«Łódź»"), "łódź");
+        assert_eq!(clean_word("…"), "");
+    }
+
+    #[test]
+    fn test_apply_custom_words_accented_input() {
+        let custom_words = vec!["Kraków".to_string(), "Zürich".to_string()];
+        assert_eq!(
+            apply_custom_words("Lecę do Krakow…", &custom_words, 0.18, "pl"),
+            "Lecę do Kraków…"
+        );
+        assert_eq!(
+            apply_custom_words("«zurich» liegt am See", &custom_words, 0.18, "de"),
+            "«Zürich» liegt am See"
+        );
+    }
+
+    #[test]
+    fn test_apply_custom_words_cjk_input() {
+        let custom_words = vec!["東京都".to_string()];
+        // One character off in three is too far at the default threshold
+        // (byte lengths made it look like one in nine)
+        assert_eq!(
+            apply_custom_words("「東京道」", &custom_words, 0.18, "ja"),
+            "「東京道」"
+        );
+        assert_eq!(
+            apply_custom_words("「東京道」", &custom_words, 0.4, "ja"),
+            "「東京都」"
+        );
+    }
+
+    #[test]
+    fn test_apply_custom_words_phonetics_by_language() {
+        // Cologne phonetics: Maier and Meyer sound the same in German
+        let custom_words = vec!["Meyer".to_string()];
+        assert_eq!(
+            apply_custom_words("Herr Maier kommt", &custom_words, 0.18, "de"),
+            "Herr Meyer kommt"
+        );
+        // French has no phonetic matching; edit distance alone is too far
+        assert_eq!(
+            apply_custom_words("Herr Maier kommt", &custom_words, 0.18, "fr"),
+            "Herr Maier kommt"
+        );
+
+        // Double Metaphone: "schmit" and "Smith" share a code in English
+        let custom_words = vec!["Smith".to_string()];
+        assert_eq!(
+            apply_custom_words("ask schmit", &custom_words, 0.18, "en"),
+            "ask Smith"
+        );
+        assert_eq!(
+            apply_custom_words("ask schmit", &custom_words, 0.18, "fr"),
+            "ask schmit"
+        );
+    }
+
     #[test]
     fn test_empty_custom_words() {
         let text = "hello world";
         let custom_words = vec![];
-        let result = apply_custom_words(text, &custom_words, 0.5);
+        let result = apply_custom_words(text, &custom_words, 0.5, "en");
         assert_eq!(result, "hello world");
     }
 
     #[test]
     fn test_filter_filler_words() {
         let text = "So um I was thinking uh about this";
-        let result = filter_transcription_output(text);
+        let result = filter_transcription_output(text, &OutputFilters::default(), "en");
         assert_eq!(result, "So I was thinking about this");
     }
 
     #[test]
     fn test_filter_filler_words_case_insensitive() {
         let text = "UM this is UH a test";
-        let result = filter_transcription_output(text);
+        let result = filter_transcription_output(text, &OutputFilters::default(), "en");
         assert_eq!(result, "this is a test");
     }
 
     #[test]
     fn test_filter_filler_words_with_punctuation() {
         let text = "Well, um, I think, uh. that's right";
-        let result = filter_transcription_output(text);
+        let result = filter_transcription_output(text, &OutputFilters::default(), "en");
         assert_eq!(result, "Well, I think, that's right");
     }
 
     #[test]
     fn test_filter_cleans_whitespace() {
         let text = "Hello    world   test";
-        let result = filter_transcription_output(text);
+        let result = filter_transcription_output(text, &OutputFilters::default(), "en");
         assert_eq!(result, "Hello world test");
     }
 
     #[test]
     fn test_filter_trims() {
         let text = "  Hello world  ";
-        let result = filter_transcription_output(text);
+        let result = filter_transcription_output(text, &OutputFilters::default(), "en");
         assert_eq!(result, "Hello world");
     }
 
     #[test]
     fn test_filter_combined() {
         let text = "  Um, so I was, uh, thinking about this  ";
-        let result = filter_transcription_output(text);
+        let result = filter_transcription_output(text, &OutputFilters::default(), "en");
         assert_eq!(result, "so I was, thinking about this");
     }
 
     #[test]
     fn test_filter_preserves_valid_text() {
         let text = "This is a completely normal sentence.";
-        let result = filter_transcription_output(text);
+        let result = filter_transcription_output(text, &OutputFilters::default(), "en");
         assert_eq!(result, "This is a completely normal sentence.");
     }
 
     #[test]
     fn test_filter_stutter_collapse() {
         let text = "w wh wh wh wh wh wh wh wh wh why";
-        let result = filter_transcription_output(text);
+        let result = filter_transcription_output(text, &OutputFilters::default(), "en");
         assert_eq!(result, "w wh why");
     }
 
     #[test]
     fn test_filter_stutter_short_words() {
         let text = "I I I I think so so so so";
-        let result = filter_transcription_output(text);
+        let result = filter_transcription_output(text, &OutputFilters::default(), "en");
         assert_eq!(result, "I think so");
     }
 
     #[test]
     fn test_filter_stutter_mixed_case() {
         let text = "No NO no NO no";
-        let result = filter_transcription_output(text);
+        let result = filter_transcription_output(text, &OutputFilters::default(), "en");
         assert_eq!(result, "No");
     }
 
+    fn rule(pattern: &str, replacement: &str, match_type: ReplacementMatch) -> TextReplacement {
+        TextReplacement {
+            pattern: pattern.to_string(),
+            replacement: replacement.to_string(),
+            match_type,
+            whole_word: true,
+        }
+    }
+
+    #[test]
+    fn test_replacement_exact() {
+        let rules = [rule("Voice See", "Voyc", ReplacementMatch::Exact)];
+        assert_eq!(
+            apply_replacements("Open Voice See now", &rules),
+            "Open Voyc now"
+        );
+        assert_eq!(
+            apply_replacements("open voice see now", &rules),
+            "open voice see now"
+        );
+    }
+
+    #[test]
+    fn test_replacement_case_insensitive() {
+        let rules = [rule("voice see", "Voyc", ReplacementMatch::CaseInsensitive)];
+        assert_eq!(
+            apply_replacements("Voice See is open. I like voice  see.", &rules),
+            "Voyc is open. I like Voyc."
+        );
+    }
+
+    #[test]
+    fn test_replacement_regex() {
+        let rules = [rule(
+            r"(?i)\bticket (\d+)\b",
+            "#$1",
+            ReplacementMatch::Regex,
+        )];
+        assert_eq!(
+            apply_replacements("Fixes Ticket 42 and ticket 7", &rules),
+            "Fixes #42 and #7"
+        );
+    }
+
+    #[test]
+    fn test_replacement_literal_does_not_expand_groups() {
+        let rules = [rule("price", "$1 each", ReplacementMatch::CaseInsensitive)];
+        assert_eq!(apply_replacements("the price", &rules), "the $1 each");
+    }
+
+    #[test]
+    fn test_replacement_word_boundaries() {
+        let rules = [rule(
+            "my email",
+            "me@example.com",
+            ReplacementMatch::CaseInsensitive,
+        )];
+        assert_eq!(
+            apply_replacements("Send it to my email, please", &rules),
+            "Send it to me@example.com, please"
+        );
+        assert_eq!(
+            apply_replacements("dummy emailing list", &rules),
+            "dummy emailing list"
+        );
+
+        let inside_words = TextReplacement {
+            whole_word: false,
+            ..rule("colour", "color", ReplacementMatch::CaseInsensitive)
+        };
+        assert_eq!(apply_replacements("Colourful", &[inside_words]), "colorful");
+    }
+
+    #[test]
+    fn test_replacement_non_word_edges() {
+        let rules = [
+            rule(
+                "shrug emoji",
+                r"¯\_(ツ)_/¯",
+                ReplacementMatch::CaseInsensitive,
+            ),
+            rule("c++", "C++", ReplacementMatch::CaseInsensitive),
+        ];
+        assert_eq!(
+            apply_replacements("Shrug emoji. I write c++ daily", &rules),
+            r"¯\_(ツ)_/¯. I write C++ daily"
+        );
+    }
+
+    #[test]
+    fn test_replacements_apply_in_order() {
+        let rules = [
+            rule(
+                "as soon as possible",
+                "asap",
+                ReplacementMatch::CaseInsensitive,
+            ),
+            rule("asap", "ASAP", ReplacementMatch::Exact),
+        ];
+        assert_eq!(
+            apply_replacements("Reply as soon as possible", &rules),
+            "Reply ASAP"
+        );
+    }
+
+    #[test]
+    fn test_invalid_replacements_are_skipped() {
+        let rules = [
+            rule("(unclosed", "x", ReplacementMatch::Regex),
+            rule("  ", "x", ReplacementMatch::CaseInsensitive),
+            rule("hello", "hi", ReplacementMatch::CaseInsensitive),
+        ];
+        assert!(rules[0].to_regex().is_err());
+        assert!(rules[1].to_regex().is_err());
+        assert_eq!(apply_replacements("hello there", &rules), "hi there");
+    }
+
     #[test]
     fn test_filter_stutter_preserves_two_repetitions() {
         let text = "no no is fine";
-        let result = filter_transcription_output(text);
+        let result = filter_transcription_output(text, &OutputFilters::default(), "en");
         assert_eq!(result, "no no is fine");
     }
+
+    #[test]
+    fn test_filter_uses_language_fillers() {
+        let filters = OutputFilters::default();
+        assert_eq!(
+            filter_transcription_output("Ich äh, wollte ähm sagen", &filters, "de"),
+            "Ich wollte sagen"
+        );
+        assert_eq!(
+            filter_transcription_output("Alors euh, on y va", &filters, "fr"),
+            "Alors on y va"
+        );
+        // "um" is an English filler but a German word
+        assert_eq!(
+            filter_transcription_output("Es geht um Zeit", &filters, "de"),
+            "Es geht um Zeit"
+        );
+    }
+
+    #[test]
+    fn test_filter_extra_fillers() {
+        let filters = OutputFilters {
+            extra_filler_words: vec!["you know".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            filter_transcription_output("It was, you know, fine um", &filters, "en"),
+            "It was, fine"
+        );
+    }
+
+    #[test]
+    fn test_filter_toggles() {
+        let filters = OutputFilters {
+            remove_filler_words: false,
+            collapse_stutters: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            filter_transcription_output("um I I I think", &filters, "en"),
+            "um I I I think"
+        );
+    }
+
+    #[test]
+    fn test_filter_keeps_hallucination_phrases() {
+        // Real dictation can consist of a known phrase; only confidence drops
+        let filters = OutputFilters::default();
+        assert_eq!(
+            filter_transcription_output("Untertitel im Auftrag des ZDF, 2017", &filters, "de"),
+            "Untertitel im Auftrag des ZDF, 2017"
+        );
+        assert_eq!(
+            filter_transcription_output("Thank you for watching!", &filters, "auto"),
+            "Thank you for watching!"
+        );
+        assert_eq!(
+            filter_transcription_output("Subscribe", &filters, "en"),
+            "Subscribe"
+        );
+        assert_eq!(
+            filter_transcription_output("Um, see you next time.", &filters, "en"),
+            "see you next time."
+        );
+    }
+
+    fn english_commands() -> CommandVocabulary {
+        builtin_command_vocabulary("en").unwrap()
+    }
+
+    #[test]
+    fn test_spoken_punctuation() {
+        let vocabulary = english_commands();
+        assert_eq!(
+            apply_spoken_commands("hello comma how are you question mark", &vocabulary),
+            "hello, how are you?"
+        );
+        assert_eq!(
+            apply_spoken_commands("it works period thanks exclamation point", &vocabulary),
+            "it works. Thanks!"
+        );
+        assert_eq!(
+            apply_spoken_commands("Hello Comma world", &vocabulary),
+            "Hello, world"
+        );
+    }
+
+    #[test]
+    fn test_spoken_punctuation_replaces_engine_punctuation() {
+        assert_eq!(
+            apply_spoken_commands(
+                "Dear John, comma, how are you? Question mark.",
+                &english_commands()
+            ),
+            "Dear John, how are you?"
+        );
+    }
+
+    #[test]
+    fn test_spoken_line_breaks() {
+        assert_eq!(
+            apply_spoken_commands(
+                "first line new line second line new paragraph third",
+                &english_commands()
+            ),
+            "first line\nSecond line\n\nThird"
+        );
+    }
+
+    #[test]
+    fn test_spoken_quotes() {
+        assert_eq!(
+            apply_spoken_commands(
+                "he said open quote hello there close quote and left",
+                &english_commands()
+            ),
+            "he said \"hello there\" and left"
+        );
+    }
+
+    #[test]
+    fn test_spoken_bullet_points() {
+        assert_eq!(
+            apply_spoken_commands(
+                "shopping list colon bullet point milk bullet point eggs",
+                &english_commands()
+            ),
+            "shopping list:\n• Milk\n• Eggs"
+        );
+    }
+
+    #[test]
+    fn test_spoken_caps() {
+        let vocabulary = english_commands();
+        assert_eq!(
+            apply_spoken_commands("use all caps nasa end caps data", &vocabulary),
+            "use NASA data"
+        );
+        assert_eq!(
+            apply_spoken_commands("all caps to the end", &vocabulary),
+            "TO THE END"
+        );
+    }
+
+    #[test]
+    fn test_spoken_command_escape_word() {
+        let vocabulary = english_commands();
+        assert_eq!(
+            apply_spoken_commands("type literal comma here", &vocabulary),
+            "type comma here"
+        );
+        assert_eq!(
+            apply_spoken_commands("literal new line", &vocabulary),
+            "new line"
+        );
+        assert_eq!(
+            apply_spoken_commands("the literal meaning", &vocabulary),
+            "the literal meaning"
+        );
+    }
+
+    #[test]
+    fn test_spoken_commands_per_language() {
+        let german = builtin_command_vocabulary("de").unwrap();
+        assert_eq!(
+            apply_spoken_commands("hallo komma wie geht's fragezeichen", &german),
+            "hallo, wie geht's?"
+        );
+        // Longest phrase wins: "punto y coma" rather than "punto"
+        let spanish = builtin_command_vocabulary("es").unwrap();
+        assert_eq!(
+            apply_spoken_commands("uno punto y coma dos punto", &spanish),
+            "uno; dos."
+        );
+        assert!(builtin_command_vocabulary("sw").is_none());
+    }
+
+    #[test]
+    fn test_spoken_commands_settings() {
+        let mut settings = SpokenCommands::default();
+        assert!(settings.vocabulary("en").is_none());
+
+        settings.enabled = true;
+        assert_eq!(settings.vocabulary("auto"), Some(english_commands()));
+        assert_eq!(settings.vocabulary("en-GB"), Some(english_commands()));
+        assert!(settings.vocabulary("sw").is_none());
+
+        // A user vocabulary replaces the built-in one
+        settings.vocabularies.insert(
+            "en".to_string(),
+            CommandVocabulary {
+                escape_word: String::new(),
+                commands: vec![SpokenCommand {
+                    phrase: "Full Stop".to_string(),
+                    action: CommandAction::Punctuation(".".to_string()),
+                }],
+            },
+        );
+        let custom = settings.vocabulary("en").unwrap();
+        assert_eq!(
+            apply_spoken_commands("the trial period ends full stop", &custom),
+            "the trial period ends."
+        );
+    }
 }
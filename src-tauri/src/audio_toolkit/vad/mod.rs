@@ -14,6 +14,19 @@ impl<'a> VadFrame<'a> {
     }
 }
 
+/// Tunable VAD parameters that can be pushed into a running recorder
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VadParams {
+    /// Speech probability above which a frame counts as voice (0.0-1.0)
+    pub threshold: f32,
+    /// Frames of audio kept before speech onset
+    pub prefill_frames: usize,
+    /// Frames of silence tolerated before speech is considered over
+    pub hangover_frames: usize,
+    /// Consecutive voice frames required to start speech
+    pub onset_frames: usize,
+}
+
 pub trait VoiceActivityDetector: Send + Sync {
     /// Primary streaming API: feed one 30-ms frame, get keep/drop decision.
     fn push_frame<'a>(&'a mut self, frame: &'a [f32]) -> Result<VadFrame<'a>>;
@@ -23,10 +36,19 @@ pub trait VoiceActivityDetector: Send + Sync {
     }
 
     fn reset(&mut self) {}
+
+    /// Unsmoothed voice decision for the last pushed frame, for detectors that
+    /// keep frames past the end of speech (prefill, hangover).
+    fn last_raw_decision(&self) -> Option<bool> {
+        None
+    }
+
+    /// Apply new parameters; detectors ignore the fields they don't use.
+    fn set_params(&mut self, _params: &VadParams) {}
 }
 
 mod silero;
 mod smoothed;
 
-pub use silero::SileroVad;
+pub use silero::{SileroVad, SILERO_FRAME_SAMPLES};
 pub use smoothed::SmoothedVad;
@@ -3,11 +3,12 @@ use std::path::Path;
 
 use vad_rs::Vad;
 
-use super::{VadFrame, VoiceActivityDetector};
+use super::{VadFrame, VadParams, VoiceActivityDetector};
 use crate::audio_toolkit::constants;
 
 const SILERO_FRAME_MS: u32 = 30;
-const SILERO_FRAME_SAMPLES: usize =
+/// Samples per frame the Silero model expects (30 ms at 16 kHz)
+pub const SILERO_FRAME_SAMPLES: usize =
     (constants::WHISPER_SAMPLE_RATE * SILERO_FRAME_MS / 1000) as usize;
 
 pub struct SileroVad {
@@ -49,4 +50,56 @@ impl VoiceActivityDetector for SileroVad {
             Ok(VadFrame::Noise)
         }
     }
+
+    fn set_params(&mut self, params: &VadParams) {
+        if (0.0..=1.0).contains(&params.threshold) {
+            self.threshold = params.threshold;
+        } else {
+            log::warn!("Ignoring out-of-range VAD threshold: {}", params.threshold);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model_path() -> std::path::PathBuf {
+        std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("resources")
+            .join("models")
+            .join("silero_vad_v4.onnx")
+    }
+
+    fn params(threshold: f32) -> VadParams {
+        VadParams {
+            threshold,
+            prefill_frames: 0,
+            hangover_frames: 0,
+            onset_frames: 1,
+        }
+    }
+
+    #[test]
+    fn test_set_params_updates_threshold() {
+        let mut vad = SileroVad::new(model_path(), 0.3).unwrap();
+        vad.set_params(&params(0.6));
+        assert_eq!(vad.threshold, 0.6);
+    }
+
+    #[test]
+    fn test_set_params_ignores_out_of_range_threshold() {
+        let mut vad = SileroVad::new(model_path(), 0.3).unwrap();
+        vad.set_params(&params(1.5));
+        vad.set_params(&params(-0.1));
+        assert_eq!(vad.threshold, 0.3);
+    }
+
+    #[test]
+    fn test_silence_is_noise() {
+        let mut vad = SileroVad::new(model_path(), 0.3).unwrap();
+        let frame = vec![0.0f32; SILERO_FRAME_SAMPLES];
+        assert!(!vad.is_voice(&frame).unwrap());
+        assert!(vad.push_frame(&frame[1..]).is_err());
+    }
 }
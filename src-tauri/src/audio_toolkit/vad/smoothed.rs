@@ -1,4 +1,4 @@
-use super::{VadFrame, VoiceActivityDetector};
+use super::{VadFrame, VadParams, VoiceActivityDetector};
 use anyhow::Result;
 use std::collections::VecDeque;
 
@@ -12,6 +12,7 @@ pub struct SmoothedVad {
     hangover_counter: usize,
     onset_counter: usize,
     in_speech: bool,
+    last_voice: bool,
 
     temp_out: Vec<f32>,
 }
@@ -32,6 +33,7 @@ impl SmoothedVad {
             hangover_counter: 0,
             onset_counter: 0,
             in_speech: false,
+            last_voice: false,
             temp_out: Vec::new(),
         }
     }
@@ -47,6 +49,7 @@ impl VoiceActivityDetector for SmoothedVad {
 
         // 2. Delegate to the wrapped boolean VAD
         let is_voice = self.inner_vad.is_voice(frame)?;
+        self.last_voice = is_voice;
 
         match (self.in_speech, is_voice) {
             // Potential start of speech - need to accumulate onset frames
@@ -100,6 +103,114 @@ impl VoiceActivityDetector for SmoothedVad {
         self.hangover_counter = 0;
         self.onset_counter = 0;
         self.in_speech = false;
+        self.last_voice = false;
         self.temp_out.clear();
     }
+
+    fn last_raw_decision(&self) -> Option<bool> {
+        Some(self.last_voice)
+    }
+
+    fn set_params(&mut self, params: &VadParams) {
+        self.prefill_frames = params.prefill_frames;
+        self.hangover_frames = params.hangover_frames;
+        self.onset_frames = params.onset_frames;
+        // Don't let a shorter hangover leave the current counter above the new limit
+        self.hangover_counter = self.hangover_counter.min(self.hangover_frames);
+        self.inner_vad.set_params(params);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    /// Replays a fixed sequence of voice decisions and records pushed params
+    struct ScriptedVad {
+        decisions: VecDeque<bool>,
+        params: Arc<Mutex<Option<VadParams>>>,
+    }
+
+    impl ScriptedVad {
+        fn new(decisions: &[bool]) -> Self {
+            Self {
+                decisions: decisions.iter().copied().collect(),
+                params: Arc::default(),
+            }
+        }
+    }
+
+    impl VoiceActivityDetector for ScriptedVad {
+        fn push_frame<'a>(&'a mut self, frame: &'a [f32]) -> Result<VadFrame<'a>> {
+            if self.decisions.pop_front().unwrap_or(false) {
+                Ok(VadFrame::Speech(frame))
+            } else {
+                Ok(VadFrame::Noise)
+            }
+        }
+
+        fn set_params(&mut self, params: &VadParams) {
+            *self.params.lock().unwrap() = Some(*params);
+        }
+    }
+
+    fn run(vad: &mut SmoothedVad, frames: usize) -> Vec<(bool, Option<bool>)> {
+        let frame = [0.0f32; 4];
+        (0..frames)
+            .map(|_| {
+                let kept = vad.push_frame(&frame).unwrap().is_speech();
+                (kept, vad.last_raw_decision())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_raw_decision_ignores_hangover() {
+        let inner = ScriptedVad::new(&[true, true, false, false, false]);
+        let mut vad = SmoothedVad::new(Box::new(inner), 0, 2, 1);
+        assert_eq!(
+            run(&mut vad, 5),
+            vec![
+                (true, Some(true)),
+                (true, Some(true)),
+                (true, Some(false)),
+                (true, Some(false)),
+                (false, Some(false)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_set_params_forwards_to_inner_vad() {
+        let inner = ScriptedVad::new(&[]);
+        let pushed = inner.params.clone();
+        let mut vad = SmoothedVad::new(Box::new(inner), 15, 15, 2);
+        let params = VadParams {
+            threshold: 0.6,
+            prefill_frames: 5,
+            hangover_frames: 3,
+            onset_frames: 1,
+        };
+        vad.set_params(&params);
+        assert_eq!(*pushed.lock().unwrap(), Some(params));
+        assert_eq!(vad.prefill_frames, 5);
+        assert_eq!(vad.hangover_frames, 3);
+        assert_eq!(vad.onset_frames, 1);
+    }
+
+    #[test]
+    fn test_shorter_hangover_applies_mid_speech() {
+        let inner = ScriptedVad::new(&[true, false, false, false, false]);
+        let mut vad = SmoothedVad::new(Box::new(inner), 0, 10, 1);
+        assert!(run(&mut vad, 1)[0].0);
+        vad.set_params(&VadParams {
+            threshold: 0.3,
+            prefill_frames: 0,
+            hangover_frames: 2,
+            onset_frames: 1,
+        });
+        let kept: Vec<bool> = run(&mut vad, 4).into_iter().map(|(kept, _)| kept).collect();
+        assert_eq!(kept, vec![true, true, false, false]);
+    }
 }
@@ -0,0 +1,452 @@
+//! Command-line control of the running instance
+//!
+//! `voyc toggle`, `voyc start`, `voyc stop` and `voyc cancel` are forwarded to
+//! the primary instance through the single-instance plugin, so compositor
+//! keybinds (`bindsym $mod+d exec voyc toggle`) drive the `DictationController`.
+//! When no instance is running, the invoking process becomes the primary
+//! instance and runs the command once its managers are up.
+//!
+//! `voyc status`, `voyc last` and `voyc transcribe <file>` need an answer,
+//! which the single-instance plugin can't deliver back to the second process,
+//! so they query the primary instance over the D-Bus service instead and print
+//! the result. `transcribe` therefore needs a running instance (`voyc --headless`
+//! is enough): the file is decoded and transcribed locally by that instance's
+//! model, so nothing is uploaded.
+//!
+//! `voyc --headless` starts the primary instance as a background daemon:
+//! only the managers run, no main window or recording overlay is created and
+//! state changes go to the log and the D-Bus signals.
+
+use crate::dictation::DictationController;
+use crate::transcript::TranscriptFormat;
+use log::{error, info, warn};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+/// Binding used for dictations started from the command line
+const CLI_BINDING_ID: &str = "transcribe";
+
+pub const USAGE: &str = "Usage: voyc [COMMAND]
+
+Commands:
+  toggle          Start dictation, or stop it if recording
+  start           Start dictation
+  stop            Stop dictation and insert the text
+  cancel          Discard the current recording
+                  (these start Voyc first if it isn't running)
+  status [--json] Print the dictation state of the running instance
+  last [--json]   Print the most recent transcript
+  transcribe FILE [--format text|srt|vtt|json]
+                  Transcribe an audio file (WAV, FLAC, MP3, OGG) with the
+                  local model of the running instance (start one with
+                  `voyc --headless`); --json is short for --format json
+  help            Print this help
+
+Options:
+  --headless      Run without the main window and recording overlay
+  --no-tray       Don't create the tray icon
+
+Without a command, Voyc starts (or shows the running instance).";
+
+/// How the primary instance starts up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StartupOptions {
+    /// Skip the main window and recording overlay
+    pub headless: bool,
+    /// Create the tray icon
+    pub tray: bool,
+}
+
+impl StartupOptions {
+    pub fn from_args<S: AsRef<str>>(args: &[S]) -> Self {
+        let has_flag = |flag: &str| args.iter().skip(1).any(|a| a.as_ref() == flag);
+        Self {
+            headless: has_flag("--headless"),
+            tray: !has_flag("--no-tray"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliCommand {
+    Toggle,
+    Start,
+    Stop,
+    Cancel,
+    Status {
+        json: bool,
+    },
+    Last {
+        json: bool,
+    },
+    Transcribe {
+        path: PathBuf,
+        format: TranscriptFormat,
+    },
+    Help,
+}
+
+impl CliCommand {
+    /// Commands that print a result in the invoking process instead of being forwarded
+    pub fn is_query(&self) -> bool {
+        matches!(
+            self,
+            CliCommand::Status { .. }
+                | CliCommand::Last { .. }
+                | CliCommand::Transcribe { .. }
+                | CliCommand::Help
+        )
+    }
+}
+
+/// Parse process arguments (including the program name in `args[0]`).
+///
+/// Returns `Ok(None)` when no subcommand was given. Options that start with
+/// `--` and aren't a subcommand's own flags are left for the app to handle.
+pub fn parse_args<S: AsRef<str>>(args: &[S]) -> Result<Option<CliCommand>, String> {
+    let args: Vec<&str> = args.iter().skip(1).map(AsRef::as_ref).collect();
+    if args.iter().any(|a| *a == "--help" || *a == "-h") {
+        return Ok(Some(CliCommand::Help));
+    }
+    let Some(position) = args.iter().position(|a| !a.starts_with('-')) else {
+        return Ok(None);
+    };
+    let mut json = false;
+    let mut format = None;
+    let mut operands = Vec::new();
+    let mut rest = args[position + 1..].iter();
+    while let Some(arg) = rest.next() {
+        match *arg {
+            "--json" => json = true,
+            "--format" => format = Some(*rest.next().ok_or("--format needs a value")?),
+            arg => {
+                if let Some(value) = arg.strip_prefix("--format=") {
+                    format = Some(value);
+                } else if !arg.starts_with('-') {
+                    operands.push(arg);
+                }
+            }
+        }
+    }
+    let mut operands = operands.into_iter();
+
+    let command = match args[position] {
+        "toggle" => CliCommand::Toggle,
+        "start" => CliCommand::Start,
+        "stop" => CliCommand::Stop,
+        "cancel" => CliCommand::Cancel,
+        "status" => CliCommand::Status { json },
+        "last" => CliCommand::Last { json },
+        "transcribe" => {
+            let path = operands.next().ok_or("transcribe needs an audio file")?;
+            let format = match format {
+                Some(value) => TranscriptFormat::parse(value)
+                    .ok_or_else(|| format!("Unknown transcript format: {}", value))?,
+                None if json => TranscriptFormat::Json,
+                None => TranscriptFormat::Text,
+            };
+            CliCommand::Transcribe {
+                path: PathBuf::from(path),
+                format,
+            }
+        }
+        "help" => CliCommand::Help,
+        other => return Err(format!("Unknown command: {}", other)),
+    };
+
+    if let Some(unexpected) = operands.next() {
+        return Err(format!("Unexpected argument: {}", unexpected));
+    }
+    Ok(Some(command))
+}
+
+/// Handle a command forwarded from a second instance.
+///
+/// Returns `false` when the arguments held no command, so the caller falls
+/// back to showing the main window.
+pub fn handle_forwarded_args(app: &AppHandle, args: &[String]) -> bool {
+    let command = match parse_args(args) {
+        Ok(Some(command)) => command,
+        Ok(None) => return false,
+        Err(e) => {
+            warn!("Ignoring command line from second instance: {}", e);
+            return false;
+        }
+    };
+
+    // Queries were answered over D-Bus by the second instance itself
+    if command.is_query() {
+        return true;
+    }
+
+    info!("Received command from second instance: {:?}", command);
+    run_command(app, command);
+    true
+}
+
+/// Run a dictation command on the `DictationController`
+pub fn run_command(app: &AppHandle, command: CliCommand) {
+    let Some(controller) = app.try_state::<Arc<DictationController>>() else {
+        warn!("DictationController not ready, ignoring {:?}", command);
+        return;
+    };
+    let controller = controller.inner().clone();
+
+    tauri::async_runtime::spawn(async move {
+        let result = match command {
+            CliCommand::Toggle => controller.toggle_active_dictation(CLI_BINDING_ID).await,
+            CliCommand::Start => tauri::async_runtime::spawn_blocking(move || {
+                controller.start_dictation(CLI_BINDING_ID)
+            })
+            .await
+            .unwrap_or_else(|e| Err(e.to_string())),
+            CliCommand::Stop => controller.stop_active_dictation().await.map(|_| ()),
+            CliCommand::Cancel => {
+                controller.cancel_dictation();
+                Ok(())
+            }
+            CliCommand::Status { .. }
+            | CliCommand::Last { .. }
+            | CliCommand::Transcribe { .. }
+            | CliCommand::Help => Ok(()),
+        };
+        if let Err(e) = result {
+            error!("Command line dictation command failed: {}", e);
+        }
+    });
+}
+
+/// Answer a query command in the invoking process.
+///
+/// `status`, `last` and `transcribe` ask the running instance over D-Bus. Returns the
+/// process exit code.
+pub fn run_query(command: &CliCommand) -> i32 {
+    if *command == CliCommand::Help {
+        println!("{}", USAGE);
+        return 0;
+    }
+    query_running_instance(command)
+}
+
+#[cfg(target_os = "linux")]
+fn query_running_instance(command: &CliCommand) -> i32 {
+    use crate::dbus_service::{BUS_NAME, OBJECT_PATH};
+
+    /// No instance owns the bus name (or there is no session bus at all)
+    fn is_not_running(e: &zbus::Error) -> bool {
+        match e {
+            zbus::Error::MethodError(name, _, _) => matches!(
+                name.as_str(),
+                "org.freedesktop.DBus.Error.ServiceUnknown"
+                    | "org.freedesktop.DBus.Error.NameHasNoOwner"
+            ),
+            zbus::Error::Address(_) | zbus::Error::InputOutput(_) => true,
+            _ => false,
+        }
+    }
+
+    fn call<B>(method: &str, body: &B) -> zbus::Result<zbus::message::Message>
+    where
+        B: serde::Serialize + zbus::zvariant::DynamicType,
+    {
+        let connection = zbus::blocking::Connection::session()?;
+        connection.call_method(Some(BUS_NAME), OBJECT_PATH, Some(BUS_NAME), method, body)
+    }
+
+    match command {
+        CliCommand::Status { json } => match call("GetState", &()) {
+            Ok(reply) => {
+                let state: String = reply.body().deserialize().unwrap_or_default();
+                if *json {
+                    println!("{}", serde_json::json!({ "running": true, "state": state }));
+                } else {
+                    println!("{}", state);
+                }
+                0
+            }
+            Err(_) => {
+                if *json {
+                    println!("{}", serde_json::json!({ "running": false, "state": null }));
+                } else {
+                    println!("not running");
+                }
+                1
+            }
+        },
+        CliCommand::Last { json } => match call("GetLastTranscript", &()) {
+            Ok(reply) => {
+                let (text, history_id): (String, i64) =
+                    reply.body().deserialize().unwrap_or_default();
+                if *json {
+                    let history_id = (history_id >= 0).then_some(history_id);
+                    println!(
+                        "{}",
+                        serde_json::json!({ "text": text, "history_id": history_id })
+                    );
+                } else {
+                    println!("{}", text);
+                }
+                0
+            }
+            Err(e) => {
+                eprintln!("Voyc is not running or unreachable: {}", e);
+                1
+            }
+        },
+        CliCommand::Transcribe { path, format } => {
+            // The running instance has its own working directory
+            let path = match std::fs::canonicalize(path) {
+                Ok(path) => path,
+                Err(e) => {
+                    eprintln!("{}: {}", path.display(), e);
+                    return 1;
+                }
+            };
+            let body = (path.to_string_lossy().to_string(), format.extension());
+            match call("TranscribeFileAs", &body) {
+                Ok(reply) => {
+                    let output: String = reply.body().deserialize().unwrap_or_default();
+                    println!("{}", output.trim_end());
+                    0
+                }
+                Err(e) if is_not_running(&e) => {
+                    eprintln!(
+                        "Voyc is not running; `voyc transcribe` uses the running instance's model. \
+                         Start it with `voyc --headless` and try again."
+                    );
+                    1
+                }
+                Err(e) => {
+                    eprintln!("Transcription failed: {}", e);
+                    1
+                }
+            }
+        }
+        _ => 0,
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn query_running_instance(_command: &CliCommand) -> i32 {
+    eprintln!("status, last and transcribe are only supported on Linux");
+    2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Option<CliCommand>, String> {
+        let mut full = vec!["voyc"];
+        full.extend_from_slice(args);
+        parse_args(&full)
+    }
+
+    #[test]
+    fn test_no_command() {
+        assert_eq!(parse(&[]), Ok(None));
+        assert_eq!(parse(&["--some-flag"]), Ok(None));
+    }
+
+    #[test]
+    fn test_dictation_commands() {
+        assert_eq!(parse(&["toggle"]), Ok(Some(CliCommand::Toggle)));
+        assert_eq!(parse(&["start"]), Ok(Some(CliCommand::Start)));
+        assert_eq!(parse(&["stop"]), Ok(Some(CliCommand::Stop)));
+        assert_eq!(parse(&["cancel"]), Ok(Some(CliCommand::Cancel)));
+    }
+
+    #[test]
+    fn test_query_commands_with_json_flag() {
+        assert_eq!(
+            parse(&["status"]),
+            Ok(Some(CliCommand::Status { json: false }))
+        );
+        assert_eq!(
+            parse(&["status", "--json"]),
+            Ok(Some(CliCommand::Status { json: true }))
+        );
+        assert_eq!(
+            parse(&["last", "--json"]),
+            Ok(Some(CliCommand::Last { json: true }))
+        );
+        assert!(CliCommand::Last { json: false }.is_query());
+        assert!(!CliCommand::Toggle.is_query());
+    }
+
+    #[test]
+    fn test_leading_flags_are_skipped() {
+        assert_eq!(
+            parse(&["--verbose", "toggle"]),
+            Ok(Some(CliCommand::Toggle))
+        );
+    }
+
+    #[test]
+    fn test_help() {
+        assert_eq!(parse(&["help"]), Ok(Some(CliCommand::Help)));
+        assert_eq!(parse(&["status", "--help"]), Ok(Some(CliCommand::Help)));
+        assert_eq!(parse(&["-h"]), Ok(Some(CliCommand::Help)));
+    }
+
+    #[test]
+    fn test_startup_options() {
+        let options = StartupOptions::from_args(&["voyc"]);
+        assert_eq!(
+            options,
+            StartupOptions {
+                headless: false,
+                tray: true
+            }
+        );
+
+        let options = StartupOptions::from_args(&["voyc", "--headless", "--no-tray"]);
+        assert_eq!(
+            options,
+            StartupOptions {
+                headless: true,
+                tray: false
+            }
+        );
+
+        // Startup flags aren't subcommands
+        assert_eq!(parse(&["--headless"]), Ok(None));
+    }
+
+    #[test]
+    fn test_transcribe_command() {
+        let transcribe = |path: &str, format| {
+            Ok(Some(CliCommand::Transcribe {
+                path: PathBuf::from(path),
+                format,
+            }))
+        };
+        assert_eq!(
+            parse(&["transcribe", "memo.ogg"]),
+            transcribe("memo.ogg", TranscriptFormat::Text)
+        );
+        assert_eq!(
+            parse(&["transcribe", "memo.ogg", "--json"]),
+            transcribe("memo.ogg", TranscriptFormat::Json)
+        );
+        assert_eq!(
+            parse(&["transcribe", "--format", "srt", "talk.mp3"]),
+            transcribe("talk.mp3", TranscriptFormat::Srt)
+        );
+        assert_eq!(
+            parse(&["transcribe", "talk.mp3", "--format=vtt"]),
+            transcribe("talk.mp3", TranscriptFormat::Vtt)
+        );
+        assert!(parse(&["transcribe"]).is_err());
+        assert!(parse(&["transcribe", "a.wav", "b.wav"]).is_err());
+        assert!(parse(&["transcribe", "a.wav", "--format", "docx"]).is_err());
+        assert!(parse(&["transcribe", "a.wav", "--format"]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_commands() {
+        assert!(parse(&["dance"]).is_err());
+        assert!(parse(&["toggle", "now"]).is_err());
+    }
+}
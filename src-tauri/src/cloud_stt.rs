@@ -3,6 +3,8 @@
 //! Provides fallback transcription via cloud providers (ElevenLabs, OpenAI)
 //! when local transcription confidence is below threshold.
 
+use crate::audio_toolkit::engine_language;
+use crate::audio_toolkit::filters::is_hallucination;
 use log::{debug, info};
 use reqwest::header::AUTHORIZATION;
 use reqwest::multipart::{Form, Part};
@@ -168,13 +170,7 @@ impl CloudSttClient {
 
         // Add language hint if provided
         if let Some(lang) = language {
-            // Normalize Chinese language codes
-            let normalized_lang = if lang == "zh-Hans" || lang == "zh-Hant" {
-                "zh"
-            } else {
-                lang
-            };
-            form = form.text("language", normalized_lang.to_string());
+            form = form.text("language", engine_language(lang).to_string());
         }
 
         let response = self
@@ -226,13 +222,7 @@ impl CloudSttClient {
 
         // Add language hint if provided
         if let Some(lang) = language {
-            // Normalize Chinese language codes
-            let normalized_lang = if lang == "zh-Hans" || lang == "zh-Hant" {
-                "zh"
-            } else {
-                lang
-            };
-            form = form.text("language_code", normalized_lang.to_string());
+            form = form.text("language_code", engine_language(lang).to_string());
         }
 
         let response = self
@@ -271,14 +261,21 @@ impl Default for CloudSttClient {
     }
 }
 
+/// Punctuation runs that mark hallucinations in any language
+const SUSPICIOUS_MARKERS: &[&str] = &["...", "!!!", "???"];
+
 /// Estimate transcription confidence based on heuristics
 ///
 /// Since the local transcription engine doesn't provide confidence scores,
 /// we estimate based on output characteristics:
 /// - Empty or very short output suggests low confidence
 /// - High ratio of special characters or numbers may indicate errors
-/// - Presence of common hallucination patterns reduces confidence
-pub fn estimate_confidence(text: &str, audio_duration_secs: f32) -> f32 {
+/// - Presence of common hallucination patterns reduces confidence, a
+///   transcript that is nothing but a known phrase most of all
+///
+/// `hallucinations` are the phrases for the transcription language, see
+/// `OutputFilters::hallucinations`.
+pub fn estimate_confidence(text: &str, audio_duration_secs: f32, hallucinations: &[String]) -> f32 {
     if text.is_empty() {
         return 0.0;
     }
@@ -306,21 +303,16 @@ pub fn estimate_confidence(text: &str, audio_duration_secs: f32) -> f32 {
 
     // Penalty for common hallucination patterns
     let text_lower = text.to_lowercase();
-    let hallucination_patterns = [
-        "thank you for watching",
-        "subscribe",
-        "like and share",
-        "see you next time",
-        "...",
-        "!!!",
-        "???",
-    ];
-
-    for pattern in hallucination_patterns {
-        if text_lower.contains(pattern) {
-            confidence *= 0.5;
-            break;
-        }
+    let is_suspicious = SUSPICIOUS_MARKERS
+        .iter()
+        .any(|marker| text_lower.contains(marker))
+        || hallucinations
+            .iter()
+            .any(|phrase| text_lower.contains(phrase.as_str()));
+    if is_hallucination(text, hallucinations) {
+        confidence *= 0.2;
+    } else if is_suspicious {
+        confidence *= 0.5;
     }
 
     // Penalty for excessive repetition (stuttering/looping hallucination)
@@ -375,27 +367,56 @@ fn samples_to_wav(samples: &[f32], sample_rate: u32) -> Result<Vec<u8>, String>
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::audio_toolkit::OutputFilters;
+
+    fn english() -> Vec<String> {
+        OutputFilters::default().hallucinations("en")
+    }
 
     #[test]
     fn test_estimate_confidence_empty() {
-        assert_eq!(estimate_confidence("", 5.0), 0.0);
+        assert_eq!(estimate_confidence("", 5.0, &[]), 0.0);
     }
 
     #[test]
     fn test_estimate_confidence_normal() {
-        let confidence = estimate_confidence("Hello world, this is a test.", 3.0);
+        let confidence = estimate_confidence("Hello world, this is a test.", 3.0, &english());
         assert!(confidence > 0.8);
     }
 
     #[test]
     fn test_estimate_confidence_hallucination() {
-        let confidence = estimate_confidence("Thank you for watching", 2.0);
+        let confidence = estimate_confidence("Thank you for watching", 2.0, &english());
         assert!(confidence < 0.6);
     }
 
+    #[test]
+    fn test_estimate_confidence_hallucination_by_language() {
+        let german = OutputFilters::default().hallucinations("de");
+        let text = "Untertitel im Auftrag des ZDF für funk, 2017";
+        assert!(estimate_confidence(text, 3.0, &german) < 0.6);
+        assert!(estimate_confidence(text, 3.0, &english()) > 0.8);
+        assert!(estimate_confidence("Wait...", 1.0, &[]) < 0.6);
+    }
+
+    #[test]
+    fn test_estimate_confidence_whole_hallucination() {
+        let whole = estimate_confidence("Thank you for watching!", 2.0, &english());
+        let contained = estimate_confidence("Thank you for watching my demo", 2.0, &english());
+        assert!(whole < 0.3);
+        assert!(whole < contained);
+    }
+
+    #[test]
+    fn test_estimate_confidence_generic_phrases() {
+        // Ordinary sign-offs are not hallucination phrases
+        assert!(estimate_confidence("See you next time", 1.5, &english()) > 0.8);
+        assert!(estimate_confidence("Please subscribe to the newsletter", 2.0, &english()) > 0.8);
+    }
+
     #[test]
     fn test_estimate_confidence_repetition() {
-        let confidence = estimate_confidence("hello hello hello hello hello", 2.0);
+        let confidence = estimate_confidence("hello hello hello hello hello", 2.0, &english());
         assert!(confidence < 0.6);
     }
 
@@ -0,0 +1,176 @@
+use crate::audio_toolkit::read_wav_file;
+use crate::cloud_stt::CloudSttProvider;
+use crate::managers::history::{AlternativeTranscript, HistoryEntry, HistoryManager};
+use crate::managers::transcription::TranscriptionManager;
+use crate::settings::{get_settings, write_settings, HistoryRetention};
+use log::info;
+use serde::Deserialize;
+use specta::Type;
+use std::sync::Arc;
+use std::time::Instant;
+use tauri::{AppHandle, State};
+
+/// What to re-transcribe a saved recording with
+#[derive(Debug, Clone, Deserialize, Type)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum RetranscribeTarget {
+    /// A downloaded local model id
+    Model(String),
+    /// A cloud STT provider (uses the configured cloud API key)
+    Cloud(CloudSttProvider),
+}
+
+/// Default page size when the frontend doesn't pass a limit
+const DEFAULT_HISTORY_LIMIT: u32 = 100;
+
+/// The history manager, unless the database failed to open at startup
+fn available(
+    history_manager: &Option<Arc<HistoryManager>>,
+) -> Result<&Arc<HistoryManager>, String> {
+    history_manager
+        .as_ref()
+        .ok_or_else(|| "History is unavailable: the history database could not be opened".into())
+}
+
+/// List history entries, most recent first
+#[tauri::command]
+#[specta::specta]
+pub fn get_history_entries(
+    history_manager: State<Option<Arc<HistoryManager>>>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> Result<Vec<HistoryEntry>, String> {
+    available(&history_manager)?
+        .list_entries(limit.unwrap_or(DEFAULT_HISTORY_LIMIT), offset.unwrap_or(0))
+        .map_err(|e| e.to_string())
+}
+
+/// Full-text search over history entries, best matches first
+#[tauri::command]
+#[specta::specta]
+pub fn search_history(
+    history_manager: State<Option<Arc<HistoryManager>>>,
+    query: String,
+    limit: Option<u32>,
+) -> Result<Vec<HistoryEntry>, String> {
+    available(&history_manager)?
+        .search(&query, limit.unwrap_or(DEFAULT_HISTORY_LIMIT))
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn delete_history_entry(
+    history_manager: State<Option<Arc<HistoryManager>>>,
+    id: i64,
+) -> Result<(), String> {
+    match available(&history_manager)?.delete_entry(id) {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(format!("History entry not found: {}", id)),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn clear_history(history_manager: State<Option<Arc<HistoryManager>>>) -> Result<(), String> {
+    available(&history_manager)?
+        .clear()
+        .map_err(|e| e.to_string())
+}
+
+/// Path of the saved audio for an entry, if a recording was kept
+#[tauri::command]
+#[specta::specta]
+pub fn get_history_recording_path(
+    history_manager: State<Option<Arc<HistoryManager>>>,
+    id: i64,
+) -> Result<Option<String>, String> {
+    let path = available(&history_manager)?.recording_path(id);
+    Ok(path.exists().then(|| path.to_string_lossy().into_owned()))
+}
+
+/// Alternative transcripts stored for an entry, oldest first
+#[tauri::command]
+#[specta::specta]
+pub fn get_history_alternatives(
+    history_manager: State<Option<Arc<HistoryManager>>>,
+    id: i64,
+) -> Result<Vec<AlternativeTranscript>, String> {
+    available(&history_manager)?
+        .list_alternatives(id)
+        .map_err(|e| e.to_string())
+}
+
+/// Re-transcribe an entry's saved recording with another model or cloud provider
+/// and store the result as an alternative transcript.
+///
+/// The live dictation engine is left as is: other local models are loaded into a
+/// temporary engine, and the loaded model queues behind live dictation.
+#[tauri::command]
+#[specta::specta]
+pub async fn retranscribe_history_entry(
+    history_manager: State<'_, Option<Arc<HistoryManager>>>,
+    transcription_manager: State<'_, Arc<TranscriptionManager>>,
+    id: i64,
+    target: RetranscribeTarget,
+) -> Result<AlternativeTranscript, String> {
+    let history_manager = available(&history_manager)?;
+    let path = history_manager.recording_path(id);
+    if !path.exists() {
+        return Err(format!("No recording saved for history entry {}", id));
+    }
+    let samples = read_wav_file(&path).map_err(|e| e.to_string())?;
+
+    info!("Re-transcribing history entry {} with {:?}", id, target);
+    let start = Instant::now();
+    let (text, model_id, provider) = match target {
+        RetranscribeTarget::Model(model_id) => {
+            let tm = transcription_manager.inner().clone();
+            let model = model_id.clone();
+            let text = tauri::async_runtime::spawn_blocking(move || {
+                tm.transcribe_with_model(&model, samples)
+            })
+            .await
+            .map_err(|e| format!("Re-transcription task failed: {}", e))?
+            .map_err(|e| e.to_string())?;
+            (text, Some(model_id), None)
+        }
+        RetranscribeTarget::Cloud(provider) => {
+            let text = transcription_manager
+                .transcribe_with_cloud_provider(provider, samples)
+                .await
+                .map_err(|e| e.to_string())?;
+            (text, None, Some(provider.display_name().to_string()))
+        }
+    };
+    let duration_ms = start.elapsed().as_millis() as u64;
+
+    history_manager
+        .add_alternative(
+            id,
+            text.trim(),
+            model_id.as_deref(),
+            provider.as_deref(),
+            duration_ms,
+        )
+        .map_err(|e| e.to_string())
+}
+
+/// Set how long history is kept; entries outside the new period are pruned immediately.
+/// `Never` stops saving new entries but keeps existing ones (see `clear_history`).
+#[tauri::command]
+#[specta::specta]
+pub fn set_history_retention(
+    app: AppHandle,
+    history_manager: State<Option<Arc<HistoryManager>>>,
+    retention: HistoryRetention,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    settings.history_retention = retention;
+    write_settings(&app, settings);
+
+    available(&history_manager)?
+        .apply_retention()
+        .map_err(|e| e.to_string())
+}
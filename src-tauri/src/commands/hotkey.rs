@@ -9,6 +9,7 @@
 //! - The `get_shortcut_backend_info` command tells the frontend which mode is active
 
 use crate::hotkey::{HotkeyManager, ShortcutBackend};
+use crate::settings::ShortcutBinding;
 use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::sync::Arc;
@@ -102,6 +103,47 @@ pub fn resume_binding(
     hotkey_manager.resume_binding(&binding_id)
 }
 
+/// Adds a dictation shortcut with its own post-processing prompt.
+///
+/// The shortcut starts unassigned; set its keys with `update_binding` and its
+/// prompt with `set_binding_post_process_prompt`.
+///
+/// # Arguments
+///
+/// * `name` - Display name of the new shortcut
+///
+/// # Returns
+///
+/// * `Ok(ShortcutBinding)` - The new binding
+/// * `Err(String)` - The name was empty
+#[tauri::command]
+#[specta::specta]
+pub fn add_dictation_binding(
+    hotkey_manager: State<Arc<HotkeyManager>>,
+    name: String,
+) -> Result<ShortcutBinding, String> {
+    hotkey_manager.add_dictation_binding(&name)
+}
+
+/// Removes a dictation shortcut added with `add_dictation_binding`.
+///
+/// # Arguments
+///
+/// * `binding_id` - The binding identifier to remove
+///
+/// # Returns
+///
+/// * `Ok(())` - The binding was removed
+/// * `Err(String)` - The binding is built in or doesn't exist
+#[tauri::command]
+#[specta::specta]
+pub fn remove_dictation_binding(
+    hotkey_manager: State<Arc<HotkeyManager>>,
+    binding_id: String,
+) -> Result<(), String> {
+    hotkey_manager.remove_dictation_binding(&binding_id)
+}
+
 /// Registers all shortcuts from settings.
 ///
 /// This command reads the current application settings and registers
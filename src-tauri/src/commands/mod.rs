@@ -1,13 +1,19 @@
 pub mod audio;
 pub mod dictation;
+pub mod history;
 pub mod hotkey;
 pub mod models;
+pub mod post_processing;
 pub mod text_injection;
 pub mod transcription;
 
+use crate::audio_toolkit::{OutputFilters, SpokenCommands, TextReplacement, ITN_LANGUAGES};
 use crate::managers::audio::AudioRecordingManager;
+use crate::managers::history::HistoryManager;
 use crate::overlay::hide_recording_overlay;
-use crate::settings::{get_default_settings, get_settings, write_settings, AppSettings};
+use crate::settings::{
+    get_default_settings, get_settings, write_settings, AppSettings, EngineParams,
+};
 use crate::tray::{change_tray_icon, TrayIconState};
 use log::{info, warn};
 use std::process::Command;
@@ -159,6 +165,14 @@ use specta::Type;
 pub enum SettingUpdate {
     #[serde(rename = "push_to_talk")]
     PushToTalk(bool),
+    #[serde(rename = "max_recording_duration_secs")]
+    MaxRecordingDurationSecs(u32),
+    #[serde(rename = "auto_stop_on_silence")]
+    AutoStopOnSilence(bool),
+    #[serde(rename = "auto_stop_silence_ms")]
+    AutoStopSilenceMs(u32),
+    #[serde(rename = "streaming_transcription")]
+    StreamingTranscription(bool),
     #[serde(rename = "audio_feedback")]
     AudioFeedback(bool),
     #[serde(rename = "audio_feedback_volume")]
@@ -187,6 +201,36 @@ pub enum SettingUpdate {
     LogLevel(String),
     #[serde(rename = "sound_theme")]
     SoundTheme(String),
+    #[serde(rename = "vad_threshold")]
+    VadThreshold(f32),
+    #[serde(rename = "vad_prefill_frames")]
+    VadPrefillFrames(u32),
+    #[serde(rename = "vad_hangover_frames")]
+    VadHangoverFrames(u32),
+    #[serde(rename = "vad_onset_frames")]
+    VadOnsetFrames(u32),
+    #[serde(rename = "post_process_enabled")]
+    PostProcessEnabled(bool),
+    #[serde(rename = "post_process_provider")]
+    PostProcessProvider(String),
+    #[serde(rename = "post_process_api_key")]
+    PostProcessApiKey(String),
+    #[serde(rename = "post_process_timeout_secs")]
+    PostProcessTimeoutSecs(u32),
+    #[serde(rename = "save_recordings")]
+    SaveRecordings(bool),
+    #[serde(rename = "recordings_quota_mb")]
+    RecordingsQuotaMb(u32),
+    #[serde(rename = "engine_params")]
+    EngineParams(EngineParams),
+    #[serde(rename = "text_replacements")]
+    TextReplacements(Vec<TextReplacement>),
+    #[serde(rename = "output_filters")]
+    OutputFilters(OutputFilters),
+    #[serde(rename = "itn_languages")]
+    ItnLanguages(Vec<String>),
+    #[serde(rename = "spoken_commands")]
+    SpokenCommands(SpokenCommands),
 }
 
 /// Update a single setting with type-safe value.
@@ -200,9 +244,21 @@ pub enum SettingUpdate {
 #[tauri::command]
 pub fn update_setting(app: AppHandle, update: SettingUpdate) -> Result<(), String> {
     let mut settings = get_settings(&app);
+    let retune_vad = matches!(
+        update,
+        SettingUpdate::VadThreshold(_)
+            | SettingUpdate::VadPrefillFrames(_)
+            | SettingUpdate::VadHangoverFrames(_)
+            | SettingUpdate::VadOnsetFrames(_)
+    );
+    let quota_changed = matches!(update, SettingUpdate::RecordingsQuotaMb(_));
 
     match update {
         SettingUpdate::PushToTalk(v) => settings.push_to_talk = v,
+        SettingUpdate::MaxRecordingDurationSecs(v) => settings.max_recording_duration_secs = v,
+        SettingUpdate::AutoStopOnSilence(v) => settings.auto_stop_on_silence = v,
+        SettingUpdate::AutoStopSilenceMs(v) => settings.auto_stop_silence_ms = v.clamp(300, 10_000),
+        SettingUpdate::StreamingTranscription(v) => settings.streaming_transcription = v,
         SettingUpdate::AudioFeedback(v) => settings.audio_feedback = v,
         SettingUpdate::AudioFeedbackVolume(v) => settings.audio_feedback_volume = v,
         SettingUpdate::StartHidden(v) => settings.start_hidden = v,
@@ -265,9 +321,79 @@ pub fn update_setting(app: AppHandle, update: SettingUpdate) -> Result<(), Strin
             };
             settings.sound_theme = theme;
         }
+        SettingUpdate::VadThreshold(v) => settings.vad_threshold = v.clamp(0.0, 1.0),
+        SettingUpdate::VadPrefillFrames(v) => settings.vad_prefill_frames = v,
+        SettingUpdate::VadHangoverFrames(v) => settings.vad_hangover_frames = v,
+        SettingUpdate::VadOnsetFrames(v) => settings.vad_onset_frames = v.max(1),
+        SettingUpdate::PostProcessEnabled(v) => settings.post_process_enabled = v,
+        SettingUpdate::PostProcessProvider(v) => {
+            // "none" keeps post-processing configured but inactive
+            if v != "none" && !settings.post_process_providers.iter().any(|p| p.id == v) {
+                return Err(format!("Unknown post_process_provider: '{}'", v));
+            }
+            settings.post_process_provider = v;
+        }
+        SettingUpdate::PostProcessApiKey(v) => settings.post_process_api_key = v,
+        SettingUpdate::PostProcessTimeoutSecs(v) => {
+            settings.post_process_timeout_secs = v.clamp(1, 120)
+        }
+        SettingUpdate::SaveRecordings(v) => settings.save_recordings = v,
+        SettingUpdate::RecordingsQuotaMb(v) => settings.recordings_quota_mb = v,
+        SettingUpdate::EngineParams(v) => {
+            settings.engine_params = EngineParams {
+                whisper: v.whisper.clamped(),
+            }
+        }
+        SettingUpdate::TextReplacements(v) => {
+            for replacement in &v {
+                replacement
+                    .to_regex()
+                    .map_err(|e| format!("Invalid replacement '{}': {}", replacement.pattern, e))?;
+            }
+            settings.text_replacements = v;
+        }
+        SettingUpdate::OutputFilters(v) => settings.output_filters = v,
+        SettingUpdate::ItnLanguages(v) => {
+            if let Some(language) = v.iter().find(|l| !ITN_LANGUAGES.contains(&l.as_str())) {
+                return Err(format!(
+                    "Inverse text normalization is not available for '{}'",
+                    language
+                ));
+            }
+            settings.itn_languages = v;
+        }
+        SettingUpdate::SpokenCommands(v) => {
+            for (language, vocabulary) in &v.vocabularies {
+                if vocabulary
+                    .commands
+                    .iter()
+                    .any(|c| c.phrase.trim().is_empty())
+                {
+                    return Err(format!("Empty spoken command phrase for '{}'", language));
+                }
+            }
+            settings.spoken_commands = v;
+        }
     }
 
     write_settings(&app, settings);
+
+    // Apply VAD changes to the live recorder so users can tune without restarting
+    if retune_vad {
+        let audio_manager = app.state::<Arc<AudioRecordingManager>>();
+        audio_manager
+            .update_vad_params()
+            .map_err(|e| format!("Failed to apply VAD settings: {}", e))?;
+    }
+
+    if quota_changed {
+        if let Some(history_manager) = app.state::<Option<Arc<HistoryManager>>>().inner() {
+            history_manager
+                .enforce_recordings_quota()
+                .map_err(|e| format!("Failed to prune recordings: {}", e))?;
+        }
+    }
+
     Ok(())
 }
 
@@ -0,0 +1,148 @@
+use crate::llm_client;
+use crate::settings::{get_settings, write_settings, PostProcessPrompt};
+use tauri::AppHandle;
+
+/// Set the model used for a post-processing provider
+#[tauri::command]
+#[specta::specta]
+pub fn set_post_process_model(
+    app: AppHandle,
+    provider_id: String,
+    model: String,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    if !settings
+        .post_process_providers
+        .iter()
+        .any(|p| p.id == provider_id)
+    {
+        return Err(format!("Unknown post-processing provider: {}", provider_id));
+    }
+    settings
+        .post_process_models
+        .insert(provider_id, model.trim().to_string());
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Set the base URL of a post-processing provider that allows editing it
+#[tauri::command]
+#[specta::specta]
+pub fn set_post_process_base_url(
+    app: AppHandle,
+    provider_id: String,
+    base_url: String,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    let provider = settings
+        .post_process_providers
+        .iter_mut()
+        .find(|p| p.id == provider_id)
+        .ok_or_else(|| format!("Unknown post-processing provider: {}", provider_id))?;
+
+    if !provider.allow_base_url_edit {
+        return Err(format!(
+            "Base URL of provider '{}' cannot be changed",
+            provider_id
+        ));
+    }
+
+    provider.base_url = base_url.trim().to_string();
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// List the models offered by a post-processing provider
+#[tauri::command]
+#[specta::specta]
+pub async fn fetch_post_process_models(
+    app: AppHandle,
+    provider_id: String,
+) -> Result<Vec<String>, String> {
+    let settings = get_settings(&app);
+    let provider = settings
+        .post_process_providers
+        .iter()
+        .find(|p| p.id == provider_id)
+        .ok_or_else(|| format!("Unknown post-processing provider: {}", provider_id))?;
+
+    llm_client::fetch_models(provider, settings.post_process_api_key.clone()).await
+}
+
+/// Add a prompt to the library, or replace the prompt with the same id
+#[tauri::command]
+#[specta::specta]
+pub fn save_post_process_prompt(app: AppHandle, prompt: PostProcessPrompt) -> Result<(), String> {
+    let id = prompt.id.trim();
+    if id.is_empty() {
+        return Err("Prompt id cannot be empty".to_string());
+    }
+    if prompt.name.trim().is_empty() {
+        return Err("Prompt name cannot be empty".to_string());
+    }
+
+    let prompt = PostProcessPrompt {
+        id: id.to_string(),
+        model: prompt
+            .model
+            .map(|m| m.trim().to_string())
+            .filter(|m| !m.is_empty()),
+        ..prompt
+    };
+
+    let mut settings = get_settings(&app);
+    match settings
+        .post_process_prompts
+        .iter_mut()
+        .find(|p| p.id == prompt.id)
+    {
+        Some(existing) => *existing = prompt,
+        None => settings.post_process_prompts.push(prompt),
+    }
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Remove a prompt from the library; bindings using it fall back to raw text
+#[tauri::command]
+#[specta::specta]
+pub fn delete_post_process_prompt(app: AppHandle, prompt_id: String) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    let before = settings.post_process_prompts.len();
+    settings.post_process_prompts.retain(|p| p.id != prompt_id);
+    if settings.post_process_prompts.len() == before {
+        return Err(format!("Prompt not found: {}", prompt_id));
+    }
+
+    for binding in settings.bindings.values_mut() {
+        if binding.post_process_prompt_id.as_deref() == Some(prompt_id.as_str()) {
+            binding.post_process_prompt_id = None;
+        }
+    }
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Select the prompt applied to a binding's dictations (`None` = no post-processing)
+#[tauri::command]
+#[specta::specta]
+pub fn set_binding_post_process_prompt(
+    app: AppHandle,
+    binding_id: String,
+    prompt_id: Option<String>,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    if let Some(id) = &prompt_id {
+        if !settings.post_process_prompts.iter().any(|p| &p.id == id) {
+            return Err(format!("Prompt not found: {}", id));
+        }
+    }
+
+    let binding = settings
+        .bindings
+        .get_mut(&binding_id)
+        .ok_or_else(|| format!("Binding not found: {}", binding_id))?;
+    binding.post_process_prompt_id = prompt_id;
+    write_settings(&app, settings);
+    Ok(())
+}
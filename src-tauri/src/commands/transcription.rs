@@ -1,8 +1,10 @@
 use crate::cloud_stt::CloudSttProvider;
 use crate::managers::transcription::{TranscriptionManager, TranscriptionResultWithFallback};
 use crate::settings::{get_settings, write_settings, ModelUnloadTimeout};
+use crate::transcript::{Transcript, TranscriptFormat};
 use serde::Serialize;
 use specta::Type;
+use std::path::PathBuf;
 use std::sync::Arc;
 use tauri::{AppHandle, State};
 
@@ -135,7 +137,7 @@ pub async fn transcribe_with_fallback(
     audio: Vec<f32>,
 ) -> Result<TranscriptionResultWithFallback, String> {
     transcription_manager
-        .transcribe_with_fallback(audio)
+        .transcribe_with_fallback(audio, None)
         .await
         .map_err(|e| format!("Transcription failed: {}", e))
 }
@@ -152,3 +154,33 @@ pub async fn transcribe_cloud_only(
         .await
         .map_err(|e| format!("Cloud transcription failed: {}", e))
 }
+
+/// Transcribe an audio file (WAV, FLAC, MP3, OGG/Vorbis, OGG/Opus) offline with the loaded model
+///
+/// Progress is reported through `transcription-progress` events. The
+/// result keeps segment timestamps for `export_transcript`.
+#[tauri::command]
+#[specta::specta]
+pub async fn transcribe_file(
+    transcription_manager: State<'_, Arc<TranscriptionManager>>,
+    path: String,
+) -> Result<Transcript, String> {
+    let transcription_manager = transcription_manager.inner().clone();
+    let path = PathBuf::from(path);
+    tauri::async_runtime::spawn_blocking(move || transcription_manager.transcribe_file(&path))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| format!("File transcription failed: {}", e))
+}
+
+/// Write a transcript to `path` as plain text, SRT, WebVTT or timestamped JSON
+#[tauri::command]
+#[specta::specta]
+pub fn export_transcript(
+    transcript: Transcript,
+    format: TranscriptFormat,
+    path: String,
+) -> Result<(), String> {
+    std::fs::write(&path, transcript.export(format))
+        .map_err(|e| format!("Failed to write {}: {}", path, e))
+}
@@ -0,0 +1,493 @@
+//! D-Bus control interface (Linux)
+//!
+//! Exposes `org.voyc.Voyc` on the session bus so window-manager keybinds,
+//! Stream Decks and shell scripts can drive dictation without the global
+//! shortcut plugin or the GlobalShortcuts portal:
+//!
+//! ```sh
+//! busctl --user call org.voyc.Voyc /org/voyc/Voyc org.voyc.Voyc ToggleDictation
+//! ```
+//!
+//! Every method maps onto the `DictationController`. State changes and
+//! completed transcripts are re-broadcast as `StateChanged` and
+//! `TranscriptionCompleted` signals.
+
+use crate::dictation::{DictationController, DictationState};
+use crate::managers::history::HistoryManager;
+use crate::managers::transcription::TranscriptionManager;
+use crate::transcript::TranscriptFormat;
+use log::{debug, info, warn};
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Listener, Manager};
+use zbus::object_server::SignalEmitter;
+use zbus::{connection, fdo, interface, Connection};
+
+pub const BUS_NAME: &str = "org.voyc.Voyc";
+pub const OBJECT_PATH: &str = "/org/voyc/Voyc";
+
+/// Binding used for dictations started over D-Bus
+const DBUS_BINDING_ID: &str = "transcribe";
+
+/// Keeps the session bus connection (and with it the service) alive
+pub struct DbusService {
+    _connection: Connection,
+}
+
+/// What the D-Bus methods act on
+trait VoycBackend: Send + Sync + 'static {
+    fn start_dictation(&self) -> impl Future<Output = Result<(), String>> + Send;
+    fn stop_dictation(&self) -> impl Future<Output = Result<String, String>> + Send;
+    fn toggle_dictation(&self) -> impl Future<Output = Result<(), String>> + Send;
+    fn cancel_dictation(&self);
+    fn state(&self) -> DictationState;
+    /// Most recent transcript and its history id
+    fn last_transcript(&self) -> Result<Option<(String, i64)>, String>;
+    fn transcribe_file(
+        &self,
+        path: PathBuf,
+        format: TranscriptFormat,
+    ) -> impl Future<Output = Result<String, String>> + Send;
+}
+
+/// The running app's managers
+struct AppBackend {
+    app_handle: AppHandle,
+}
+
+impl AppBackend {
+    fn controller(&self) -> Arc<DictationController> {
+        self.app_handle
+            .state::<Arc<DictationController>>()
+            .inner()
+            .clone()
+    }
+}
+
+impl VoycBackend for AppBackend {
+    async fn start_dictation(&self) -> Result<(), String> {
+        let controller = self.controller();
+        // Starting blocks while the start sound plays
+        tauri::async_runtime::spawn_blocking(move || controller.start_dictation(DBUS_BINDING_ID))
+            .await
+            .map_err(|e| e.to_string())?
+    }
+
+    async fn stop_dictation(&self) -> Result<String, String> {
+        self.controller().stop_active_dictation().await
+    }
+
+    async fn toggle_dictation(&self) -> Result<(), String> {
+        let controller = self.controller();
+        if controller.is_active() {
+            return controller.toggle_active_dictation(DBUS_BINDING_ID).await;
+        }
+        self.start_dictation().await
+    }
+
+    fn cancel_dictation(&self) {
+        self.controller().cancel_dictation();
+    }
+
+    fn state(&self) -> DictationState {
+        self.controller().get_state()
+    }
+
+    fn last_transcript(&self) -> Result<Option<(String, i64)>, String> {
+        let history_state = self.app_handle.state::<Option<Arc<HistoryManager>>>();
+        let history_manager = history_state
+            .as_ref()
+            .ok_or_else(|| "History is unavailable".to_string())?;
+        let entries = history_manager
+            .list_entries(1, 0)
+            .map_err(|e| e.to_string())?;
+        Ok(entries
+            .into_iter()
+            .next()
+            .map(|entry| (entry.text, entry.id)))
+    }
+
+    async fn transcribe_file(
+        &self,
+        path: PathBuf,
+        format: TranscriptFormat,
+    ) -> Result<String, String> {
+        let transcription_manager = self
+            .app_handle
+            .state::<Arc<TranscriptionManager>>()
+            .inner()
+            .clone();
+        let transcript = tauri::async_runtime::spawn_blocking(move || {
+            transcription_manager.transcribe_file(&path)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())?;
+        Ok(transcript.export(format))
+    }
+}
+
+struct VoycInterface<B> {
+    backend: B,
+}
+
+#[interface(name = "org.voyc.Voyc")]
+impl<B: VoycBackend> VoycInterface<B> {
+    /// Start recording (no-op if dictation is already active)
+    async fn start_dictation(&self) -> fdo::Result<()> {
+        self.backend
+            .start_dictation()
+            .await
+            .map_err(fdo::Error::Failed)
+    }
+
+    /// Stop recording, transcribe and inject; returns the transcribed text
+    async fn stop_dictation(&self) -> fdo::Result<String> {
+        self.backend
+            .stop_dictation()
+            .await
+            .map_err(fdo::Error::Failed)
+    }
+
+    /// Start when idle, stop when recording
+    async fn toggle_dictation(&self) -> fdo::Result<()> {
+        self.backend
+            .toggle_dictation()
+            .await
+            .map_err(fdo::Error::Failed)
+    }
+
+    /// Discard the current recording
+    async fn cancel_dictation(&self) {
+        self.backend.cancel_dictation();
+    }
+
+    /// "idle", "recording" or "transcribing"
+    async fn get_state(&self) -> String {
+        self.backend.state().as_str().to_string()
+    }
+
+    /// Most recent transcript and its history id (-1 and empty text when there is none)
+    async fn get_last_transcript(&self) -> fdo::Result<(String, i64)> {
+        let last = self.backend.last_transcript().map_err(fdo::Error::Failed)?;
+        Ok(last.unwrap_or_else(|| (String::new(), -1)))
+    }
+
+    /// Transcribe an audio file (WAV, FLAC, MP3, OGG) with the active model (no text injection)
+    async fn transcribe_file(&self, path: String) -> fdo::Result<String> {
+        self.transcribe_file_as(path, "text".to_string()).await
+    }
+
+    /// Like `TranscribeFile`, rendered as "text", "srt", "vtt" or "json"
+    async fn transcribe_file_as(&self, path: String, format: String) -> fdo::Result<String> {
+        let format = TranscriptFormat::parse(&format).ok_or_else(|| {
+            fdo::Error::InvalidArgs(format!("Unknown transcript format: {}", format))
+        })?;
+        let path = PathBuf::from(path);
+        if !path.is_file() {
+            return Err(fdo::Error::InvalidArgs(format!(
+                "No such file: {}",
+                path.display()
+            )));
+        }
+
+        self.backend
+            .transcribe_file(path, format)
+            .await
+            .map_err(fdo::Error::Failed)
+    }
+
+    /// Emitted whenever the dictation state changes
+    #[zbus(signal)]
+    async fn state_changed(emitter: &SignalEmitter<'_>, state: &str) -> zbus::Result<()>;
+
+    /// Emitted when a dictation finished; `history_id` is -1 when it wasn't recorded
+    #[zbus(signal)]
+    async fn transcription_completed(
+        emitter: &SignalEmitter<'_>,
+        text: &str,
+        history_id: i64,
+    ) -> zbus::Result<()>;
+}
+
+/// Register the service on the session bus in the background.
+///
+/// Failure (no session bus, name already owned) is logged and otherwise ignored.
+pub fn start(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        match serve(app_handle.clone()).await {
+            Ok(connection) => {
+                forward_events(&app_handle, connection.clone());
+                app_handle.manage(DbusService {
+                    _connection: connection,
+                });
+                info!("D-Bus service registered as {}", BUS_NAME);
+            }
+            Err(e) => warn!("Failed to register D-Bus service {}: {}", BUS_NAME, e),
+        }
+    });
+}
+
+async fn serve(app_handle: AppHandle) -> zbus::Result<Connection> {
+    connection::Builder::session()?
+        .name(BUS_NAME)?
+        .serve_at(
+            OBJECT_PATH,
+            VoycInterface {
+                backend: AppBackend { app_handle },
+            },
+        )?
+        .build()
+        .await
+}
+
+/// `StateChanged` argument for a "dictation-state-changed" event payload
+fn state_from_event(payload: &str) -> Option<String> {
+    serde_json::from_str(payload).ok()
+}
+
+/// `TranscriptionCompleted` arguments for a "dictation-complete" event payload
+fn completion_from_event(payload: &str) -> Option<(String, i64)> {
+    let payload = serde_json::from_str::<serde_json::Value>(payload).ok()?;
+    let text = payload["text"].as_str().unwrap_or_default().to_string();
+    let history_id = payload["history_id"].as_i64().unwrap_or(-1);
+    Some((text, history_id))
+}
+
+async fn emit_state_changed(connection: &Connection, state: &str) -> zbus::Result<()> {
+    let emitter = SignalEmitter::new(connection, OBJECT_PATH)?;
+    VoycInterface::<AppBackend>::state_changed(&emitter, state).await
+}
+
+async fn emit_transcription_completed(
+    connection: &Connection,
+    text: &str,
+    history_id: i64,
+) -> zbus::Result<()> {
+    let emitter = SignalEmitter::new(connection, OBJECT_PATH)?;
+    VoycInterface::<AppBackend>::transcription_completed(&emitter, text, history_id).await
+}
+
+/// Re-broadcast app events as D-Bus signals
+fn forward_events(app_handle: &AppHandle, connection: Connection) {
+    let state_connection = connection.clone();
+    app_handle.listen("dictation-state-changed", move |event| {
+        let Some(state) = state_from_event(event.payload()) else {
+            return;
+        };
+        let connection = state_connection.clone();
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = emit_state_changed(&connection, &state).await {
+                debug!("Failed to emit StateChanged signal: {}", e);
+            }
+        });
+    });
+
+    app_handle.listen("dictation-complete", move |event| {
+        let Some((text, history_id)) = completion_from_event(event.payload()) else {
+            return;
+        };
+        let connection = connection.clone();
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = emit_transcription_completed(&connection, &text, history_id).await {
+                debug!("Failed to emit TranscriptionCompleted signal: {}", e);
+            }
+        });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+    use std::sync::Mutex;
+    use tokio::net::UnixStream;
+    use zbus::message::Type as MessageType;
+    use zbus::Guid;
+
+    /// Dictation that only tracks its state
+    struct FakeBackend {
+        state: Mutex<DictationState>,
+    }
+
+    impl FakeBackend {
+        fn new(state: DictationState) -> Self {
+            Self {
+                state: Mutex::new(state),
+            }
+        }
+    }
+
+    impl VoycBackend for FakeBackend {
+        async fn start_dictation(&self) -> Result<(), String> {
+            let mut state = self.state.lock().unwrap();
+            if *state == DictationState::Idle {
+                *state = DictationState::Recording;
+            }
+            Ok(())
+        }
+
+        async fn stop_dictation(&self) -> Result<String, String> {
+            let mut state = self.state.lock().unwrap();
+            if *state != DictationState::Recording {
+                return Err("Not recording".to_string());
+            }
+            *state = DictationState::Idle;
+            Ok("hello world".to_string())
+        }
+
+        async fn toggle_dictation(&self) -> Result<(), String> {
+            if self.state() == DictationState::Idle {
+                self.start_dictation().await
+            } else {
+                self.stop_dictation().await.map(|_| ())
+            }
+        }
+
+        fn cancel_dictation(&self) {
+            *self.state.lock().unwrap() = DictationState::Idle;
+        }
+
+        fn state(&self) -> DictationState {
+            self.state.lock().unwrap().clone()
+        }
+
+        fn last_transcript(&self) -> Result<Option<(String, i64)>, String> {
+            Ok(None)
+        }
+
+        async fn transcribe_file(
+            &self,
+            _path: PathBuf,
+            _format: TranscriptFormat,
+        ) -> Result<String, String> {
+            Ok("from file".to_string())
+        }
+    }
+
+    /// Service and client ends of a private peer-to-peer connection
+    async fn connect(backend: FakeBackend) -> (Connection, Connection) {
+        let (service, client) = UnixStream::pair().unwrap();
+        let service = connection::Builder::socket(service)
+            .server(Guid::generate())
+            .unwrap()
+            .p2p()
+            .serve_at(OBJECT_PATH, VoycInterface { backend })
+            .unwrap()
+            .build();
+        let client = connection::Builder::socket(client).p2p().build();
+        futures_util::try_join!(service, client).unwrap()
+    }
+
+    async fn call<B>(client: &Connection, method: &str, body: &B) -> zbus::Result<zbus::Message>
+    where
+        B: serde::Serialize + zbus::zvariant::DynamicType,
+    {
+        client
+            .call_method(None::<&str>, OBJECT_PATH, Some(BUS_NAME), method, body)
+            .await
+    }
+
+    async fn get_state(client: &Connection) -> String {
+        let reply = call(client, "GetState", &()).await.unwrap();
+        reply.body().deserialize().unwrap()
+    }
+
+    #[test]
+    fn test_get_state() {
+        tauri::async_runtime::block_on(async {
+            let (_service, client) = connect(FakeBackend::new(DictationState::Transcribing)).await;
+            assert_eq!(get_state(&client).await, "transcribing");
+        });
+    }
+
+    #[test]
+    fn test_toggle_dictation() {
+        tauri::async_runtime::block_on(async {
+            let (_service, client) = connect(FakeBackend::new(DictationState::Idle)).await;
+
+            call(&client, "ToggleDictation", &()).await.unwrap();
+            assert_eq!(get_state(&client).await, "recording");
+
+            call(&client, "ToggleDictation", &()).await.unwrap();
+            assert_eq!(get_state(&client).await, "idle");
+        });
+    }
+
+    #[test]
+    fn test_errors_map_to_dbus_errors() {
+        tauri::async_runtime::block_on(async {
+            let (_service, client) = connect(FakeBackend::new(DictationState::Idle)).await;
+
+            let err = call(&client, "StopDictation", &()).await.unwrap_err();
+            assert!(
+                matches!(fdo::Error::from(err), fdo::Error::Failed(msg) if msg == "Not recording")
+            );
+
+            let err = call(&client, "TranscribeFileAs", &("/dev/null", "docx"))
+                .await
+                .unwrap_err();
+            assert!(matches!(fdo::Error::from(err), fdo::Error::InvalidArgs(_)));
+        });
+    }
+
+    #[test]
+    fn test_last_transcript_without_history() {
+        tauri::async_runtime::block_on(async {
+            let (_service, client) = connect(FakeBackend::new(DictationState::Idle)).await;
+            let reply = call(&client, "GetLastTranscript", &()).await.unwrap();
+            let last: (String, i64) = reply.body().deserialize().unwrap();
+            assert_eq!(last, (String::new(), -1));
+        });
+    }
+
+    #[test]
+    fn test_state_changed_signal() {
+        tauri::async_runtime::block_on(async {
+            let (service, client) = connect(FakeBackend::new(DictationState::Idle)).await;
+            let mut messages = zbus::MessageStream::from(&client);
+
+            emit_state_changed(&service, "recording").await.unwrap();
+
+            let signal = loop {
+                let message = messages.next().await.unwrap().unwrap();
+                if message.message_type() == MessageType::Signal {
+                    break message;
+                }
+            };
+            let header = signal.header();
+            assert_eq!(header.interface().unwrap().as_str(), BUS_NAME);
+            assert_eq!(header.member().unwrap().as_str(), "StateChanged");
+            assert_eq!(header.path().unwrap().as_str(), OBJECT_PATH);
+            let state: String = signal.body().deserialize().unwrap();
+            assert_eq!(state, "recording");
+        });
+    }
+
+    #[test]
+    fn test_state_events_match_get_state() {
+        for state in [
+            DictationState::Idle,
+            DictationState::Recording,
+            DictationState::Transcribing,
+        ] {
+            let payload = serde_json::to_string(&state).unwrap();
+            assert_eq!(state_from_event(&payload).as_deref(), Some(state.as_str()));
+        }
+        assert_eq!(state_from_event("{}"), None);
+    }
+
+    #[test]
+    fn test_completion_from_event() {
+        assert_eq!(
+            completion_from_event(r#"{"text":"hello","history_id":7}"#),
+            Some(("hello".to_string(), 7))
+        );
+        assert_eq!(
+            completion_from_event(r#"{"text":"hello","history_id":null}"#),
+            Some(("hello".to_string(), -1))
+        );
+        assert_eq!(completion_from_event("not json"), None);
+    }
+}
@@ -6,40 +6,57 @@
 //! This module implements the brain of the application, tying together:
 //! - AudioRecordingManager - start/stop recording, get audio samples
 //! - TranscriptionManager - transcribe audio to text
+//! - post_processing module - optional LLM cleanup of the transcription
 //! - text_injection::inject_text - inject text into focused app
+//! - HistoryManager - record completed dictations
 //! - audio_feedback module - play start/stop sounds
 //! - overlay module - show/hide recording overlay
 //! - tray module - change tray icon state
 
 use crate::audio_feedback::{play_feedback_sound, play_feedback_sound_blocking, SoundType};
 use crate::managers::audio::AudioRecordingManager;
+use crate::managers::history::HistoryManager;
 use crate::managers::transcription::TranscriptionManager;
 use crate::overlay::{hide_recording_overlay, show_recording_overlay, show_transcribing_overlay};
+use crate::post_processing::post_process_transcription;
 use crate::settings::get_settings;
 use crate::text_injection::{self, InjectionResult};
 use crate::tray::{change_tray_icon, TrayIconState};
 use log::{debug, error, info, warn};
 use serde::Serialize;
 use specta::Type;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
-use std::time::Instant;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::async_runtime::JoinHandle;
 use tauri::{AppHandle, Emitter, Manager};
 
 /// Dictation state for tracking workflow progress
-#[derive(Debug, Clone, PartialEq, Serialize, Type)]
+#[derive(Debug, Clone, PartialEq, Serialize, Type, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum DictationState {
+    #[default]
     Idle,
     Recording,
     Transcribing,
 }
 
+impl DictationState {
+    /// Stable name used by external interfaces (D-Bus, command line)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DictationState::Idle => "idle",
+            DictationState::Recording => "recording",
+            DictationState::Transcribing => "transcribing",
+        }
+    }
+}
+
 /// Latency metrics for performance tracking (REQ-016)
 #[derive(Debug, Clone, Serialize, Type, Default)]
 pub struct LatencyMetrics {
     pub capture_ms: u64,
     pub transcription_ms: u64,
+    pub post_process_ms: u64,
     pub injection_ms: u64,
     pub total_ms: u64,
 }
@@ -48,10 +65,17 @@ pub struct LatencyMetrics {
 #[derive(Debug, Clone, Serialize, Type)]
 pub struct DictationCompleteEvent {
     pub text: String,
+    /// Transcription before LLM post-processing (equal to `text` when it was skipped)
+    pub raw_text: String,
+    pub post_processed: bool,
+    /// Local model that was active (None when no model was loaded)
+    pub model_id: Option<String>,
     pub used_fallback: bool,
     pub provider: Option<String>,
     pub duration_ms: u64,
     pub latency: LatencyMetrics,
+    /// Id of the history entry recorded for this dictation
+    pub history_id: Option<i64>,
 }
 
 /// Event emitted when text is copied to clipboard only (no paste tool available)
@@ -61,10 +85,87 @@ pub struct TextClipboardOnlyEvent {
     pub reason: String,
 }
 
+/// What a hotkey press or release should do
+#[derive(Debug, PartialEq)]
+enum HotkeyAction {
+    Start,
+    Stop,
+    Ignore,
+}
+
+/// The controller's state and the binding that started the current recording
+///
+/// Only that binding may stop the recording: a second binding pressed while
+/// another one records must not end up stopping nothing and leave the
+/// microphone open.
+#[derive(Debug, Default)]
+struct Session {
+    state: DictationState,
+    binding_id: Option<String>,
+    /// Incremented on every start so the max-duration watchdog can tell
+    /// whether the recording it was armed for is still running
+    id: u64,
+}
+
+impl Session {
+    /// Idle to Recording for `binding_id`; false when a dictation is already active
+    fn begin(&mut self, binding_id: &str) -> bool {
+        if self.state != DictationState::Idle {
+            return false;
+        }
+        self.state = DictationState::Recording;
+        self.binding_id = Some(binding_id.to_string());
+        self.id += 1;
+        true
+    }
+
+    fn is_recording(&self, binding_id: &str) -> bool {
+        self.state == DictationState::Recording && self.binding_id.as_deref() == Some(binding_id)
+    }
+
+    /// Recording to Transcribing, only for the binding that is recording
+    fn claim_stop(&mut self, binding_id: &str) -> bool {
+        if !self.is_recording(binding_id) {
+            return false;
+        }
+        self.state = DictationState::Transcribing;
+        true
+    }
+
+    /// Push-to-talk starts on press; toggle mode also stops on the second press
+    fn press_action(&self, binding_id: &str, push_to_talk: bool) -> HotkeyAction {
+        if self.state == DictationState::Idle {
+            HotkeyAction::Start
+        } else if !push_to_talk && self.is_recording(binding_id) {
+            HotkeyAction::Stop
+        } else {
+            HotkeyAction::Ignore
+        }
+    }
+
+    /// Only push-to-talk stops on release
+    fn release_action(&self, binding_id: &str, push_to_talk: bool) -> HotkeyAction {
+        if push_to_talk && self.is_recording(binding_id) {
+            HotkeyAction::Stop
+        } else {
+            HotkeyAction::Ignore
+        }
+    }
+
+    /// Back to Idle after a dictation finished, failed or was cancelled
+    fn end(&mut self) {
+        self.state = DictationState::Idle;
+        self.binding_id = None;
+    }
+}
+
 /// Manages the complete dictation workflow
 pub struct DictationController {
     app_handle: AppHandle,
-    is_active: Arc<AtomicBool>,
+    session: Mutex<Session>,
+    /// Stops a recording left running past the maximum duration; aborted when
+    /// the dictation ends earlier
+    watchdog: Mutex<Option<JoinHandle<()>>>,
 }
 
 impl DictationController {
@@ -73,7 +174,8 @@ impl DictationController {
         info!("DictationController created");
         Self {
             app_handle,
-            is_active: Arc::new(AtomicBool::new(false)),
+            session: Mutex::new(Session::default()),
+            watchdog: Mutex::new(None),
         }
     }
 
@@ -82,18 +184,18 @@ impl DictationController {
     /// This method:
     /// 1. Checks if dictation is already active
     /// 2. Plays start sound if audio feedback is enabled
-    /// 3. Starts audio recording
+    /// 3. Starts audio recording (and streaming transcription when enabled)
     /// 4. Applies mute if enabled (after audio feedback delay)
     /// 5. Updates UI (tray icon, overlay)
+    /// 6. Arms the max recording duration watchdog
     pub fn start_dictation(&self, binding_id: &str) -> Result<(), String> {
         // Check if already active
-        if self.is_active.load(Ordering::SeqCst) {
+        if !self.session.lock().unwrap().begin(binding_id) {
             debug!("Dictation already active, ignoring start");
             return Ok(());
         }
 
         info!("Starting dictation for binding: {}", binding_id);
-        self.is_active.store(true, Ordering::SeqCst);
 
         let settings = get_settings(&self.app_handle);
         let audio_manager = self.app_handle.state::<Arc<AudioRecordingManager>>();
@@ -103,9 +205,19 @@ impl DictationController {
             play_feedback_sound_blocking(&self.app_handle, SoundType::Start);
         }
 
+        // Streaming mode transcribes while recording, so the model is needed now
+        if settings.streaming_transcription {
+            let transcription_manager = self.app_handle.state::<Arc<TranscriptionManager>>();
+            transcription_manager.initiate_model_load();
+            transcription_manager.start_streaming();
+        }
+
         // Start recording
         if !audio_manager.try_start_recording(binding_id) {
-            self.is_active.store(false, Ordering::SeqCst);
+            self.session.lock().unwrap().end();
+            self.app_handle
+                .state::<Arc<TranscriptionManager>>()
+                .cancel_streaming();
             let current_state = audio_manager.is_recording();
             error!(
                 "Failed to start recording for binding: {} (audio_manager.is_recording={})",
@@ -134,11 +246,96 @@ impl DictationController {
             .app_handle
             .emit("dictation-state-changed", DictationState::Recording);
 
+        // Guard against a forgotten toggle (or a lost release event) recording forever
+        if settings.max_recording_duration_secs > 0 {
+            let session_id = self.session.lock().unwrap().id;
+            self.arm_max_duration_watchdog(
+                binding_id,
+                session_id,
+                settings.max_recording_duration_secs,
+            );
+        }
+
         info!("Dictation started successfully");
         Ok(())
     }
 
-    /// Stop dictation and process (called on hotkey release)
+    /// Toggle dictation (called on hotkey press when push-to-talk is disabled)
+    ///
+    /// The first press starts recording, the second press of the same binding
+    /// stops and transcribes. Presses of other bindings while recording, and
+    /// presses that arrive while a recording is being transcribed, are ignored.
+    pub async fn toggle_dictation(&self, binding_id: &str) -> Result<(), String> {
+        let action = self.session.lock().unwrap().press_action(binding_id, false);
+        match action {
+            HotkeyAction::Start => self.start_dictation(binding_id),
+            HotkeyAction::Stop => self.stop_dictation(binding_id).await.map(|_| ()),
+            HotkeyAction::Ignore => {
+                debug!("Dictation busy with another binding or transcribing, ignoring toggle");
+                Ok(())
+            }
+        }
+    }
+
+    /// Handle a hotkey release: stops the recording in push-to-talk mode, and
+    /// is ignored in toggle mode
+    pub async fn release_dictation(&self, binding_id: &str, push_to_talk: bool) {
+        let action = self
+            .session
+            .lock()
+            .unwrap()
+            .release_action(binding_id, push_to_talk);
+        if action == HotkeyAction::Stop {
+            if let Err(e) = self.stop_dictation(binding_id).await {
+                error!("Failed to stop dictation: {}", e);
+            }
+        }
+    }
+
+    /// Stop whichever binding is currently recording
+    ///
+    /// Used by external triggers (D-Bus, command line) that don't know which
+    /// binding started the recording.
+    pub async fn stop_active_dictation(&self) -> Result<String, String> {
+        let audio_manager = self.app_handle.state::<Arc<AudioRecordingManager>>();
+        match audio_manager.active_binding_id() {
+            Some(binding_id) => self.stop_dictation(&binding_id).await,
+            None => {
+                debug!("No active recording, ignoring stop");
+                Ok(String::new())
+            }
+        }
+    }
+
+    /// Toggle dictation from an external trigger, stopping whichever binding is recording
+    pub async fn toggle_active_dictation(&self, binding_id: &str) -> Result<(), String> {
+        match self.get_state() {
+            DictationState::Recording => self.stop_active_dictation().await.map(|_| ()),
+            _ => self.toggle_dictation(binding_id).await,
+        }
+    }
+
+    /// Stop dictation after the recorder reported trailing silence (hands-free mode)
+    pub async fn handle_end_of_utterance(&self) {
+        if self.get_state() != DictationState::Recording {
+            return;
+        }
+
+        let audio_manager = self.app_handle.state::<Arc<AudioRecordingManager>>();
+        let Some(binding_id) = audio_manager.active_binding_id() else {
+            return;
+        };
+
+        info!(
+            "End of utterance detected, stopping dictation for binding: {}",
+            binding_id
+        );
+        if let Err(e) = self.stop_dictation(&binding_id).await {
+            error!("Failed to stop dictation after end of utterance: {}", e);
+        }
+    }
+
+    /// Stop dictation and process (called on hotkey release, or the second press in toggle mode)
     ///
     /// This method:
     /// 1. Stops recording and gets audio samples
@@ -148,9 +345,10 @@ impl DictationController {
     /// 5. Plays stop sound
     /// 6. Cleans up and returns to idle state
     pub async fn stop_dictation(&self, binding_id: &str) -> Result<String, String> {
-        // Check if active
-        if !self.is_active.load(Ordering::SeqCst) {
-            debug!("Dictation not active, ignoring stop");
+        // Check if this binding is recording (claim the transition so concurrent
+        // stops, and stops from other bindings, are no-ops)
+        if !self.session.lock().unwrap().claim_stop(binding_id) {
+            debug!("Binding {} is not recording, ignoring stop", binding_id);
             return Ok(String::new());
         }
 
@@ -168,7 +366,10 @@ impl DictationController {
 
         // Stop recording and get audio samples
         let capture_start = Instant::now();
-        let audio_samples = match audio_manager.stop_recording(binding_id) {
+        let audio_samples = audio_manager.stop_recording(binding_id);
+        // Chunks already transcribed while recording (None unless streaming)
+        let streamed = transcription_manager.finish_streaming().await;
+        let audio_samples = match audio_samples {
             Some(samples) => samples,
             None => {
                 warn!("No audio recorded for binding: {}", binding_id);
@@ -197,12 +398,17 @@ impl DictationController {
             .app_handle
             .emit("dictation-state-changed", DictationState::Transcribing);
 
+        // Keep a copy of the audio for history when saving recordings is enabled
+        let recording = get_settings(&self.app_handle)
+            .save_recordings
+            .then(|| audio_samples.clone());
+
         // Initiate model load if needed (this will block until ready)
         transcription_manager.initiate_model_load();
 
         // Transcribe the audio
         let result = transcription_manager
-            .transcribe_with_fallback(audio_samples)
+            .transcribe_with_fallback(audio_samples, streamed)
             .await
             .map_err(|e| {
                 error!("Transcription failed: {}", e);
@@ -229,6 +435,17 @@ impl DictationController {
             result.provider
         );
 
+        // Optional LLM post-processing; falls back to the raw text on any failure
+        let settings = get_settings(&self.app_handle);
+        let raw_text = text;
+        let post_process_start = Instant::now();
+        let processed = post_process_transcription(&settings, binding_id, &raw_text).await;
+        let post_processed = processed.is_some();
+        let text = processed.unwrap_or_else(|| raw_text.clone());
+        if settings.post_process_enabled {
+            latency.post_process_ms = post_process_start.elapsed().as_millis() as u64;
+        }
+
         // Inject text into focused application
         let injection_start = Instant::now();
         let injection_result = text_injection::inject_text(&self.app_handle, &text);
@@ -260,24 +477,51 @@ impl DictationController {
 
         // REQ-016: Log latency metrics
         info!(
-            "Latency metrics: capture={}ms, transcription={}ms, injection={}ms, total={}ms",
-            latency.capture_ms, latency.transcription_ms, latency.injection_ms, latency.total_ms
+            "Latency metrics: capture={}ms, transcription={}ms, post_process={}ms, injection={}ms, total={}ms",
+            latency.capture_ms,
+            latency.transcription_ms,
+            latency.post_process_ms,
+            latency.injection_ms,
+            latency.total_ms
         );
 
         // Play stop sound asynchronously
         self.play_stop_sound_async();
 
+        let mut event = DictationCompleteEvent {
+            text: text.clone(),
+            raw_text,
+            post_processed,
+            model_id: transcription_manager.get_current_model(),
+            used_fallback: result.used_fallback,
+            provider: result.provider,
+            duration_ms: result.duration_ms,
+            latency,
+            history_id: None,
+        };
+
+        // Record in history before notifying listeners so they can refer to the entry
+        let history_state = self.app_handle.state::<Option<Arc<HistoryManager>>>();
+        let history_manager = history_state.inner().clone();
+        if let Some(history_manager) = &history_manager {
+            match history_manager.save_dictation(&event) {
+                Ok(id) => event.history_id = id,
+                Err(e) => warn!("Failed to save dictation to history: {}", e),
+            }
+        }
+
+        if let (Some(history_manager), Some(id), Some(samples)) =
+            (history_manager, event.history_id, recording)
+        {
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = history_manager.save_recording(id, &samples).await {
+                    warn!("Failed to save recording for history entry {}: {}", id, e);
+                }
+            });
+        }
+
         // Emit completion event with latency metrics
-        let _ = self.app_handle.emit(
-            "dictation-complete",
-            DictationCompleteEvent {
-                text: text.clone(),
-                used_fallback: result.used_fallback,
-                provider: result.provider,
-                duration_ms: result.duration_ms,
-                latency,
-            },
-        );
+        let _ = self.app_handle.emit("dictation-complete", event);
 
         self.cleanup();
         Ok(text)
@@ -290,7 +534,7 @@ impl DictationController {
     /// 2. Cancels recording (discards audio)
     /// 3. Cleans up UI state
     pub fn cancel_dictation(&self) {
-        if !self.is_active.load(Ordering::SeqCst) {
+        if !self.is_active() {
             debug!("Dictation not active, nothing to cancel");
             return;
         }
@@ -311,25 +555,60 @@ impl DictationController {
         self.cleanup();
     }
 
-    /// Check if dictation is currently active
+    /// Check if dictation is currently active (recording or transcribing)
     pub fn is_active(&self) -> bool {
-        self.is_active.load(Ordering::SeqCst)
+        self.get_state() != DictationState::Idle
     }
 
     /// Get current dictation state
     pub fn get_state(&self) -> DictationState {
-        if self.is_active.load(Ordering::SeqCst) {
-            // Could be recording or transcribing, but we track it as a single active state
-            // More detailed state tracking would require additional atomic state
-            DictationState::Recording
-        } else {
-            DictationState::Idle
+        self.session.lock().unwrap().state.clone()
+    }
+
+    /// Stop the recording automatically once it exceeds the configured maximum
+    /// length. The timer is aborted by `cleanup` when the dictation ends sooner.
+    fn arm_max_duration_watchdog(&self, binding_id: &str, session_id: u64, limit_secs: u32) {
+        let app_handle = self.app_handle.clone();
+        let binding_id = binding_id.to_string();
+        let watchdog = tauri::async_runtime::spawn(async move {
+            tokio::time::sleep(Duration::from_secs(limit_secs as u64)).await;
+
+            let controller = app_handle
+                .state::<Arc<DictationController>>()
+                .inner()
+                .clone();
+            {
+                let session = controller.session.lock().unwrap();
+                if session.id != session_id || !session.is_recording(&binding_id) {
+                    return;
+                }
+            }
+            // The stop below ends in `cleanup`, which must not abort this task
+            controller.watchdog.lock().unwrap().take();
+
+            warn!(
+                "Recording reached the {}s limit, stopping dictation for binding: {}",
+                limit_secs, binding_id
+            );
+            let _ = app_handle.emit("dictation-max-duration-reached", limit_secs);
+            if let Err(e) = controller.stop_dictation(&binding_id).await {
+                error!("Failed to stop dictation after max duration: {}", e);
+            }
+        });
+        if let Some(previous) = self.watchdog.lock().unwrap().replace(watchdog) {
+            previous.abort();
         }
     }
 
     /// Clean up after dictation ends (success, failure, or cancel)
     fn cleanup(&self) {
-        self.is_active.store(false, Ordering::SeqCst);
+        self.session.lock().unwrap().end();
+        if let Some(watchdog) = self.watchdog.lock().unwrap().take() {
+            watchdog.abort();
+        }
+        self.app_handle
+            .state::<Arc<TranscriptionManager>>()
+            .cancel_streaming();
         change_tray_icon(&self.app_handle, TrayIconState::Idle);
         hide_recording_overlay(&self.app_handle);
 
@@ -349,3 +628,104 @@ impl DictationController {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_other_binding_cannot_stop_recording() {
+        // Push-to-talk: press A, then press and release B while A records
+        let mut session = Session::default();
+        assert!(session.begin("transcribe"));
+        assert!(!session.begin("transcribe_1"));
+        assert!(!session.claim_stop("transcribe_1"));
+        assert_eq!(session.state, DictationState::Recording);
+
+        // A's release still stops the recording
+        assert!(session.claim_stop("transcribe"));
+        assert_eq!(session.state, DictationState::Transcribing);
+        session.end();
+        assert!(session.begin("transcribe_1"));
+    }
+
+    #[test]
+    fn test_other_binding_toggle_is_ignored() {
+        let mut session = Session::default();
+        assert_eq!(
+            session.press_action("transcribe", false),
+            HotkeyAction::Start
+        );
+        assert!(session.begin("transcribe"));
+        assert_eq!(
+            session.press_action("transcribe_1", false),
+            HotkeyAction::Ignore
+        );
+        assert_eq!(
+            session.press_action("transcribe", false),
+            HotkeyAction::Stop
+        );
+    }
+
+    #[test]
+    fn test_toggle_mode() {
+        let mut session = Session::default();
+        // First press starts
+        assert_eq!(
+            session.press_action("transcribe", false),
+            HotkeyAction::Start
+        );
+        assert!(session.begin("transcribe"));
+        // Releases never stop in toggle mode
+        assert_eq!(
+            session.release_action("transcribe", false),
+            HotkeyAction::Ignore
+        );
+        // Second press stops
+        assert_eq!(
+            session.press_action("transcribe", false),
+            HotkeyAction::Stop
+        );
+        assert!(session.claim_stop("transcribe"));
+        // Presses while transcribing are ignored, and so is a second stop
+        assert_eq!(
+            session.press_action("transcribe", false),
+            HotkeyAction::Ignore
+        );
+        assert!(!session.claim_stop("transcribe"));
+        session.end();
+        assert_eq!(
+            session.press_action("transcribe", false),
+            HotkeyAction::Start
+        );
+    }
+
+    #[test]
+    fn test_push_to_talk_mode() {
+        let mut session = Session::default();
+        assert!(session.begin("transcribe"));
+        // Key repeat presses while held are ignored; the release stops
+        assert_eq!(
+            session.press_action("transcribe", true),
+            HotkeyAction::Ignore
+        );
+        assert_eq!(
+            session.release_action("transcribe", true),
+            HotkeyAction::Stop
+        );
+        assert_eq!(
+            session.release_action("transcribe_1", true),
+            HotkeyAction::Ignore
+        );
+    }
+
+    #[test]
+    fn test_session_ids_tell_recordings_apart() {
+        let mut session = Session::default();
+        assert!(session.begin("transcribe"));
+        let first = session.id;
+        session.end();
+        assert!(session.begin("transcribe"));
+        assert_ne!(session.id, first);
+    }
+}
@@ -12,7 +12,9 @@
 //! - Users configure shortcuts through System Settings
 //! - The portal notifies the app when shortcuts are activated
 
-use crate::settings::{get_settings, write_settings};
+use crate::settings::{
+    get_settings, new_dictation_binding, write_settings, ShortcutBinding, DICTATION_BINDING_PREFIX,
+};
 use log::{debug, error, info, warn};
 use std::sync::{Arc, Mutex};
 use tauri::{AppHandle, Emitter};
@@ -20,7 +22,7 @@ use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
 
 #[cfg(target_os = "linux")]
 use crate::wayland_shortcuts::{
-    get_display_server_info, is_portal_available, WaylandShortcutManager,
+    get_display_server_info, is_portal_available, ShortcutAction, WaylandShortcutManager,
 };
 
 /// Backend type for global shortcuts
@@ -199,8 +201,20 @@ impl HotkeyManager {
         // Get the app ID from tauri.conf.json (com.voyc.dictation)
         let app_id = "com.voyc.dictation";
 
-        // Create actions for registration
-        let actions = WaylandShortcutManager::get_default_actions();
+        // Create actions for registration, including dictation bindings added by the user
+        let mut actions = WaylandShortcutManager::get_default_actions();
+        let settings = get_settings(&self.app_handle);
+        let mut added: Vec<&ShortcutBinding> = settings
+            .bindings
+            .values()
+            .filter(|b| b.id.starts_with(DICTATION_BINDING_PREFIX))
+            .collect();
+        added.sort_by(|a, b| a.id.cmp(&b.id));
+        actions.extend(added.into_iter().map(|binding| ShortcutAction {
+            id: binding.id.clone(),
+            description: format!("Voice dictation: {}", binding.name),
+            preferred_trigger: None,
+        }));
 
         // Spawn async task to register with portal
         tauri::async_runtime::spawn(async move {
@@ -525,6 +539,90 @@ impl HotkeyManager {
             .unwrap_or(false)
     }
 
+    /// Adds a dictation binding with its own post-processing prompt.
+    ///
+    /// The new binding has no shortcut yet; the user assigns one with
+    /// `update_binding` (X11) or in System Settings (Wayland).
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Display name, e.g. the prompt it will use
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ShortcutBinding)` - The stored binding
+    /// * `Err(String)` - The name was empty
+    pub fn add_dictation_binding(&self, name: &str) -> Result<ShortcutBinding, String> {
+        if name.trim().is_empty() {
+            return Err("Shortcut name cannot be empty".to_string());
+        }
+
+        let mut settings = get_settings(&self.app_handle);
+        let binding = new_dictation_binding(&settings.bindings, name);
+        settings
+            .bindings
+            .insert(binding.id.clone(), binding.clone());
+        write_settings(&self.app_handle, settings);
+        info!("Added dictation binding '{}'", binding.id);
+
+        self.refresh_portal_actions();
+        Ok(binding)
+    }
+
+    /// Removes a dictation binding added with `add_dictation_binding`.
+    ///
+    /// Built-in bindings cannot be removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The binding identifier to remove
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The binding was removed
+    /// * `Err(String)` - The binding is built in or doesn't exist
+    pub fn remove_dictation_binding(&self, id: &str) -> Result<(), String> {
+        if !id.starts_with(DICTATION_BINDING_PREFIX) {
+            return Err(format!("Binding '{}' cannot be removed", id));
+        }
+
+        let mut settings = get_settings(&self.app_handle);
+        let binding = settings
+            .bindings
+            .remove(id)
+            .ok_or_else(|| format!("Unknown binding id: {}", id))?;
+        if !binding.current_binding.is_empty() {
+            if let Err(e) = self.unregister_shortcut(&binding.current_binding) {
+                warn!("Failed to unregister removed shortcut: {}", e);
+            }
+        }
+        write_settings(&self.app_handle, settings);
+
+        if let Ok(mut suspended) = self.suspended_bindings.lock() {
+            suspended.retain(|b| b != id);
+        }
+        info!("Removed dictation binding '{}'", id);
+
+        self.refresh_portal_actions();
+        Ok(())
+    }
+
+    /// Re-registers the portal actions after bindings were added or removed.
+    ///
+    /// X11 shortcuts are registered per binding, so only Wayland needs this.
+    fn refresh_portal_actions(&self) {
+        if self.get_backend() != ShortcutBackend::WaylandPortal {
+            return;
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            if let Err(e) = self.register_wayland_shortcuts() {
+                warn!("Failed to refresh Wayland shortcuts: {}", e);
+            }
+        }
+    }
+
     /// Opens the system settings for configuring shortcuts (Wayland only).
     ///
     /// On GNOME, this opens Settings > Keyboard > Keyboard Shortcuts
@@ -1,5 +1,6 @@
 pub mod audio_feedback;
 pub mod audio_toolkit;
+pub mod cli;
 pub mod cloud_stt;
 pub mod commands;
 pub mod dictation;
@@ -7,8 +8,11 @@ pub mod hotkey;
 pub mod llm_client;
 pub mod managers;
 pub mod overlay;
+pub mod post_processing;
 pub mod settings;
+pub mod speech_engine;
 pub mod text_injection;
+pub mod transcript;
 pub mod tray;
 pub mod utils;
 
@@ -18,8 +22,12 @@ pub mod wayland_shortcuts;
 #[cfg(target_os = "linux")]
 pub mod desktop_integration;
 
-use log::{info, warn};
+#[cfg(target_os = "linux")]
+pub mod dbus_service;
+
+use log::{error, info, warn};
 use managers::audio::AudioRecordingManager;
+use managers::history::HistoryManager;
 use managers::model::ModelManager;
 use managers::transcription::TranscriptionManager;
 use overlay::create_recording_overlay;
@@ -29,12 +37,27 @@ use tauri::image::Image;
 use tauri::tray::TrayIconBuilder;
 use tauri::{Emitter, Listener, Manager};
 #[cfg(debug_assertions)]
-use specta_typescript::Typescript;
+use specta_typescript::{BigIntExportBehavior, Typescript};
 use tauri_specta::{collect_commands, Builder};
-use tray::{change_tray_icon, get_current_theme, get_icon_path, show_main_window, TrayIconState};
+use tray::{
+    change_tray_icon, create_main_window, get_current_theme, get_icon_path, show_main_window,
+    TrayIconState,
+};
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    // `voyc status`, `voyc last` and `voyc help` print an answer and exit without starting the app
+    let args: Vec<String> = std::env::args().collect();
+    let startup_command = match cli::parse_args(&args) {
+        Ok(Some(command)) if command.is_query() => std::process::exit(cli::run_query(&command)),
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+    let startup = cli::StartupOptions::from_args(&args);
+
     // Set up Specta builder for TypeScript bindings
     let builder = Builder::<tauri::Wry>::new().commands(collect_commands![
         // General commands
@@ -92,6 +115,24 @@ pub fn run() {
         commands::transcription::is_cloud_stt_available,
         commands::transcription::transcribe_with_fallback,
         commands::transcription::transcribe_cloud_only,
+        commands::transcription::transcribe_file,
+        commands::transcription::export_transcript,
+        // History commands
+        commands::history::get_history_entries,
+        commands::history::search_history,
+        commands::history::delete_history_entry,
+        commands::history::clear_history,
+        commands::history::get_history_recording_path,
+        commands::history::get_history_alternatives,
+        commands::history::retranscribe_history_entry,
+        commands::history::set_history_retention,
+        // Post-processing commands
+        commands::post_processing::set_post_process_model,
+        commands::post_processing::set_post_process_base_url,
+        commands::post_processing::fetch_post_process_models,
+        commands::post_processing::save_post_process_prompt,
+        commands::post_processing::delete_post_process_prompt,
+        commands::post_processing::set_binding_post_process_prompt,
         // Text injection commands
         commands::text_injection::inject_text,
         commands::text_injection::check_paste_tools,
@@ -99,6 +140,8 @@ pub fn run() {
         commands::hotkey::update_binding,
         commands::hotkey::suspend_binding,
         commands::hotkey::resume_binding,
+        commands::hotkey::add_dictation_binding,
+        commands::hotkey::remove_dictation_binding,
         commands::hotkey::register_all_shortcuts,
         commands::hotkey::is_binding_suspended,
         commands::hotkey::get_shortcut_backend_info,
@@ -118,7 +161,10 @@ pub fn run() {
     // Export TypeScript bindings in development
     #[cfg(debug_assertions)]
     builder
-        .export(Typescript::default(), "../src/bindings.ts")
+        .export(
+            Typescript::default().bigint(BigIntExportBehavior::Number),
+            "../src/bindings.ts",
+        )
         .expect("Failed to export TypeScript bindings");
 
     tauri::Builder::default()
@@ -129,11 +175,12 @@ pub fn run() {
         .plugin(tauri_plugin_clipboard_manager::init())
         .plugin(tauri_plugin_process::init())
         .plugin(tauri_plugin_fs::init())
-        .plugin(tauri_plugin_single_instance::init(|app, _args, _cwd| {
-            if let Some(main_window) = app.get_webview_window("main") {
-                let _ = main_window.show();
-                let _ = main_window.set_focus();
+        .plugin(tauri_plugin_single_instance::init(|app, args, _cwd| {
+            // `voyc toggle` etc. from a second instance drive dictation without showing the window
+            if cli::handle_forwarded_args(app, &args) {
+                return;
             }
+            show_main_window(app);
         }))
         .plugin(tauri_plugin_global_shortcut::Builder::new().build())
         .plugin(tauri_plugin_autostart::init(
@@ -150,6 +197,16 @@ pub fn run() {
             #[cfg(target_os = "linux")]
             desktop_integration::setup_desktop_integration(&app.handle());
 
+            // The main window has `create: false`; headless mode only builds it on demand
+            if startup.headless {
+                info!("Starting headless: no main window or recording overlay");
+            } else {
+                create_main_window(app.handle())?;
+            }
+
+            // Merge new defaults and migrate stored settings before anything reads them
+            settings::load_or_create_app_settings(app.handle());
+
             // Initialize managers
             info!("Initializing managers...");
 
@@ -175,6 +232,23 @@ pub fn run() {
             app.manage(audio_manager);
             info!("AudioRecordingManager initialized");
 
+            // Initialize HistoryManager; a corrupt or locked database disables history
+            // instead of preventing startup
+            let history_manager = match HistoryManager::new(&app.handle()) {
+                Ok(manager) => {
+                    info!("HistoryManager initialized");
+                    Some(Arc::new(manager))
+                }
+                Err(e) => {
+                    error!(
+                        "Failed to open history database, running without history: {}",
+                        e
+                    );
+                    None
+                }
+            };
+            app.manage(history_manager);
+
             // Initialize HotkeyManager
             let hotkey_manager = Arc::new(hotkey::HotkeyManager::new(app.handle().clone()));
             if let Err(e) = hotkey_manager.register_all() {
@@ -192,6 +266,7 @@ pub fn run() {
             // Set up hotkey event handlers to trigger dictation
             info!("Setting up hotkey event listeners for dictation...");
             let dc_pressed = dictation_controller.clone();
+            let app_handle_pressed = app.handle().clone();
             app.listen("shortcut-pressed", move |event| {
                 let payload = event.payload();
                 log::debug!("Received shortcut-pressed event with payload: {}", payload);
@@ -199,9 +274,19 @@ pub fn run() {
                 match serde_json::from_str::<String>(payload) {
                     Ok(binding_id) => {
                         log::debug!("Parsed shortcut-pressed binding_id: {}", binding_id);
-                        if binding_id == "transcribe" {
-                            if let Err(e) = dc_pressed.start_dictation(&binding_id) {
-                                log::error!("Failed to start dictation: {}", e);
+                        if settings::is_dictation_binding(&binding_id) {
+                            if get_settings(&app_handle_pressed).push_to_talk {
+                                if let Err(e) = dc_pressed.start_dictation(&binding_id) {
+                                    log::error!("Failed to start dictation: {}", e);
+                                }
+                            } else {
+                                // Toggle mode: press starts, next press stops
+                                let dc = dc_pressed.clone();
+                                tauri::async_runtime::spawn(async move {
+                                    if let Err(e) = dc.toggle_dictation(&binding_id).await {
+                                        log::error!("Failed to toggle dictation: {}", e);
+                                    }
+                                });
                             }
                         } else if binding_id == "cancel" {
                             dc_pressed.cancel_dictation();
@@ -218,6 +303,7 @@ pub fn run() {
             });
 
             let dc_released = dictation_controller.clone();
+            let app_handle_released = app.handle().clone();
             app.listen("shortcut-released", move |event| {
                 let payload = event.payload();
                 log::debug!("Received shortcut-released event with payload: {}", payload);
@@ -225,13 +311,12 @@ pub fn run() {
                 match serde_json::from_str::<String>(payload) {
                     Ok(binding_id) => {
                         log::debug!("Parsed shortcut-released binding_id: {}", binding_id);
-                        if binding_id == "transcribe" {
+                        if settings::is_dictation_binding(&binding_id) {
+                            // Release is only meaningful in push-to-talk mode
+                            let push_to_talk = get_settings(&app_handle_released).push_to_talk;
                             let dc = dc_released.clone();
-                            let binding = binding_id.clone();
                             tauri::async_runtime::spawn(async move {
-                                if let Err(e) = dc.stop_dictation(&binding).await {
-                                    log::error!("Failed to stop dictation: {}", e);
-                                }
+                                dc.release_dictation(&binding_id, push_to_talk).await;
                             });
                         }
                     }
@@ -244,66 +329,82 @@ pub fn run() {
                     }
                 }
             });
+            // Hands-free mode: the recorder reports trailing silence after speech
+            let dc_utterance = dictation_controller.clone();
+            app.listen("end-of-utterance", move |_event| {
+                let dc = dc_utterance.clone();
+                tauri::async_runtime::spawn(async move {
+                    dc.handle_end_of_utterance().await;
+                });
+            });
             info!("Hotkey event listeners registered and active");
 
-            // Get the current theme to set the appropriate initial icon
+            // Scriptable control over the session bus (keybinds, Stream Decks, scripts)
+            #[cfg(target_os = "linux")]
+            dbus_service::start(app.handle().clone());
+
             let app_handle = app.handle().clone();
-            let initial_theme = get_current_theme(&app_handle);
-
-            // Choose the appropriate initial icon based on theme
-            let initial_icon_path = get_icon_path(initial_theme.clone(), TrayIconState::Idle);
-
-            // For colored theme (Linux), disable template mode to preserve colors
-            // For macOS dark/light themes, enable template mode for system-tinted icons
-            let use_template = !matches!(initial_theme, tray::AppTheme::Colored);
-
-            let tray = TrayIconBuilder::new()
-                .icon(
-                    Image::from_path(
-                        app_handle
-                            .path()
-                            .resolve(initial_icon_path, tauri::path::BaseDirectory::Resource)
-                            .unwrap(),
+
+            // Tray icon is optional (`--no-tray`), e.g. on WMs without a status notifier host
+            if startup.tray {
+                // Get the current theme to set the appropriate initial icon
+                let initial_theme = get_current_theme(&app_handle);
+
+                // Choose the appropriate initial icon based on theme
+                let initial_icon_path = get_icon_path(initial_theme.clone(), TrayIconState::Idle);
+
+                // For colored theme (Linux), disable template mode to preserve colors
+                // For macOS dark/light themes, enable template mode for system-tinted icons
+                let use_template = !matches!(initial_theme, tray::AppTheme::Colored);
+
+                let tray = TrayIconBuilder::new()
+                    .icon(
+                        Image::from_path(
+                            app_handle
+                                .path()
+                                .resolve(initial_icon_path, tauri::path::BaseDirectory::Resource)
+                                .unwrap(),
+                        )
+                        .unwrap(),
                     )
-                    .unwrap(),
-                )
-                .show_menu_on_left_click(true)
-                .icon_as_template(use_template)
-                .on_menu_event(move |app, event| match event.id.as_ref() {
-                    "settings" => {
-                        show_main_window(app);
-                    }
-                    "check_updates" => {
-                        let settings = get_settings(app);
-                        if settings.update_checks_enabled {
+                    .show_menu_on_left_click(true)
+                    .icon_as_template(use_template)
+                    .on_menu_event(move |app, event| match event.id.as_ref() {
+                        "settings" => {
                             show_main_window(app);
-                            let _ = app.emit("check-for-updates", ());
                         }
-                    }
-                    "start_dictation" => {
-                        // Trigger dictation start via the controller
-                        if let Some(dc) = app.try_state::<std::sync::Arc<dictation::DictationController>>() {
-                            log::info!("Starting dictation from tray menu");
-                            if let Err(e) = dc.start_dictation("transcribe") {
-                                log::error!("Failed to start dictation: {}", e);
+                        "check_updates" => {
+                            let settings = get_settings(app);
+                            if settings.update_checks_enabled {
+                                show_main_window(app);
+                                let _ = app.emit("check-for-updates", ());
                             }
                         }
-                    }
-                    "cancel" => {
-                        commands::cancel_operation(app.clone());
-                    }
-                    "quit" => {
-                        app.exit(0);
-                    }
-                    _ => {}
-                })
-                .build(&app_handle)
-                .unwrap();
-            app.manage(tray);
-            info!("System tray initialized");
+                        "start_dictation" => {
+                            // Trigger dictation start via the controller
+                            if let Some(dc) = app.try_state::<std::sync::Arc<dictation::DictationController>>() {
+                                log::info!("Starting dictation from tray menu");
+                                if let Err(e) = dc.start_dictation("transcribe") {
+                                    log::error!("Failed to start dictation: {}", e);
+                                }
+                            }
+                        }
+                        "cancel" => {
+                            commands::cancel_operation(app.clone());
+                        }
+                        "quit" => {
+                            app.exit(0);
+                        }
+                        _ => {}
+                    })
+                    .build(&app_handle)
+                    .unwrap();
+                app.manage(tray);
+                info!("System tray initialized");
 
-            // Initialize tray menu with idle state
-            change_tray_icon(&app_handle, TrayIconState::Idle);
+                // Initialize tray menu with idle state
+                change_tray_icon(&app_handle, TrayIconState::Idle);
+            }
 
             // Apply saved log level
             let settings = get_settings(&app_handle);
@@ -317,17 +418,27 @@ pub fn run() {
             log::set_max_level(log_level);
             info!("Log level set to: {}", settings.log_level);
 
-            // Show main window on setup (unless start_hidden is enabled)
-            if !settings.start_hidden {
-                if let Some(main_window) = app.get_webview_window("main") {
-                    let _ = main_window.show();
-                    let _ = main_window.set_focus();
+            if startup.headless {
+                // No webview listens in headless mode; report state changes in the log
+                // (on Linux they are also broadcast as D-Bus signals)
+                app.listen("dictation-state-changed", |event| {
+                    if let Ok(state) = serde_json::from_str::<String>(event.payload()) {
+                        info!("Dictation state: {}", state);
+                    }
+                });
+            } else {
+                // Show main window on setup (unless start_hidden is enabled)
+                if !settings.start_hidden {
+                    if let Some(main_window) = app.get_webview_window("main") {
+                        let _ = main_window.show();
+                        let _ = main_window.set_focus();
+                    }
                 }
-            }
 
-            // Create the recording overlay window (hidden by default)
-            create_recording_overlay(&app_handle);
-            info!("Recording overlay created");
+                // Create the recording overlay window (hidden by default)
+                create_recording_overlay(&app_handle);
+                info!("Recording overlay created");
+            }
 
             // Subscribe to system color scheme changes via XDG Desktop Portal (Linux)
             #[cfg(target_os = "linux")]
@@ -351,6 +462,12 @@ pub fn run() {
                 });
             }
 
+            // `voyc toggle` etc. with no instance running started this one; run the command now
+            if let Some(command) = startup_command {
+                info!("Running command line command {:?}", command);
+                cli::run_command(&app_handle, command);
+            }
+
             info!("Application setup complete");
             Ok(())
         })
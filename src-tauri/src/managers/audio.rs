@@ -1,10 +1,17 @@
-use crate::audio_toolkit::{list_input_devices, vad::SmoothedVad, AudioRecorder, SileroVad};
+use crate::audio_toolkit::{
+    list_input_devices, vad::SmoothedVad, AudioRecorder, RecordingOptions, SileroVad, VadParams,
+};
+use crate::managers::transcription::TranscriptionManager;
 use crate::settings::{get_settings, AppSettings};
 use crate::utils;
 use log::{debug, error, info};
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
-use std::time::Instant;
-use tauri::Manager;
+use std::time::{Duration, Instant};
+use tauri::{Emitter, Manager};
+
+/// Speech collected between hand-offs to streaming transcription
+const STREAMING_INTERVAL: Duration = Duration::from_secs(2);
 
 fn set_mute(mute: bool) {
     // Expected behavior:
@@ -69,13 +76,52 @@ pub enum MicrophoneMode {
 
 /* ──────────────────────────────────────────────────────────────── */
 
+/// VAD tuning derived from the user's settings
+fn vad_params_from_settings(settings: &AppSettings) -> VadParams {
+    VadParams {
+        threshold: settings.vad_threshold.clamp(0.0, 1.0),
+        prefill_frames: settings.vad_prefill_frames as usize,
+        hangover_frames: settings.vad_hangover_frames as usize,
+        onset_frames: settings.vad_onset_frames.max(1) as usize,
+    }
+}
+
+/// Locate the Silero VAD model - works for both dev and release modes
+pub fn resolve_vad_model_path(app_handle: &tauri::AppHandle) -> Result<PathBuf, anyhow::Error> {
+    app_handle
+        .path()
+        .resolve(
+            "resources/models/silero_vad_v4.onnx",
+            tauri::path::BaseDirectory::Resource,
+        )
+        .or_else(|_| {
+            // Fallback for development mode - look relative to src-tauri
+            let dev_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("resources")
+                .join("models")
+                .join("silero_vad_v4.onnx");
+            if dev_path.exists() {
+                Ok(dev_path)
+            } else {
+                Err(tauri::Error::UnknownPath)
+            }
+        })
+        .map_err(|e| anyhow::anyhow!("Failed to resolve VAD path: {}. Ensure the model exists at resources/models/silero_vad_v4.onnx", e))
+}
+
 fn create_audio_recorder(
     vad_path: &str,
     app_handle: &tauri::AppHandle,
+    vad_params: VadParams,
 ) -> Result<AudioRecorder, anyhow::Error> {
-    let silero = SileroVad::new(vad_path, 0.3)
+    let silero = SileroVad::new(vad_path, vad_params.threshold)
         .map_err(|e| anyhow::anyhow!("Failed to create SileroVad: {}", e))?;
-    let smoothed_vad = SmoothedVad::new(Box::new(silero), 15, 15, 2);
+    let smoothed_vad = SmoothedVad::new(
+        Box::new(silero),
+        vad_params.prefill_frames,
+        vad_params.hangover_frames,
+        vad_params.onset_frames,
+    );
 
     // Recorder with VAD plus a spectrum-level callback that forwards updates to
     // the frontend.
@@ -87,6 +133,22 @@ fn create_audio_recorder(
             move |levels| {
                 utils::emit_levels(&app_handle, &levels);
             }
+        })
+        .with_end_of_utterance_callback({
+            let app_handle = app_handle.clone();
+            move || {
+                let _ = app_handle.emit("end-of-utterance", ());
+            }
+        })
+        .with_speech_callback({
+            let app_handle = app_handle.clone();
+            move |samples| {
+                if let Some(transcription_manager) =
+                    app_handle.try_state::<Arc<TranscriptionManager>>()
+                {
+                    transcription_manager.push_streaming_audio(samples);
+                }
+            }
         });
 
     Ok(recorder)
@@ -191,38 +253,20 @@ impl AudioRecordingManager {
         let mut did_mute_guard = self.did_mute.lock().unwrap();
         *did_mute_guard = false;
 
-        // Try to resolve VAD model path - works for both dev and release modes
-        let vad_path = self
-            .app_handle
-            .path()
-            .resolve(
-                "resources/models/silero_vad_v4.onnx",
-                tauri::path::BaseDirectory::Resource,
-            )
-            .or_else(|_| {
-                // Fallback for development mode - look relative to src-tauri
-                let dev_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-                    .join("resources")
-                    .join("models")
-                    .join("silero_vad_v4.onnx");
-                if dev_path.exists() {
-                    Ok(dev_path)
-                } else {
-                    Err(tauri::Error::UnknownPath)
-                }
-            })
-            .map_err(|e| anyhow::anyhow!("Failed to resolve VAD path: {}. Ensure the model exists at resources/models/silero_vad_v4.onnx", e))?;
+        let vad_path = resolve_vad_model_path(&self.app_handle)?;
         let mut recorder_opt = self.recorder.lock().unwrap();
 
+        // Get the selected device and VAD tuning from settings
+        let settings = get_settings(&self.app_handle);
+
         if recorder_opt.is_none() {
             *recorder_opt = Some(create_audio_recorder(
                 vad_path.to_str().unwrap(),
                 &self.app_handle,
+                vad_params_from_settings(&settings),
             )?);
         }
 
-        // Get the selected device from settings
-        let settings = get_settings(&self.app_handle);
         let selected_device = self.get_effective_microphone_device(&settings);
 
         if let Some(rec) = recorder_opt.as_mut() {
@@ -301,8 +345,19 @@ impl AudioRecordingManager {
                 }
             }
 
+            // Hands-free mode: let the recorder report trailing silence
+            let settings = get_settings(&self.app_handle);
+            let options = RecordingOptions {
+                end_of_utterance: settings
+                    .auto_stop_on_silence
+                    .then(|| Duration::from_millis(settings.auto_stop_silence_ms as u64)),
+                speech_interval: settings
+                    .streaming_transcription
+                    .then_some(STREAMING_INTERVAL),
+            };
+
             if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
-                if rec.start().is_ok() {
+                if rec.start_with_options(options).is_ok() {
                     *self.is_recording.lock().unwrap() = true;
                     *state = RecordingState::Recording {
                         binding_id: binding_id.to_string(),
@@ -318,6 +373,19 @@ impl AudioRecordingManager {
         }
     }
 
+    /// Push the VAD settings into the recorder without reopening the microphone
+    pub fn update_vad_params(&self) -> Result<(), anyhow::Error> {
+        let settings = get_settings(&self.app_handle);
+        let params = vad_params_from_settings(&settings);
+
+        if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
+            rec.update_vad_params(params)
+                .map_err(|e| anyhow::anyhow!("Failed to update VAD parameters: {}", e))?;
+            debug!("VAD parameters pushed to recorder: {:?}", params);
+        }
+        Ok(())
+    }
+
     pub fn update_selected_device(&self) -> Result<(), anyhow::Error> {
         // If currently open, restart the microphone stream to use the new device
         if *self.is_open.lock().unwrap() {
@@ -370,6 +438,14 @@ impl AudioRecordingManager {
             _ => None,
         }
     }
+    /// Binding id of the recording in progress, if any
+    pub fn active_binding_id(&self) -> Option<String> {
+        match &*self.state.lock().unwrap() {
+            RecordingState::Recording { binding_id } => Some(binding_id.clone()),
+            RecordingState::Idle => None,
+        }
+    }
+
     pub fn is_recording(&self) -> bool {
         matches!(
             *self.state.lock().unwrap(),
@@ -398,3 +474,34 @@ impl AudioRecordingManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::get_default_settings;
+
+    #[test]
+    fn test_vad_params_from_default_settings() {
+        assert_eq!(
+            vad_params_from_settings(&get_default_settings()),
+            VadParams {
+                threshold: 0.3,
+                prefill_frames: 15,
+                hangover_frames: 15,
+                onset_frames: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_vad_params_are_sanitized() {
+        let settings = AppSettings {
+            vad_threshold: 1.5,
+            vad_onset_frames: 0,
+            ..get_default_settings()
+        };
+        let params = vad_params_from_settings(&settings);
+        assert_eq!(params.threshold, 1.0);
+        assert_eq!(params.onset_frames, 1);
+    }
+}
@@ -0,0 +1,691 @@
+//! Dictation history stored in a SQLite database in the app data dir
+//!
+//! Every completed dictation is recorded from its `DictationCompleteEvent`.
+//! Entries are searchable through an FTS5 index that is kept in sync with the
+//! main table by triggers, and pruned according to `HistoryRetention`.
+//!
+//! When `save_recordings` is on, the audio of each dictation is kept next to
+//! the database as `recordings/<entry id>.wav`, pruned under a disk quota and
+//! removed together with its entry.
+
+use crate::audio_toolkit::save_wav_file;
+use crate::dictation::{DictationCompleteEvent, LatencyMetrics};
+use crate::settings::{get_settings, HistoryRetention};
+use anyhow::Result;
+use log::{debug, info, warn};
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use rusqlite_migration::{Migrations, M};
+use serde::Serialize;
+use specta::Type;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
+
+const DB_FILENAME: &str = "history.db";
+const RECORDINGS_DIR: &str = "recordings";
+
+/// Schema migrations, applied in order. Never edit an existing entry; append a new one.
+static MIGRATIONS: &[M<'static>] = &[
+    M::up(
+        "CREATE TABLE dictation_history (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        timestamp INTEGER NOT NULL,
+        text TEXT NOT NULL,
+        raw_text TEXT NOT NULL,
+        post_processed INTEGER NOT NULL DEFAULT 0,
+        model_id TEXT,
+        provider TEXT,
+        used_fallback INTEGER NOT NULL DEFAULT 0,
+        duration_ms INTEGER NOT NULL DEFAULT 0,
+        capture_ms INTEGER NOT NULL DEFAULT 0,
+        transcription_ms INTEGER NOT NULL DEFAULT 0,
+        post_process_ms INTEGER NOT NULL DEFAULT 0,
+        injection_ms INTEGER NOT NULL DEFAULT 0,
+        total_ms INTEGER NOT NULL DEFAULT 0
+    );
+    CREATE INDEX idx_dictation_history_timestamp ON dictation_history(timestamp);
+
+    CREATE VIRTUAL TABLE dictation_history_fts USING fts5(
+        text,
+        raw_text,
+        content='dictation_history',
+        content_rowid='id'
+    );
+    CREATE TRIGGER dictation_history_ai AFTER INSERT ON dictation_history BEGIN
+        INSERT INTO dictation_history_fts(rowid, text, raw_text)
+        VALUES (new.id, new.text, new.raw_text);
+    END;
+    CREATE TRIGGER dictation_history_ad AFTER DELETE ON dictation_history BEGIN
+        INSERT INTO dictation_history_fts(dictation_history_fts, rowid, text, raw_text)
+        VALUES ('delete', old.id, old.text, old.raw_text);
+    END;
+    CREATE TRIGGER dictation_history_au AFTER UPDATE ON dictation_history BEGIN
+        INSERT INTO dictation_history_fts(dictation_history_fts, rowid, text, raw_text)
+        VALUES ('delete', old.id, old.text, old.raw_text);
+        INSERT INTO dictation_history_fts(rowid, text, raw_text)
+        VALUES (new.id, new.text, new.raw_text);
+    END;",
+    ),
+    // Alternative transcripts produced by re-transcribing a saved recording
+    M::up(
+        "CREATE TABLE dictation_alternatives (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        entry_id INTEGER NOT NULL REFERENCES dictation_history(id),
+        timestamp INTEGER NOT NULL,
+        text TEXT NOT NULL,
+        model_id TEXT,
+        provider TEXT,
+        duration_ms INTEGER NOT NULL DEFAULT 0
+    );
+    CREATE INDEX idx_dictation_alternatives_entry ON dictation_alternatives(entry_id);
+    CREATE TRIGGER dictation_history_alternatives_ad AFTER DELETE ON dictation_history BEGIN
+        DELETE FROM dictation_alternatives WHERE entry_id = old.id;
+    END;",
+    ),
+];
+
+// Selected from `dictation_history h`, in `HistoryEntry::from_row` order
+const SELECT_COLUMNS: &str =
+    "h.id, h.timestamp, h.text, h.raw_text, h.post_processed, h.model_id, \
+     h.provider, h.used_fallback, h.duration_ms, h.capture_ms, h.transcription_ms, \
+     h.post_process_ms, h.injection_ms, h.total_ms";
+
+/// Another transcription of a history entry's recording
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct AlternativeTranscript {
+    pub id: i64,
+    pub entry_id: i64,
+    /// Unix timestamp in seconds
+    pub timestamp: i64,
+    pub text: String,
+    /// Local model used (None for cloud providers)
+    pub model_id: Option<String>,
+    /// Cloud provider used (None for local models)
+    pub provider: Option<String>,
+    pub duration_ms: u64,
+}
+
+impl AlternativeTranscript {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            entry_id: row.get(1)?,
+            timestamp: row.get(2)?,
+            text: row.get(3)?,
+            model_id: row.get(4)?,
+            provider: row.get(5)?,
+            duration_ms: row.get::<_, i64>(6)? as u64,
+        })
+    }
+}
+
+/// A recorded dictation
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct HistoryEntry {
+    pub id: i64,
+    /// Unix timestamp in seconds
+    pub timestamp: i64,
+    /// Final text (after post-processing)
+    pub text: String,
+    /// Transcription before post-processing
+    pub raw_text: String,
+    pub post_processed: bool,
+    pub model_id: Option<String>,
+    pub provider: Option<String>,
+    pub used_fallback: bool,
+    pub duration_ms: u64,
+    pub latency: LatencyMetrics,
+    /// Whether the dictation audio was saved (see `get_history_recording_path`)
+    pub has_recording: bool,
+}
+
+impl HistoryEntry {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            timestamp: row.get(1)?,
+            text: row.get(2)?,
+            raw_text: row.get(3)?,
+            post_processed: row.get(4)?,
+            model_id: row.get(5)?,
+            provider: row.get(6)?,
+            used_fallback: row.get(7)?,
+            duration_ms: row.get::<_, i64>(8)? as u64,
+            latency: LatencyMetrics {
+                capture_ms: row.get::<_, i64>(9)? as u64,
+                transcription_ms: row.get::<_, i64>(10)? as u64,
+                post_process_ms: row.get::<_, i64>(11)? as u64,
+                injection_ms: row.get::<_, i64>(12)? as u64,
+                total_ms: row.get::<_, i64>(13)? as u64,
+            },
+            has_recording: false,
+        })
+    }
+}
+
+pub struct HistoryManager {
+    app_handle: AppHandle,
+    db_path: PathBuf,
+    recordings_dir: PathBuf,
+    // Serializes writers; SQLite handles concurrent readers itself
+    write_lock: Mutex<()>,
+}
+
+impl HistoryManager {
+    pub fn new(app_handle: &AppHandle) -> Result<Self> {
+        let app_data_dir = app_handle
+            .path()
+            .app_data_dir()
+            .map_err(|e| anyhow::anyhow!("Failed to get app data dir: {}", e))?;
+
+        let recordings_dir = app_data_dir.join(RECORDINGS_DIR);
+        if !recordings_dir.exists() {
+            fs::create_dir_all(&recordings_dir)?;
+        }
+
+        let manager = Self {
+            app_handle: app_handle.clone(),
+            db_path: app_data_dir.join(DB_FILENAME),
+            recordings_dir,
+            write_lock: Mutex::new(()),
+        };
+
+        let mut conn = Connection::open(&manager.db_path)?;
+        migrate(&mut conn)?;
+        info!("History database ready at {:?}", manager.db_path);
+
+        manager.apply_retention()?;
+        Ok(manager)
+    }
+
+    fn connection(&self) -> Result<Connection> {
+        Ok(Connection::open(&self.db_path)?)
+    }
+
+    /// Record a completed dictation. Returns the new entry id, or None when
+    /// history is disabled by the retention setting.
+    pub fn save_dictation(&self, event: &DictationCompleteEvent) -> Result<Option<i64>> {
+        let retention = get_settings(&self.app_handle).history_retention;
+        if !retention.records_history() {
+            return Ok(None);
+        }
+
+        let guard = self.write_lock.lock().unwrap();
+        let conn = self.connection()?;
+        let id = insert_entry(&conn, event, chrono::Utc::now().timestamp())?;
+        if prune_older_than(&conn, retention, chrono::Utc::now().timestamp())? > 0 {
+            self.remove_orphan_recordings(&conn)?;
+        }
+        drop(guard);
+
+        debug!("Saved dictation to history with id {}", id);
+        let _ = self.app_handle.emit("history-updated", ());
+        Ok(Some(id))
+    }
+
+    /// Most recent entries first
+    pub fn list_entries(&self, limit: u32, offset: u32) -> Result<Vec<HistoryEntry>> {
+        let conn = self.connection()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM dictation_history h
+             ORDER BY h.timestamp DESC, h.id DESC LIMIT ?1 OFFSET ?2",
+            SELECT_COLUMNS
+        ))?;
+        let entries = stmt
+            .query_map(params![limit, offset], HistoryEntry::from_row)?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(self.with_recording_flags(entries))
+    }
+
+    pub fn get_entry(&self, id: i64) -> Result<Option<HistoryEntry>> {
+        let conn = self.connection()?;
+        let entry = conn
+            .query_row(
+                &format!(
+                    "SELECT {} FROM dictation_history h WHERE h.id = ?1",
+                    SELECT_COLUMNS
+                ),
+                params![id],
+                HistoryEntry::from_row,
+            )
+            .optional()?;
+        Ok(entry.map(|mut e| {
+            e.has_recording = self.recording_path(e.id).exists();
+            e
+        }))
+    }
+
+    /// Full-text search over final and raw text, best matches first
+    pub fn search(&self, query: &str, limit: u32) -> Result<Vec<HistoryEntry>> {
+        let conn = self.connection()?;
+        Ok(self.with_recording_flags(search_entries(&conn, query, limit)?))
+    }
+
+    /// Returns whether an entry was deleted
+    pub fn delete_entry(&self, id: i64) -> Result<bool> {
+        let guard = self.write_lock.lock().unwrap();
+        let conn = self.connection()?;
+        let deleted = conn.execute("DELETE FROM dictation_history WHERE id = ?1", params![id])?;
+        drop(guard);
+        self.remove_recording(id);
+
+        if deleted > 0 {
+            let _ = self.app_handle.emit("history-updated", ());
+        }
+        Ok(deleted > 0)
+    }
+
+    pub fn clear(&self) -> Result<()> {
+        let guard = self.write_lock.lock().unwrap();
+        let conn = self.connection()?;
+        conn.execute("DELETE FROM dictation_history", [])?;
+        self.remove_orphan_recordings(&conn)?;
+        drop(guard);
+
+        info!("Dictation history cleared");
+        let _ = self.app_handle.emit("history-updated", ());
+        Ok(())
+    }
+
+    /// Delete entries that fall outside the configured retention period
+    pub fn apply_retention(&self) -> Result<()> {
+        let retention = get_settings(&self.app_handle).history_retention;
+        let guard = self.write_lock.lock().unwrap();
+        let conn = self.connection()?;
+        let pruned = prune_older_than(&conn, retention, chrono::Utc::now().timestamp())?;
+        self.remove_orphan_recordings(&conn)?;
+        drop(guard);
+
+        if pruned > 0 {
+            info!("Pruned {} history entries ({:?})", pruned, retention);
+            let _ = self.app_handle.emit("history-updated", ());
+        }
+        Ok(())
+    }
+
+    /// Store another transcription of an entry's recording
+    pub fn add_alternative(
+        &self,
+        entry_id: i64,
+        text: &str,
+        model_id: Option<&str>,
+        provider: Option<&str>,
+        duration_ms: u64,
+    ) -> Result<AlternativeTranscript> {
+        let guard = self.write_lock.lock().unwrap();
+        let conn = self.connection()?;
+        let alternative = insert_alternative(
+            &conn,
+            entry_id,
+            chrono::Utc::now().timestamp(),
+            text,
+            model_id,
+            provider,
+            duration_ms,
+        )?;
+        drop(guard);
+
+        let _ = self.app_handle.emit("history-updated", ());
+        Ok(alternative)
+    }
+
+    /// Alternative transcripts of an entry, oldest first
+    pub fn list_alternatives(&self, entry_id: i64) -> Result<Vec<AlternativeTranscript>> {
+        let conn = self.connection()?;
+        list_alternatives(&conn, entry_id)
+    }
+
+    /// Path of the saved audio for an entry (the file may not exist)
+    pub fn recording_path(&self, id: i64) -> PathBuf {
+        self.recordings_dir.join(format!("{}.wav", id))
+    }
+
+    /// Save the 16 kHz samples of a dictation for the given entry, then prune
+    /// old recordings that exceed the disk quota
+    pub async fn save_recording(&self, id: i64, samples: &[f32]) -> Result<()> {
+        save_wav_file(self.recording_path(id), samples).await?;
+        debug!("Saved recording for history entry {}", id);
+        self.enforce_recordings_quota()
+    }
+
+    /// Delete the oldest recordings until they fit in `recordings_quota_mb`
+    pub fn enforce_recordings_quota(&self) -> Result<()> {
+        let quota_mb = get_settings(&self.app_handle).recordings_quota_mb;
+        if quota_mb == 0 {
+            return Ok(());
+        }
+
+        let recordings = list_recordings(&self.recordings_dir)?;
+        let to_prune = recordings_over_quota(&recordings, u64::from(quota_mb) * 1024 * 1024);
+        if !to_prune.is_empty() {
+            info!(
+                "Pruning {} recordings to stay under {} MB",
+                to_prune.len(),
+                quota_mb
+            );
+            for id in to_prune {
+                self.remove_recording(id);
+            }
+            let _ = self.app_handle.emit("history-updated", ());
+        }
+        Ok(())
+    }
+
+    fn remove_recording(&self, id: i64) {
+        let path = self.recording_path(id);
+        if path.exists() {
+            if let Err(e) = fs::remove_file(&path) {
+                warn!("Failed to remove recording {:?}: {}", path, e);
+            }
+        }
+    }
+
+    /// Remove recordings whose history entry no longer exists
+    fn remove_orphan_recordings(&self, conn: &Connection) -> Result<()> {
+        let mut stmt = conn.prepare("SELECT id FROM dictation_history")?;
+        let ids = stmt
+            .query_map([], |row| row.get::<_, i64>(0))?
+            .collect::<rusqlite::Result<HashSet<_>>>()?;
+
+        for (id, _) in list_recordings(&self.recordings_dir)? {
+            if !ids.contains(&id) {
+                self.remove_recording(id);
+            }
+        }
+        Ok(())
+    }
+
+    fn with_recording_flags(&self, mut entries: Vec<HistoryEntry>) -> Vec<HistoryEntry> {
+        for entry in &mut entries {
+            entry.has_recording = self.recording_path(entry.id).exists();
+        }
+        entries
+    }
+}
+
+/// Saved recordings as (entry id, size in bytes)
+fn list_recordings(dir: &Path) -> Result<Vec<(i64, u64)>> {
+    let mut recordings = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("wav") {
+            continue;
+        }
+        let Some(id) = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .and_then(|s| s.parse::<i64>().ok())
+        else {
+            continue;
+        };
+        recordings.push((id, fs::metadata(&path)?.len()));
+    }
+    Ok(recordings)
+}
+
+/// Pick the oldest recordings (lowest entry ids) to delete so the remaining
+/// total size fits in `quota_bytes`
+fn recordings_over_quota(recordings: &[(i64, u64)], quota_bytes: u64) -> Vec<i64> {
+    let mut sorted = recordings.to_vec();
+    sorted.sort_by_key(|(id, _)| *id);
+
+    let mut total: u64 = sorted.iter().map(|(_, size)| size).sum();
+    let mut to_prune = Vec::new();
+    for (id, size) in sorted {
+        if total <= quota_bytes {
+            break;
+        }
+        total -= size;
+        to_prune.push(id);
+    }
+    to_prune
+}
+
+fn migrate(conn: &mut Connection) -> Result<()> {
+    Migrations::from_slice(MIGRATIONS)
+        .to_latest(conn)
+        .map_err(|e| anyhow::anyhow!("Failed to migrate history database: {}", e))
+}
+
+fn insert_entry(conn: &Connection, event: &DictationCompleteEvent, timestamp: i64) -> Result<i64> {
+    conn.execute(
+        "INSERT INTO dictation_history (
+            timestamp, text, raw_text, post_processed, model_id, provider, used_fallback,
+            duration_ms, capture_ms, transcription_ms, post_process_ms, injection_ms, total_ms
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+        params![
+            timestamp,
+            event.text,
+            event.raw_text,
+            event.post_processed,
+            event.model_id,
+            event.provider,
+            event.used_fallback,
+            event.duration_ms as i64,
+            event.latency.capture_ms as i64,
+            event.latency.transcription_ms as i64,
+            event.latency.post_process_ms as i64,
+            event.latency.injection_ms as i64,
+            event.latency.total_ms as i64,
+        ],
+    )?;
+    Ok(conn.last_insert_rowid())
+}
+
+fn insert_alternative(
+    conn: &Connection,
+    entry_id: i64,
+    timestamp: i64,
+    text: &str,
+    model_id: Option<&str>,
+    provider: Option<&str>,
+    duration_ms: u64,
+) -> Result<AlternativeTranscript> {
+    let exists: bool = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM dictation_history WHERE id = ?1)",
+        params![entry_id],
+        |row| row.get(0),
+    )?;
+    if !exists {
+        return Err(anyhow::anyhow!("History entry not found: {}", entry_id));
+    }
+
+    conn.execute(
+        "INSERT INTO dictation_alternatives (entry_id, timestamp, text, model_id, provider, duration_ms)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![entry_id, timestamp, text, model_id, provider, duration_ms as i64],
+    )?;
+
+    Ok(AlternativeTranscript {
+        id: conn.last_insert_rowid(),
+        entry_id,
+        timestamp,
+        text: text.to_string(),
+        model_id: model_id.map(str::to_string),
+        provider: provider.map(str::to_string),
+        duration_ms,
+    })
+}
+
+fn list_alternatives(conn: &Connection, entry_id: i64) -> Result<Vec<AlternativeTranscript>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, entry_id, timestamp, text, model_id, provider, duration_ms
+         FROM dictation_alternatives WHERE entry_id = ?1 ORDER BY id",
+    )?;
+    let alternatives = stmt
+        .query_map(params![entry_id], AlternativeTranscript::from_row)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(alternatives)
+}
+
+fn search_entries(conn: &Connection, query: &str, limit: u32) -> Result<Vec<HistoryEntry>> {
+    let Some(match_expr) = fts_match_expression(query) else {
+        return Ok(Vec::new());
+    };
+
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {} FROM dictation_history_fts f
+         JOIN dictation_history h ON h.id = f.rowid
+         WHERE dictation_history_fts MATCH ?1
+         ORDER BY f.rank LIMIT ?2",
+        SELECT_COLUMNS
+    ))?;
+    let entries = stmt
+        .query_map(params![match_expr, limit], HistoryEntry::from_row)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(entries)
+}
+
+/// Returns the number of deleted entries
+fn prune_older_than(conn: &Connection, retention: HistoryRetention, now: i64) -> Result<usize> {
+    let Some(days) = retention.to_days() else {
+        return Ok(0);
+    };
+    let cutoff = now - i64::from(days) * 24 * 60 * 60;
+    let deleted = conn.execute(
+        "DELETE FROM dictation_history WHERE timestamp < ?1",
+        params![cutoff],
+    )?;
+    Ok(deleted)
+}
+
+/// Turn free-form user input into a safe FTS5 match expression.
+///
+/// Each whitespace-separated term is quoted (so FTS5 operators and punctuation
+/// in the input can't cause syntax errors) and prefix-matched; all terms must match.
+pub fn fts_match_expression(query: &str) -> Option<String> {
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .map(|term| term.replace('"', ""))
+        .filter(|term| !term.is_empty())
+        .map(|term| format!("\"{}\"*", term))
+        .collect();
+
+    if terms.is_empty() {
+        None
+    } else {
+        Some(terms.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_db() -> Connection {
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrate(&mut conn).unwrap();
+        conn
+    }
+
+    fn event(text: &str, raw_text: &str) -> DictationCompleteEvent {
+        DictationCompleteEvent {
+            text: text.to_string(),
+            raw_text: raw_text.to_string(),
+            post_processed: text != raw_text,
+            model_id: None,
+            used_fallback: false,
+            provider: None,
+            duration_ms: 120,
+            latency: LatencyMetrics::default(),
+            history_id: None,
+        }
+    }
+
+    #[test]
+    fn test_migrations_are_valid() {
+        assert!(Migrations::from_slice(MIGRATIONS).validate().is_ok());
+    }
+
+    #[test]
+    fn test_fts_match_expression() {
+        assert_eq!(
+            fts_match_expression("hello world"),
+            Some("\"hello\"* \"world\"*".to_string())
+        );
+        assert_eq!(
+            fts_match_expression("say \"NEAR\" OR"),
+            Some("\"say\"* \"NEAR\"* \"OR\"*".to_string())
+        );
+        assert_eq!(fts_match_expression("   "), None);
+        assert_eq!(fts_match_expression("\"\""), None);
+    }
+
+    #[test]
+    fn test_search_matches_raw_and_processed_text() {
+        let conn = test_db();
+        insert_entry(&conn, &event("Meeting notes.", "meeting notes"), 1).unwrap();
+        let mut milk = event("- buy milk", "buy some milk");
+        milk.model_id = Some("base".to_string());
+        insert_entry(&conn, &milk, 2).unwrap();
+
+        let results = search_entries(&conn, "milk", 10).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].model_id.as_deref(), Some("base"));
+
+        // Prefix match against the raw text only
+        let results = search_entries(&conn, "som", 10).unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].post_processed);
+    }
+
+    #[test]
+    fn test_deleted_entries_leave_search_index() {
+        let conn = test_db();
+        let id = insert_entry(&conn, &event("hello there", "hello there"), 1).unwrap();
+        conn.execute("DELETE FROM dictation_history WHERE id = ?1", params![id])
+            .unwrap();
+        assert!(search_entries(&conn, "hello", 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_alternatives_follow_their_entry() {
+        let conn = test_db();
+        let id = insert_entry(&conn, &event("hello", "hello"), 1).unwrap();
+        insert_alternative(&conn, id, 2, "hallo", Some("large"), None, 900).unwrap();
+        insert_alternative(&conn, id, 3, "hello!", None, Some("OpenAI Whisper"), 400).unwrap();
+        assert!(insert_alternative(&conn, id + 1, 4, "nope", None, None, 0).is_err());
+
+        let alternatives = list_alternatives(&conn, id).unwrap();
+        assert_eq!(alternatives.len(), 2);
+        assert_eq!(alternatives[0].model_id.as_deref(), Some("large"));
+
+        conn.execute("DELETE FROM dictation_history WHERE id = ?1", params![id])
+            .unwrap();
+        assert!(list_alternatives(&conn, id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_recordings_over_quota_prunes_oldest_first() {
+        let recordings = [(3, 400), (1, 300), (2, 300)];
+        assert!(recordings_over_quota(&recordings, 1000).is_empty());
+        assert_eq!(recordings_over_quota(&recordings, 700), vec![1]);
+        assert_eq!(recordings_over_quota(&recordings, 500), vec![1, 2]);
+        assert_eq!(recordings_over_quota(&recordings, 0), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_prune_respects_retention() {
+        let conn = test_db();
+        let day = 24 * 60 * 60;
+        let now = 100 * day;
+        insert_entry(&conn, &event("old", "old"), now - 40 * day).unwrap();
+        insert_entry(&conn, &event("recent", "recent"), now - day).unwrap();
+
+        assert_eq!(
+            prune_older_than(&conn, HistoryRetention::Forever, now).unwrap(),
+            0
+        );
+        assert_eq!(
+            prune_older_than(&conn, HistoryRetention::Days30, now).unwrap(),
+            1
+        );
+        // Turning history off keeps what was already saved
+        assert_eq!(
+            prune_older_than(&conn, HistoryRetention::Never, now).unwrap(),
+            0
+        );
+    }
+}
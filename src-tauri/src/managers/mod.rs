@@ -1,3 +1,4 @@
 pub mod audio;
+pub mod history;
 pub mod model;
 pub mod transcription;
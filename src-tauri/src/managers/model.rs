@@ -14,11 +14,28 @@ use std::sync::Mutex;
 use tar::Archive;
 use tauri::{AppHandle, Emitter, Manager};
 
-#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+/// Moonshine model size; must match the model files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum MoonshineVariant {
+    Tiny,
+    Base,
+}
+
+/// Precision the Parakeet ONNX weights were exported at
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ParakeetQuantization {
+    Int8,
+    Fp32,
+}
+
+/// Engine a model runs on, with the load-time options its files need
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
 pub enum EngineType {
     Whisper,
-    Parakeet,
-    Moonshine,
+    Parakeet(ParakeetQuantization),
+    Moonshine(MoonshineVariant),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -159,7 +176,7 @@ impl ModelManager {
                 is_downloading: false,
                 partial_size: 0,
                 is_directory: true,
-                engine_type: EngineType::Parakeet,
+                engine_type: EngineType::Parakeet(ParakeetQuantization::Int8),
                 accuracy_score: 0.85,
                 speed_score: 0.85,
             },
@@ -178,12 +195,51 @@ impl ModelManager {
                 is_downloading: false,
                 partial_size: 0,
                 is_directory: true,
-                engine_type: EngineType::Parakeet,
+                engine_type: EngineType::Parakeet(ParakeetQuantization::Int8),
                 accuracy_score: 0.80,
                 speed_score: 0.85,
             },
         );
 
+        available_models.insert(
+            "parakeet-tdt-0.6b-v3-fp32".to_string(),
+            ModelInfo {
+                id: "parakeet-tdt-0.6b-v3-fp32".to_string(),
+                name: "Parakeet V3 (full precision)".to_string(),
+                description: "Unquantized Parakeet V3. Slightly more accurate, much larger."
+                    .to_string(),
+                filename: "parakeet-tdt-0.6b-v3-fp32".to_string(), // Directory name
+                url: Some("https://blob.handy.computer/parakeet-v3-fp32.tar.gz".to_string()),
+                size_mb: 2400, // Approximate size for fp32 weights
+                is_downloaded: false,
+                is_downloading: false,
+                partial_size: 0,
+                is_directory: true,
+                engine_type: EngineType::Parakeet(ParakeetQuantization::Fp32),
+                accuracy_score: 0.82,
+                speed_score: 0.70,
+            },
+        );
+
+        available_models.insert(
+            "moonshine-tiny".to_string(),
+            ModelInfo {
+                id: "moonshine-tiny".to_string(),
+                name: "Moonshine Tiny".to_string(),
+                description: "Fastest, English only. For slow machines.".to_string(),
+                filename: "moonshine-tiny".to_string(),
+                url: Some("https://blob.handy.computer/moonshine-tiny.tar.gz".to_string()),
+                size_mb: 30, // Approximate size
+                is_downloaded: false,
+                is_downloading: false,
+                partial_size: 0,
+                is_directory: true,
+                engine_type: EngineType::Moonshine(MoonshineVariant::Tiny),
+                accuracy_score: 0.60,
+                speed_score: 0.95,
+            },
+        );
+
         available_models.insert(
             "moonshine-base".to_string(),
             ModelInfo {
@@ -197,7 +253,7 @@ impl ModelManager {
                 is_downloading: false,
                 partial_size: 0,
                 is_directory: true,
-                engine_type: EngineType::Moonshine,
+                engine_type: EngineType::Moonshine(MoonshineVariant::Base),
                 accuracy_score: 0.70,
                 speed_score: 0.90,
             },
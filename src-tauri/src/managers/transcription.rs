@@ -1,31 +1,53 @@
-use crate::audio_toolkit::{apply_custom_words, filter_transcription_output};
+use crate::audio_toolkit::chunking::{
+    plan_chunks, stitch_overlap, AudioChunk, ChunkParams, StreamingChunker,
+};
+use crate::audio_toolkit::vad::SILERO_FRAME_SAMPLES;
+use crate::audio_toolkit::{
+    apply_custom_words, apply_replacements, apply_spoken_commands, convert_chinese_script,
+    decode_audio_file, filter_transcription_output, inverse_text_normalization, SileroVad,
+    VoiceActivityDetector,
+};
 use crate::cloud_stt::{
-    estimate_confidence, CloudSttClient, CloudSttConfig, FallbackTriggeredEvent,
+    estimate_confidence, CloudSttClient, CloudSttConfig, CloudSttProvider, FallbackTriggeredEvent,
 };
+use crate::managers::audio::resolve_vad_model_path;
 use crate::managers::model::{EngineType, ModelManager};
 use crate::settings::{get_settings, AppSettings, ModelUnloadTimeout};
+use crate::speech_engine::{
+    new_engine, EngineCapabilities, SpeechEngine, TranscribeOptions, ENGINE_SAMPLE_RATE,
+};
+use crate::transcript::{Transcript, TranscriptSegment};
 use anyhow::Result;
 use log::{debug, error, info, warn};
 use serde::Serialize;
 use specta::Type;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::{Arc, Condvar, Mutex};
+use std::sync::{mpsc, Arc, Condvar, Mutex};
 use std::thread;
-use std::time::{Duration, SystemTime};
+use std::time::{Duration, Instant, SystemTime};
 use tauri::{AppHandle, Emitter};
-use transcribe_rs::{
-    engines::{
-        moonshine::{ModelVariant, MoonshineEngine, MoonshineModelParams},
-        parakeet::{
-            ParakeetEngine, ParakeetInferenceParams, ParakeetModelParams, TimestampGranularity,
-        },
-        whisper::{WhisperEngine, WhisperInferenceParams},
-    },
-    TranscriptionEngine,
-};
 
 /// Sample rate used for transcription (16kHz is standard for speech)
-const TRANSCRIPTION_SAMPLE_RATE: u32 = 16000;
+const TRANSCRIPTION_SAMPLE_RATE: u32 = ENGINE_SAMPLE_RATE;
+
+/// Long-form chunking: stay under Whisper's 30 s window and cut at pauses of
+/// 300 ms or more; hard cuts repeat 1 s of audio
+const LONG_FORM_CHUNK_PARAMS: ChunkParams = ChunkParams {
+    max_samples: 28 * TRANSCRIPTION_SAMPLE_RATE as usize,
+    min_samples: TRANSCRIPTION_SAMPLE_RATE as usize,
+    overlap_samples: TRANSCRIPTION_SAMPLE_RATE as usize,
+    min_pause_frames: 10,
+};
+
+/// Words compared when removing text repeated across a hard cut
+const MAX_OVERLAP_WORDS: usize = 8;
+
+/// Open streaming chunks shorter than this aren't worth a partial pass
+const MIN_PARTIAL_SAMPLES: usize = TRANSCRIPTION_SAMPLE_RATE as usize / 2;
+
+/// Shortest gap between partial passes over the open streaming chunk
+const MIN_PARTIAL_INTERVAL: Duration = Duration::from_millis(750);
 
 #[derive(Clone, Debug, Serialize)]
 pub struct ModelStateEvent {
@@ -35,10 +57,35 @@ pub struct ModelStateEvent {
     pub error: Option<String>,
 }
 
-enum LoadedEngine {
-    Whisper(WhisperEngine),
-    Parakeet(ParakeetEngine),
-    Moonshine(MoonshineEngine),
+/// Progress of a long-form transcription, emitted after each chunk
+#[derive(Clone, Debug, Serialize)]
+pub struct TranscriptionProgress {
+    /// Source file, or None for a recording
+    pub path: Option<String>,
+    /// Chunks transcribed so far
+    pub chunk: usize,
+    pub total_chunks: usize,
+}
+
+/// What a streaming session already transcribed, continued by `transcribe_continuing`
+#[derive(Clone, Debug, Default)]
+pub struct StreamedPrefix {
+    /// Samples at the start of the recording covered by `text`
+    pub samples: usize,
+    /// Raw engine output of the finalized chunks
+    pub text: String,
+    /// The remaining audio starts inside the last finalized chunk
+    pub overlaps: bool,
+}
+
+/// Transcription running alongside a recording
+struct StreamingSession {
+    audio_tx: mpsc::Sender<Vec<f32>>,
+    /// Cleared when the recording ends, so no further partials are emitted
+    active: Arc<AtomicBool>,
+    /// Set when the session is dropped, so the worker skips any further inference
+    cancelled: Arc<AtomicBool>,
+    worker: thread::JoinHandle<StreamedPrefix>,
 }
 
 /// Detailed transcription result with confidence and fallback information
@@ -56,11 +103,42 @@ pub struct TranscriptionResultWithFallback {
     pub duration_ms: u64,
 }
 
+/// Where the manager reads its settings and sends its events
+trait Host: Send + Sync {
+    fn settings(&self) -> AppSettings;
+    fn emit_value(&self, event: &str, payload: serde_json::Value);
+    fn vad_model_path(&self) -> Result<PathBuf>;
+}
+
+impl Host for AppHandle {
+    fn settings(&self) -> AppSettings {
+        get_settings(self)
+    }
+
+    fn emit_value(&self, event: &str, payload: serde_json::Value) {
+        let _ = self.emit(event, payload);
+    }
+
+    fn vad_model_path(&self) -> Result<PathBuf> {
+        resolve_vad_model_path(self)
+    }
+}
+
+impl dyn Host {
+    fn emit<S: Serialize>(&self, event: &str, payload: S) {
+        match serde_json::to_value(payload) {
+            Ok(payload) => self.emit_value(event, payload),
+            Err(e) => warn!("Failed to serialize {} event: {}", event, e),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct TranscriptionManager {
-    engine: Arc<Mutex<Option<LoadedEngine>>>,
-    model_manager: Arc<ModelManager>,
-    app_handle: AppHandle,
+    engine: Arc<Mutex<Option<Box<dyn SpeechEngine>>>>,
+    /// None only for managers built around a fixed engine
+    model_manager: Option<Arc<ModelManager>>,
+    host: Arc<dyn Host>,
     current_model_id: Arc<Mutex<Option<String>>>,
     last_activity: Arc<AtomicU64>,
     shutdown_signal: Arc<AtomicBool>,
@@ -68,27 +146,13 @@ pub struct TranscriptionManager {
     is_loading: Arc<Mutex<bool>>,
     loading_condvar: Arc<Condvar>,
     cloud_stt_client: Arc<CloudSttClient>,
+    alternate_engine_lock: Arc<Mutex<()>>,
+    streaming: Arc<Mutex<Option<StreamingSession>>>,
 }
 
 impl TranscriptionManager {
     pub fn new(app_handle: &AppHandle, model_manager: Arc<ModelManager>) -> Result<Self> {
-        let manager = Self {
-            engine: Arc::new(Mutex::new(None)),
-            model_manager,
-            app_handle: app_handle.clone(),
-            current_model_id: Arc::new(Mutex::new(None)),
-            last_activity: Arc::new(AtomicU64::new(
-                SystemTime::now()
-                    .duration_since(SystemTime::UNIX_EPOCH)
-                    .unwrap()
-                    .as_millis() as u64,
-            )),
-            shutdown_signal: Arc::new(AtomicBool::new(false)),
-            watcher_handle: Arc::new(Mutex::new(None)),
-            is_loading: Arc::new(Mutex::new(false)),
-            loading_condvar: Arc::new(Condvar::new()),
-            cloud_stt_client: Arc::new(CloudSttClient::new()),
-        };
+        let manager = Self::from_parts(Arc::new(app_handle.clone()), Some(model_manager), None);
 
         // Start the idle watcher
         {
@@ -153,6 +217,38 @@ impl TranscriptionManager {
         Ok(manager)
     }
 
+    fn from_parts(
+        host: Arc<dyn Host>,
+        model_manager: Option<Arc<ModelManager>>,
+        engine: Option<Box<dyn SpeechEngine>>,
+    ) -> Self {
+        Self {
+            engine: Arc::new(Mutex::new(engine)),
+            model_manager,
+            host,
+            current_model_id: Arc::new(Mutex::new(None)),
+            last_activity: Arc::new(AtomicU64::new(
+                SystemTime::now()
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .unwrap()
+                    .as_millis() as u64,
+            )),
+            shutdown_signal: Arc::new(AtomicBool::new(false)),
+            watcher_handle: Arc::new(Mutex::new(None)),
+            is_loading: Arc::new(Mutex::new(false)),
+            loading_condvar: Arc::new(Condvar::new()),
+            cloud_stt_client: Arc::new(CloudSttClient::new()),
+            alternate_engine_lock: Arc::new(Mutex::new(())),
+            streaming: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    fn model_manager(&self) -> Result<&Arc<ModelManager>> {
+        self.model_manager
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("No model manager to load models from"))
+    }
+
     pub fn is_model_loaded(&self) -> bool {
         let engine = self.engine.lock().unwrap();
         engine.is_some()
@@ -164,12 +260,8 @@ impl TranscriptionManager {
 
         {
             let mut engine = self.engine.lock().unwrap();
-            if let Some(ref mut loaded_engine) = *engine {
-                match loaded_engine {
-                    LoadedEngine::Whisper(ref mut e) => e.unload_model(),
-                    LoadedEngine::Parakeet(ref mut e) => e.unload_model(),
-                    LoadedEngine::Moonshine(ref mut e) => e.unload_model(),
-                }
+            if let Some(loaded_engine) = engine.as_mut() {
+                loaded_engine.unload();
             }
             *engine = None; // Drop the engine to free memory
         }
@@ -179,7 +271,7 @@ impl TranscriptionManager {
         }
 
         // Emit unloaded event
-        let _ = self.app_handle.emit(
+        self.host.emit(
             "model-state-changed",
             ModelStateEvent {
                 event_type: "unloaded".to_string(),
@@ -199,7 +291,7 @@ impl TranscriptionManager {
 
     /// Unloads the model immediately if the setting is enabled and the model is loaded
     pub fn maybe_unload_immediately(&self, context: &str) {
-        let settings = get_settings(&self.app_handle);
+        let settings = self.host.settings();
         if settings.model_unload_timeout == ModelUnloadTimeout::Immediately
             && self.is_model_loaded()
         {
@@ -215,7 +307,7 @@ impl TranscriptionManager {
         debug!("Starting to load model: {}", model_id);
 
         // Emit loading started event
-        let _ = self.app_handle.emit(
+        self.host.emit(
             "model-state-changed",
             ModelStateEvent {
                 event_type: "loading_started".to_string(),
@@ -226,13 +318,13 @@ impl TranscriptionManager {
         );
 
         let model_info = self
-            .model_manager
+            .model_manager()?
             .get_model_info(model_id)
             .ok_or_else(|| anyhow::anyhow!("Model not found: {}", model_id))?;
 
         if !model_info.is_downloaded {
             let error_msg = "Model not downloaded";
-            let _ = self.app_handle.emit(
+            self.host.emit(
                 "model-state-changed",
                 ModelStateEvent {
                     event_type: "loading_failed".to_string(),
@@ -244,71 +336,21 @@ impl TranscriptionManager {
             return Err(anyhow::anyhow!(error_msg));
         }
 
-        let model_path = self.model_manager.get_model_path(model_id)?;
+        let model_path = self.model_manager()?.get_model_path(model_id)?;
 
         // Create appropriate engine based on model type
-        let loaded_engine = match model_info.engine_type {
-            EngineType::Whisper => {
-                let mut engine = WhisperEngine::new();
-                engine.load_model(&model_path).map_err(|e| {
-                    let error_msg = format!("Failed to load whisper model {}: {}", model_id, e);
-                    let _ = self.app_handle.emit(
-                        "model-state-changed",
-                        ModelStateEvent {
-                            event_type: "loading_failed".to_string(),
-                            model_id: Some(model_id.to_string()),
-                            model_name: Some(model_info.name.clone()),
-                            error: Some(error_msg.clone()),
-                        },
-                    );
-                    anyhow::anyhow!(error_msg)
-                })?;
-                LoadedEngine::Whisper(engine)
-            }
-            EngineType::Parakeet => {
-                let mut engine = ParakeetEngine::new();
-                engine
-                    .load_model_with_params(&model_path, ParakeetModelParams::int8())
-                    .map_err(|e| {
-                        let error_msg =
-                            format!("Failed to load parakeet model {}: {}", model_id, e);
-                        let _ = self.app_handle.emit(
-                            "model-state-changed",
-                            ModelStateEvent {
-                                event_type: "loading_failed".to_string(),
-                                model_id: Some(model_id.to_string()),
-                                model_name: Some(model_info.name.clone()),
-                                error: Some(error_msg.clone()),
-                            },
-                        );
-                        anyhow::anyhow!(error_msg)
-                    })?;
-                LoadedEngine::Parakeet(engine)
-            }
-            EngineType::Moonshine => {
-                let mut engine = MoonshineEngine::new();
-                engine
-                    .load_model_with_params(
-                        &model_path,
-                        MoonshineModelParams::variant(ModelVariant::Base),
-                    )
-                    .map_err(|e| {
-                        let error_msg =
-                            format!("Failed to load moonshine model {}: {}", model_id, e);
-                        let _ = self.app_handle.emit(
-                            "model-state-changed",
-                            ModelStateEvent {
-                                event_type: "loading_failed".to_string(),
-                                model_id: Some(model_id.to_string()),
-                                model_name: Some(model_info.name.clone()),
-                                error: Some(error_msg.clone()),
-                            },
-                        );
-                        anyhow::anyhow!(error_msg)
-                    })?;
-                LoadedEngine::Moonshine(engine)
-            }
-        };
+        let loaded_engine = Self::create_engine(model_id, &model_info.engine_type, &model_path)
+            .inspect_err(|e| {
+                self.host.emit(
+                    "model-state-changed",
+                    ModelStateEvent {
+                        event_type: "loading_failed".to_string(),
+                        model_id: Some(model_id.to_string()),
+                        model_name: Some(model_info.name.clone()),
+                        error: Some(e.to_string()),
+                    },
+                );
+            })?;
 
         // Update the current engine and model ID
         {
@@ -321,7 +363,7 @@ impl TranscriptionManager {
         }
 
         // Emit loading completed event
-        let _ = self.app_handle.emit(
+        self.host.emit(
             "model-state-changed",
             ModelStateEvent {
                 event_type: "loading_completed".to_string(),
@@ -340,6 +382,28 @@ impl TranscriptionManager {
         Ok(())
     }
 
+    /// Construct and load an engine for a model without touching the live engine
+    fn create_engine(
+        model_id: &str,
+        engine_type: &EngineType,
+        model_path: &Path,
+    ) -> Result<Box<dyn SpeechEngine>> {
+        let mut engine = new_engine(engine_type);
+        engine
+            .load(model_path)
+            .map_err(|e| anyhow::anyhow!("{} ({})", e, model_id))?;
+        Ok(engine)
+    }
+
+    /// Capabilities of the loaded engine, if any
+    pub fn engine_capabilities(&self) -> Option<EngineCapabilities> {
+        self.engine
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|engine| engine.capabilities())
+    }
+
     /// Kicks off the model loading in a background thread if it's not already loaded
     pub fn initiate_model_load(&self) {
         let mut is_loading = self.is_loading.lock().unwrap();
@@ -350,7 +414,7 @@ impl TranscriptionManager {
         *is_loading = true;
         let self_clone = self.clone();
         thread::spawn(move || {
-            let settings = get_settings(&self_clone.app_handle);
+            let settings = self_clone.host.settings();
             if let Err(e) = self_clone.load_model(&settings.selected_model) {
                 error!("Failed to load model: {}", e);
             }
@@ -365,8 +429,8 @@ impl TranscriptionManager {
         current_model.clone()
     }
 
-    pub fn transcribe(&self, audio: Vec<f32>) -> Result<String> {
-        // Update last activity timestamp
+    /// Update the last activity timestamp used by the idle unload watcher
+    fn touch_activity(&self) {
         self.last_activity.store(
             SystemTime::now()
                 .duration_since(SystemTime::UNIX_EPOCH)
@@ -374,6 +438,25 @@ impl TranscriptionManager {
                 .as_millis() as u64,
             Ordering::Relaxed,
         );
+    }
+
+    /// Block until a pending model load finishes; errors if no model is loaded
+    fn wait_for_model(&self) -> Result<()> {
+        // If the model is loading, wait for it to complete.
+        let mut is_loading = self.is_loading.lock().unwrap();
+        while *is_loading {
+            is_loading = self.loading_condvar.wait(is_loading).unwrap();
+        }
+
+        let engine_guard = self.engine.lock().unwrap();
+        if engine_guard.is_none() {
+            return Err(anyhow::anyhow!("Model is not loaded for transcription."));
+        }
+        Ok(())
+    }
+
+    pub fn transcribe(&self, audio: Vec<f32>) -> Result<String> {
+        self.touch_activity();
 
         let st = std::time::Instant::now();
 
@@ -385,22 +468,19 @@ impl TranscriptionManager {
             return Ok(String::new());
         }
 
-        // Check if model is loaded, if not try to load it
-        {
-            // If the model is loading, wait for it to complete.
-            let mut is_loading = self.is_loading.lock().unwrap();
-            while *is_loading {
-                is_loading = self.loading_condvar.wait(is_loading).unwrap();
-            }
-
-            let engine_guard = self.engine.lock().unwrap();
-            if engine_guard.is_none() {
-                return Err(anyhow::anyhow!("Model is not loaded for transcription."));
-            }
+        // Long dictations go through the chunked path instead of one engine call
+        if audio.len() > LONG_FORM_CHUNK_PARAMS.max_samples {
+            let transcript = self.transcribe_chunked(audio, |chunk, total_chunks| {
+                self.emit_progress(None, chunk, total_chunks)
+            })?;
+            return Ok(transcript.text);
         }
 
+        // Check if model is loaded, if not try to load it
+        self.wait_for_model()?;
+
         // Get current settings for configuration
-        let settings = get_settings(&self.app_handle);
+        let settings = self.host.settings();
 
         // Perform transcription with the appropriate engine
         let result = {
@@ -411,64 +491,16 @@ impl TranscriptionManager {
                 )
             })?;
 
-            match engine {
-                LoadedEngine::Whisper(whisper_engine) => {
-                    // Normalize language code for Whisper
-                    // Convert zh-Hans and zh-Hant to zh since Whisper uses ISO 639-1 codes
-                    let whisper_language = if settings.selected_language == "auto" {
-                        None
-                    } else {
-                        let normalized = if settings.selected_language == "zh-Hans"
-                            || settings.selected_language == "zh-Hant"
-                        {
-                            "zh".to_string()
-                        } else {
-                            settings.selected_language.clone()
-                        };
-                        Some(normalized)
-                    };
-
-                    let params = WhisperInferenceParams {
-                        language: whisper_language,
-                        translate: settings.translate_to_english,
-                        ..Default::default()
-                    };
-
-                    whisper_engine
-                        .transcribe_samples(audio, Some(params))
-                        .map_err(|e| anyhow::anyhow!("Whisper transcription failed: {}", e))?
-                }
-                LoadedEngine::Parakeet(parakeet_engine) => {
-                    let params = ParakeetInferenceParams {
-                        timestamp_granularity: TimestampGranularity::Segment,
-                        ..Default::default()
-                    };
-                    parakeet_engine
-                        .transcribe_samples(audio, Some(params))
-                        .map_err(|e| anyhow::anyhow!("Parakeet transcription failed: {}", e))?
-                }
-                LoadedEngine::Moonshine(moonshine_engine) => moonshine_engine
-                    .transcribe_samples(audio, None)
-                    .map_err(|e| anyhow::anyhow!("Moonshine transcription failed: {}", e))?,
-            }
+            Self::run_engine(engine, audio, &settings)?.text
         };
 
-        // Apply word correction if custom words are configured
-        let corrected_result = if !settings.custom_words.is_empty() {
-            apply_custom_words(
-                &result.text,
-                &settings.custom_words,
-                settings.word_correction_threshold,
-            )
-        } else {
-            result.text
-        };
-
-        // Filter out filler words and hallucinations
-        let filtered_result = filter_transcription_output(&corrected_result);
+        let filtered_result = Self::clean_output(result, &settings);
 
         let et = std::time::Instant::now();
-        let translation_note = if settings.translate_to_english {
+        let translates = self
+            .engine_capabilities()
+            .is_some_and(|capabilities| capabilities.translate);
+        let translation_note = if settings.translate_to_english && translates {
             " (translated)"
         } else {
             ""
@@ -493,6 +525,407 @@ impl TranscriptionManager {
         Ok(final_result)
     }
 
+    /// Decode an audio file and transcribe it offline with the loaded model.
+    ///
+    /// Accepts anything `decode_audio_file` can read (WAV, FLAC, MP3, OGG/Vorbis,
+    /// OGG/Opus). Emits `transcription-progress` after every chunk.
+    /// Blocking; call from a blocking-capable thread.
+    pub fn transcribe_file(&self, path: &Path) -> Result<Transcript> {
+        let audio = decode_audio_file(path)?;
+        self.initiate_model_load();
+
+        let path_label = path.to_string_lossy().to_string();
+        self.transcribe_chunked(audio, |chunk, total_chunks| {
+            self.emit_progress(Some(path_label.clone()), chunk, total_chunks)
+        })
+    }
+
+    fn emit_progress(&self, path: Option<String>, chunk: usize, total_chunks: usize) {
+        self.host.emit(
+            "transcription-progress",
+            TranscriptionProgress {
+                path,
+                chunk,
+                total_chunks,
+            },
+        );
+    }
+
+    /// Transcribe long audio chunk by chunk, reporting `(done, total)` after each.
+    ///
+    /// Chunks are cut at pauses found by the Silero VAD (see `plan_chunks`);
+    /// text repeated across a hard cut is removed when stitching. Segment
+    /// timestamps are relative to the start of `audio`. The engine lock is taken
+    /// per chunk, so live dictation isn't stuck behind a long recording.
+    pub fn transcribe_chunked(
+        &self,
+        audio: Vec<f32>,
+        mut on_progress: impl FnMut(usize, usize),
+    ) -> Result<Transcript> {
+        let settings = self.host.settings();
+        let chunks = self.plan_long_form_chunks(&audio, &settings);
+        if chunks.is_empty() {
+            return Ok(Transcript::new(String::new(), Vec::new(), 0.0));
+        }
+        self.wait_for_model()?;
+
+        let st = std::time::Instant::now();
+        let total_chunks = chunks.len();
+        let Transcript { text, segments } = self.stitch_chunks(
+            &audio,
+            chunks,
+            |samples| self.run_loaded_engine(samples, &settings),
+            &mut on_progress,
+        )?;
+        let text = Self::clean_output(text, &settings);
+        let segments = segments
+            .into_iter()
+            .filter_map(|mut segment| {
+                segment.text = Self::clean_output(segment.text, &settings);
+                (!segment.text.is_empty()).then_some(segment)
+            })
+            .collect::<Vec<_>>();
+
+        // REQ-020: Redact transcription content from logs
+        info!(
+            "Long-form transcription completed in {}ms: {} chunks, {} segments, {} chars [REDACTED]",
+            st.elapsed().as_millis(),
+            total_chunks,
+            segments.len(),
+            text.len()
+        );
+
+        self.maybe_unload_immediately("long-form transcription");
+        Ok(Transcript { text, segments })
+    }
+
+    /// Run `chunks` of `audio` through `run_chunk` in order, reporting `(done, total)`
+    /// after each, and stitch the raw engine output. Callers clean the result.
+    fn stitch_chunks(
+        &self,
+        audio: &[f32],
+        chunks: Vec<AudioChunk>,
+        mut run_chunk: impl FnMut(Vec<f32>) -> Result<Transcript>,
+        mut on_progress: impl FnMut(usize, usize),
+    ) -> Result<Transcript> {
+        let total_chunks = chunks.len();
+        let mut stitcher = ChunkStitcher::default();
+
+        for (index, chunk) in chunks.into_iter().enumerate() {
+            let transcript = run_chunk(audio[chunk.range.clone()].to_vec())?;
+            stitcher.add(&chunk, transcript);
+            on_progress(index + 1, total_chunks);
+        }
+
+        Ok(stitcher.finish())
+    }
+
+    /// Run one chunk on the loaded engine, holding the engine lock for that chunk only
+    fn run_loaded_engine(&self, audio: Vec<f32>, settings: &AppSettings) -> Result<Transcript> {
+        self.touch_activity();
+        let mut engine_guard = self.engine.lock().unwrap();
+        let engine = engine_guard
+            .as_mut()
+            .ok_or_else(|| anyhow::anyhow!("Model was unloaded during long-form transcription"))?;
+        Self::run_engine(engine, audio, settings)
+    }
+
+    /// Start transcribing the recording that is about to begin.
+    ///
+    /// Speech arrives through `push_streaming_audio`. A worker thread transcribes
+    /// the chunks that are final and keeps them for `finish_streaming`, and emits
+    /// `partial-transcript` with the text so far, at most every `MIN_PARTIAL_INTERVAL`.
+    /// A cancelled worker still finishing an inference just holds the engine lock
+    /// until it is done, so the new one waits for it there.
+    pub fn start_streaming(&self) {
+        self.cancel_streaming();
+
+        let (audio_tx, audio_rx) = mpsc::channel();
+        let active = Arc::new(AtomicBool::new(true));
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let worker = StreamingWorker {
+            engine: self.engine.clone(),
+            host: self.host.clone(),
+            last_activity: self.last_activity.clone(),
+            active: active.clone(),
+            cancelled: cancelled.clone(),
+            settings: self.host.settings(),
+        };
+        let worker = thread::spawn(move || worker.run(audio_rx));
+
+        *self.streaming.lock().unwrap() = Some(StreamingSession {
+            audio_tx,
+            active,
+            cancelled,
+            worker,
+        });
+        debug!("Streaming transcription started");
+    }
+
+    /// Hand newly recorded speech to the streaming session, if there is one
+    pub fn push_streaming_audio(&self, samples: Vec<f32>) {
+        if let Some(session) = self.streaming.lock().unwrap().as_ref() {
+            let _ = session.audio_tx.send(samples);
+        }
+    }
+
+    /// End the streaming session and return what it finalized (None when not streaming).
+    /// The worker may still be transcribing its last chunk; it is joined on a
+    /// blocking thread so the caller's runtime thread stays free.
+    pub async fn finish_streaming(&self) -> Option<StreamedPrefix> {
+        let session = self.streaming.lock().unwrap().take()?;
+        session.active.store(false, Ordering::Relaxed);
+        drop(session.audio_tx);
+        let worker = session.worker;
+        match tauri::async_runtime::spawn_blocking(move || worker.join()).await {
+            Ok(Ok(prefix)) => Some(prefix),
+            _ => {
+                warn!("Streaming transcription worker panicked");
+                None
+            }
+        }
+    }
+
+    /// Drop the streaming session without waiting for its worker, which stops
+    /// before its next inference
+    pub fn cancel_streaming(&self) {
+        if let Some(session) = self.streaming.lock().unwrap().take() {
+            session.active.store(false, Ordering::Relaxed);
+            session.cancelled.store(true, Ordering::Relaxed);
+            drop(session.audio_tx);
+            debug!("Streaming transcription cancelled");
+        }
+    }
+
+    /// Finish a streamed recording: only the audio after `prefix` goes through the engine
+    pub fn transcribe_continuing(&self, audio: Vec<f32>, prefix: StreamedPrefix) -> Result<String> {
+        if prefix.samples == 0 || prefix.samples > audio.len() {
+            return self.transcribe(audio);
+        }
+        self.touch_activity();
+        let st = std::time::Instant::now();
+        self.wait_for_model()?;
+
+        let settings = self.host.settings();
+        let tail = audio[prefix.samples..].to_vec();
+        let tail_samples = tail.len();
+        // Raw text for the tail too, so the whole result is cleaned exactly once
+        let tail_text = if tail.len() > LONG_FORM_CHUNK_PARAMS.max_samples {
+            let chunks = self.plan_long_form_chunks(&tail, &settings);
+            self.stitch_chunks(
+                &tail,
+                chunks,
+                |samples| self.run_loaded_engine(samples, &settings),
+                |chunk, total_chunks| self.emit_progress(None, chunk, total_chunks),
+            )?
+            .text
+        } else if tail.is_empty() {
+            String::new()
+        } else {
+            let mut engine_guard = self.engine.lock().unwrap();
+            let engine = engine_guard.as_mut().ok_or_else(|| {
+                anyhow::anyhow!("Model was unloaded during streaming transcription")
+            })?;
+            Self::run_engine(engine, tail, &settings)?.text
+        };
+
+        let mut text = prefix.text;
+        append_chunk_text(&mut text, &tail_text, prefix.overlaps);
+        let result = Self::clean_output(text, &settings);
+
+        // REQ-020: Redact transcription content from logs
+        info!(
+            "Streamed transcription completed in {}ms ({:.1}s left after streaming): {} chars [REDACTED]",
+            st.elapsed().as_millis(),
+            tail_samples as f32 / TRANSCRIPTION_SAMPLE_RATE as f32,
+            result.len()
+        );
+
+        self.maybe_unload_immediately("transcription");
+        Ok(result)
+    }
+
+    /// Split audio into chunks at VAD pauses; short audio stays a single chunk
+    fn plan_long_form_chunks(&self, audio: &[f32], settings: &AppSettings) -> Vec<AudioChunk> {
+        let speech = if audio.len() > LONG_FORM_CHUNK_PARAMS.max_samples {
+            self.detect_speech(audio, settings.vad_threshold)
+        } else {
+            vec![true; audio.len().div_ceil(SILERO_FRAME_SAMPLES)]
+        };
+        plan_chunks(
+            &speech,
+            SILERO_FRAME_SAMPLES,
+            audio.len(),
+            &LONG_FORM_CHUNK_PARAMS,
+        )
+    }
+
+    /// One Silero decision per frame. Without a usable VAD every frame counts
+    /// as speech, which leaves only hard cuts with overlap.
+    fn detect_speech(&self, audio: &[f32], threshold: f32) -> Vec<bool> {
+        let frames = audio.len().div_ceil(SILERO_FRAME_SAMPLES);
+        let vad = self
+            .host
+            .vad_model_path()
+            .and_then(|path| SileroVad::new(path, threshold.clamp(0.0, 1.0)));
+        let mut vad = match vad {
+            Ok(vad) => vad,
+            Err(e) => {
+                warn!("VAD unavailable, chunking without pause detection: {}", e);
+                return vec![true; frames];
+            }
+        };
+
+        let mut padded = Vec::with_capacity(SILERO_FRAME_SAMPLES);
+        audio
+            .chunks(SILERO_FRAME_SAMPLES)
+            .map(|frame| {
+                padded.clear();
+                padded.extend_from_slice(frame);
+                padded.resize(SILERO_FRAME_SAMPLES, 0.0);
+                vad.is_voice(&padded).unwrap_or(true)
+            })
+            .collect()
+    }
+
+    /// Run raw inference on a loaded engine using the current settings
+    fn run_engine(
+        engine: &mut Box<dyn SpeechEngine>,
+        audio: Vec<f32>,
+        settings: &AppSettings,
+    ) -> Result<Transcript> {
+        engine.transcribe(audio, &TranscribeOptions::from_settings(settings))
+    }
+
+    /// Apply custom word correction, filter fillers and normalize numbers; local
+    /// and cloud text alike go through here exactly once
+    fn clean_output(text: String, settings: &AppSettings) -> String {
+        // Convert to the requested Chinese script first, so custom words match it
+        let text = convert_chinese_script(&text, &settings.selected_language);
+
+        // Apply word correction if custom words are configured
+        let corrected = if !settings.custom_words.is_empty() {
+            apply_custom_words(
+                &text,
+                &settings.custom_words,
+                settings.word_correction_threshold,
+                &settings.selected_language,
+            )
+        } else {
+            text
+        };
+
+        // Filter out filler words and hallucinations
+        let filtered = filter_transcription_output(
+            &corrected,
+            &settings.output_filters,
+            &settings.selected_language,
+        );
+
+        // Spoken formatting commands ("comma", "new line") to punctuation and layout
+        let formatted = match settings
+            .spoken_commands
+            .vocabulary(&settings.selected_language)
+        {
+            Some(vocabulary) => apply_spoken_commands(&filtered, &vocabulary),
+            None => filtered,
+        };
+
+        // Spoken numbers, dates and units to written form
+        let normalized = inverse_text_normalization(
+            &formatted,
+            &settings.selected_language,
+            &settings.itn_languages,
+        );
+
+        apply_replacements(&normalized, &settings.text_replacements)
+    }
+
+    /// Transcribe audio with a specific local model, without disturbing live dictation.
+    ///
+    /// Long audio is transcribed chunk by chunk like `transcribe_chunked`. If
+    /// `model_id` is the loaded model, each chunk queues behind live dictation on the
+    /// engine lock. Otherwise the model is loaded into a temporary engine that is
+    /// dropped afterwards. Blocking; call from a blocking-capable thread.
+    pub fn transcribe_with_model(&self, model_id: &str, audio: Vec<f32>) -> Result<String> {
+        if audio.is_empty() {
+            return Ok(String::new());
+        }
+
+        let settings = self.host.settings();
+        let chunks = self.plan_long_form_chunks(&audio, &settings);
+        if chunks.is_empty() {
+            return Ok(String::new());
+        }
+
+        if self.get_current_model().as_deref() == Some(model_id) && self.is_model_loaded() {
+            debug!("Re-transcribing with the loaded model {}", model_id);
+            let text = self
+                .stitch_chunks(
+                    &audio,
+                    chunks,
+                    |samples| self.run_loaded_engine(samples, &settings),
+                    |_, _| {},
+                )?
+                .text;
+            return Ok(Self::clean_output(text, &settings));
+        }
+
+        let model_info = self
+            .model_manager()?
+            .get_model_info(model_id)
+            .ok_or_else(|| anyhow::anyhow!("Model not found: {}", model_id))?;
+        if !model_info.is_downloaded {
+            return Err(anyhow::anyhow!("Model not downloaded: {}", model_id));
+        }
+        let model_path = self.model_manager()?.get_model_path(model_id)?;
+
+        // One temporary engine at a time to bound memory use
+        let _alt_guard = self.alternate_engine_lock.lock().unwrap();
+        let load_start = std::time::Instant::now();
+        let mut engine = Self::create_engine(model_id, &model_info.engine_type, &model_path)?;
+        debug!(
+            "Loaded temporary engine for {} in {}ms",
+            model_id,
+            load_start.elapsed().as_millis()
+        );
+
+        let text = self
+            .stitch_chunks(
+                &audio,
+                chunks,
+                |samples| Self::run_engine(&mut engine, samples, &settings),
+                |_, _| {},
+            )?
+            .text;
+        Ok(Self::clean_output(text, &settings))
+    }
+
+    /// Transcribe audio with a specific cloud provider, regardless of the fallback settings
+    pub async fn transcribe_with_cloud_provider(
+        &self,
+        provider: CloudSttProvider,
+        audio: Vec<f32>,
+    ) -> Result<String> {
+        let settings = self.host.settings();
+        let cloud_config = CloudSttConfig {
+            enabled: true,
+            provider,
+            ..Self::get_cloud_stt_config(&settings)
+        };
+
+        if cloud_config.api_key.is_empty() {
+            return Err(anyhow::anyhow!("Cloud STT API key is not configured"));
+        }
+
+        let cloud_result = self
+            .cloud_stt_client
+            .transcribe(&cloud_config, &audio, TRANSCRIPTION_SAMPLE_RATE)
+            .await
+            .map_err(|e| anyhow::anyhow!("Cloud transcription failed: {}", e))?;
+        Ok(Self::clean_output(cloud_result.text, &settings))
+    }
+
     /// Transcribe audio with automatic cloud fallback if confidence is below threshold
     ///
     /// This method:
@@ -503,23 +936,30 @@ impl TranscriptionManager {
     pub async fn transcribe_with_fallback(
         &self,
         audio: Vec<f32>,
+        streamed: Option<StreamedPrefix>,
     ) -> Result<TranscriptionResultWithFallback> {
         let start = std::time::Instant::now();
 
         // Get settings for cloud STT config
-        let settings = get_settings(&self.app_handle);
+        let settings = self.host.settings();
         let cloud_config = Self::get_cloud_stt_config(&settings);
 
         // Calculate audio duration for confidence estimation
         let audio_duration_secs = audio.len() as f32 / TRANSCRIPTION_SAMPLE_RATE as f32;
 
-        // First, try local transcription
-        let local_result = self.transcribe(audio.clone());
+        // First, try local transcription, continuing a streamed one if there is one
+        let local_result = match streamed {
+            Some(prefix) => self.transcribe_continuing(audio.clone(), prefix),
+            None => self.transcribe(audio.clone()),
+        };
 
         match local_result {
             Ok(text) => {
                 // Estimate confidence based on output characteristics
-                let confidence = estimate_confidence(&text, audio_duration_secs);
+                let hallucinations = settings
+                    .output_filters
+                    .hallucinations(&settings.selected_language);
+                let confidence = estimate_confidence(&text, audio_duration_secs, &hallucinations);
 
                 info!(
                     "Local transcription confidence: {:.2} (threshold: {:.2})",
@@ -537,7 +977,7 @@ impl TranscriptionManager {
                     );
 
                     // Emit fallback triggered event
-                    let _ = self.app_handle.emit(
+                    self.host.emit(
                         "fallback-triggered",
                         FallbackTriggeredEvent {
                             provider: cloud_config.provider.display_name().to_string(),
@@ -565,7 +1005,7 @@ impl TranscriptionManager {
                             );
 
                             return Ok(TranscriptionResultWithFallback {
-                                text: cloud_result.text,
+                                text: Self::clean_output(cloud_result.text, &settings),
                                 confidence: 1.0, // Cloud results are considered high confidence
                                 used_fallback: true,
                                 provider: Some(cloud_config.provider.display_name().to_string()),
@@ -598,7 +1038,7 @@ impl TranscriptionManager {
                     );
 
                     // Emit fallback triggered event
-                    let _ = self.app_handle.emit(
+                    self.host.emit(
                         "fallback-triggered",
                         FallbackTriggeredEvent {
                             provider: cloud_config.provider.display_name().to_string(),
@@ -622,7 +1062,7 @@ impl TranscriptionManager {
                             );
 
                             return Ok(TranscriptionResultWithFallback {
-                                text: cloud_result.text,
+                                text: Self::clean_output(cloud_result.text, &settings),
                                 confidence: 1.0,
                                 used_fallback: true,
                                 provider: Some(cloud_config.provider.display_name().to_string()),
@@ -652,7 +1092,7 @@ impl TranscriptionManager {
     /// Transcribe audio using only cloud STT (bypass local)
     pub async fn transcribe_cloud_only(&self, audio: Vec<f32>) -> Result<TranscriptionResultWithFallback> {
         let start = std::time::Instant::now();
-        let settings = get_settings(&self.app_handle);
+        let settings = self.host.settings();
         let cloud_config = Self::get_cloud_stt_config(&settings);
 
         if !cloud_config.enabled {
@@ -672,7 +1112,7 @@ impl TranscriptionManager {
         let duration_ms = start.elapsed().as_millis() as u64;
 
         Ok(TranscriptionResultWithFallback {
-            text: cloud_result.text,
+            text: Self::clean_output(cloud_result.text, &settings),
             confidence: 1.0,
             used_fallback: true,
             provider: Some(cloud_config.provider.display_name().to_string()),
@@ -697,11 +1137,193 @@ impl TranscriptionManager {
 
     /// Check if cloud STT is configured and available
     pub fn is_cloud_stt_available(&self) -> bool {
-        let settings = get_settings(&self.app_handle);
+        let settings = self.host.settings();
         settings.cloud_stt_enabled && !settings.cloud_stt_api_key.is_empty()
     }
 }
 
+/// Background half of a streaming session; owns only what it needs so the
+/// manager itself is never cloned into the thread
+struct StreamingWorker {
+    engine: Arc<Mutex<Option<Box<dyn SpeechEngine>>>>,
+    host: Arc<dyn Host>,
+    last_activity: Arc<AtomicU64>,
+    active: Arc<AtomicBool>,
+    cancelled: Arc<AtomicBool>,
+    settings: AppSettings,
+}
+
+/// Spaces out partial passes so they can't starve the final chunks: the next
+/// pass waits `MIN_PARTIAL_INTERVAL`, or as long as the previous one took
+#[derive(Default)]
+struct PartialThrottle {
+    next: Option<Instant>,
+}
+
+impl PartialThrottle {
+    fn is_ready(&self, now: Instant) -> bool {
+        self.next.is_none_or(|next| now >= next)
+    }
+
+    /// Record a pass that ran from `started` to `finished`
+    fn record(&mut self, started: Instant, finished: Instant) {
+        let took = finished.saturating_duration_since(started);
+        self.next = Some(finished + MIN_PARTIAL_INTERVAL.max(took));
+    }
+}
+
+impl StreamingWorker {
+    /// Consume speech until the session ends. On any engine failure the prefix is
+    /// discarded, so the final pass transcribes the whole recording instead.
+    fn run(self, audio_rx: mpsc::Receiver<Vec<f32>>) -> StreamedPrefix {
+        let mut vad = self
+            .host
+            .vad_model_path()
+            .and_then(|path| SileroVad::new(path, self.settings.vad_threshold.clamp(0.0, 1.0)))
+            .map_err(|e| warn!("VAD unavailable, streaming without pause detection: {}", e))
+            .ok();
+        let mut chunker = StreamingChunker::new(SILERO_FRAME_SAMPLES, LONG_FORM_CHUNK_PARAMS);
+        let mut audio = Vec::new();
+        let mut text = String::new();
+        let mut throttle = PartialThrottle::default();
+
+        while let Ok(samples) = audio_rx.recv() {
+            audio.extend(samples);
+            // Catch up with whatever arrived while the engine was busy
+            audio.extend(audio_rx.try_iter().flatten());
+
+            while chunker.analyzed_samples() + SILERO_FRAME_SAMPLES <= audio.len() {
+                let start = chunker.analyzed_samples();
+                let frame = &audio[start..start + SILERO_FRAME_SAMPLES];
+                chunker.push_frame(
+                    vad.as_mut()
+                        .is_none_or(|vad| vad.is_voice(frame).unwrap_or(true)),
+                );
+            }
+
+            for chunk in chunker.take_final_chunks(audio.len()) {
+                let Some(chunk_text) = self.transcribe_chunk(&audio, &chunk) else {
+                    return StreamedPrefix::default();
+                };
+                append_chunk_text(&mut text, &chunk_text, chunk.overlaps_previous);
+            }
+
+            if !self.active.load(Ordering::Relaxed) || !throttle.is_ready(Instant::now()) {
+                continue;
+            }
+            let started = Instant::now();
+            let open = chunker.open_chunk(audio.len());
+            let mut partial = text.clone();
+            if open.range.len() >= MIN_PARTIAL_SAMPLES {
+                if let Some(open_text) = self.transcribe_chunk(&audio, &open) {
+                    append_chunk_text(&mut partial, &open_text, open.overlaps_previous);
+                }
+            }
+            throttle.record(started, Instant::now());
+            if !self.active.load(Ordering::Relaxed) {
+                continue;
+            }
+            let partial = TranscriptionManager::clean_output(partial, &self.settings);
+            self.host.emit("partial-transcript", partial);
+        }
+
+        let open = chunker.open_chunk(audio.len());
+        StreamedPrefix {
+            samples: open.range.start,
+            text,
+            overlaps: open.overlaps_previous,
+        }
+    }
+
+    /// Raw engine text for one chunk; None if the session was cancelled, no model
+    /// is loaded or inference failed
+    fn transcribe_chunk(&self, audio: &[f32], chunk: &AudioChunk) -> Option<String> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return None;
+        }
+        let mut engine_guard = self.engine.lock().unwrap();
+        let engine = engine_guard.as_mut()?;
+        self.last_activity.store(
+            SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap()
+                .as_millis() as u64,
+            Ordering::Relaxed,
+        );
+        match TranscriptionManager::run_engine(
+            engine,
+            audio[chunk.range.clone()].to_vec(),
+            &self.settings,
+        ) {
+            Ok(transcript) => Some(transcript.text),
+            Err(e) => {
+                warn!("Streaming transcription failed: {}", e);
+                None
+            }
+        }
+    }
+}
+
+/// Joins the transcripts of consecutive chunks into one, dropping what
+/// overlapping chunks transcribed twice
+#[derive(Default)]
+struct ChunkStitcher {
+    text: String,
+    segments: Vec<TranscriptSegment>,
+    /// End of the previous chunk, in seconds
+    covered_until: f32,
+}
+
+impl ChunkStitcher {
+    /// Add the next chunk's transcript (timestamps relative to the chunk)
+    fn add(&mut self, chunk: &AudioChunk, transcript: Transcript) {
+        let offset = chunk.range.start as f32 / TRANSCRIPTION_SAMPLE_RATE as f32;
+        append_chunk_text(&mut self.text, &transcript.text, chunk.overlaps_previous);
+
+        for segment in transcript.segments {
+            let mut segment = segment.offset(offset);
+            // Segments in the repeated audio were already transcribed by the previous chunk
+            if chunk.overlaps_previous && segment.start < self.covered_until {
+                if (segment.start + segment.end) / 2.0 < self.covered_until {
+                    continue;
+                }
+                let previous = self
+                    .segments
+                    .last()
+                    .map(|s| s.text.as_str())
+                    .unwrap_or_default();
+                segment.text = stitch_overlap(previous, &segment.text, MAX_OVERLAP_WORDS);
+            }
+            self.segments.push(segment);
+        }
+
+        self.covered_until = chunk.range.end as f32 / TRANSCRIPTION_SAMPLE_RATE as f32;
+    }
+
+    fn finish(self) -> Transcript {
+        Transcript {
+            text: self.text,
+            segments: self.segments,
+        }
+    }
+}
+
+/// Append a chunk's text, dropping the words it repeats after a hard cut
+fn append_chunk_text(text: &mut String, chunk_text: &str, overlaps_previous: bool) {
+    let chunk_text = if overlaps_previous {
+        stitch_overlap(text, chunk_text, MAX_OVERLAP_WORDS)
+    } else {
+        chunk_text.trim().to_string()
+    };
+    if chunk_text.is_empty() {
+        return;
+    }
+    if !text.is_empty() {
+        text.push(' ');
+    }
+    text.push_str(&chunk_text);
+}
+
 impl Drop for TranscriptionManager {
     fn drop(&mut self) {
         debug!("Shutting down TranscriptionManager");
@@ -719,3 +1341,225 @@ impl Drop for TranscriptionManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio_toolkit::{ReplacementMatch, TextReplacement};
+    use crate::speech_engine::mock::MockEngine;
+
+    const SECOND: usize = TRANSCRIPTION_SAMPLE_RATE as usize;
+
+    fn segment(start: f32, end: f32, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            start,
+            end,
+            text: text.to_string(),
+        }
+    }
+
+    /// Fixed settings and no events, for managers without a running app
+    impl Host for AppSettings {
+        fn settings(&self) -> AppSettings {
+            self.clone()
+        }
+
+        fn emit_value(&self, _event: &str, _payload: serde_json::Value) {}
+
+        fn vad_model_path(&self) -> Result<PathBuf> {
+            Err(anyhow::anyhow!("No VAD model without the app"))
+        }
+    }
+
+    /// Manager around an already loaded engine: no model manager and no idle watcher
+    fn manager_with_settings(engine: MockEngine, settings: AppSettings) -> TranscriptionManager {
+        TranscriptionManager::from_parts(Arc::new(settings), None, Some(Box::new(engine)))
+    }
+
+    /// Manager around `engine` with default settings
+    fn manager_with(engine: MockEngine) -> TranscriptionManager {
+        manager_with_settings(engine, crate::settings::get_default_settings())
+    }
+
+    /// Run `transcribe_chunked` over silence of `secs`, collecting progress reports
+    fn transcribe_secs(
+        manager: &TranscriptionManager,
+        secs: usize,
+    ) -> (Result<Transcript>, Vec<(usize, usize)>) {
+        let mut progress = Vec::new();
+        let result = manager.transcribe_chunked(vec![0.0; secs * SECOND], |chunk, total| {
+            progress.push((chunk, total))
+        });
+        (result, progress)
+    }
+
+    #[test]
+    fn test_short_audio_is_one_chunk() {
+        let mock = MockEngine::with_texts(&[" Hello world. "]);
+        let calls = mock.calls.clone();
+        let manager = manager_with(mock);
+
+        let (transcript, progress) = transcribe_secs(&manager, 20);
+        let transcript = transcript.unwrap();
+        assert_eq!(transcript.text, "Hello world.");
+        assert_eq!(
+            transcript.segments,
+            vec![segment(0.0, 20.0, "Hello world.")]
+        );
+        assert_eq!(*calls.lock().unwrap(), vec![20 * SECOND]);
+        assert_eq!(progress, vec![(1, 1)]);
+    }
+
+    #[test]
+    fn test_chunks_are_joined_in_order() {
+        let mock = MockEngine::with_texts(&["First part.", " Second part. "]);
+        let calls = mock.calls.clone();
+        let manager = manager_with(mock);
+
+        // Without a VAD the 40 s are cut at 28 s; the second chunk repeats 1 s
+        let (transcript, progress) = transcribe_secs(&manager, 40);
+        let transcript = transcript.unwrap();
+        assert_eq!(transcript.text, "First part. Second part.");
+        assert_eq!(*calls.lock().unwrap(), vec![28 * SECOND, 13 * SECOND]);
+        assert_eq!(progress, vec![(1, 2), (2, 2)]);
+        // Segments are shifted to recording time
+        assert_eq!(
+            transcript.segments,
+            vec![
+                segment(0.0, 28.0, "First part."),
+                segment(27.0, 40.0, "Second part.")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_overlapping_chunks_drop_repeated_text_and_segments() {
+        let mut mock = MockEngine::with_texts(&[]);
+        mock.responses = [
+            Transcript {
+                text: "we should ship it on".to_string(),
+                segments: vec![
+                    segment(0.0, 20.0, "we should"),
+                    segment(20.0, 28.0, "ship it on"),
+                ],
+            },
+            // Second chunk starts 1 s before the first one ended
+            Transcript {
+                text: "it on Friday".to_string(),
+                segments: vec![segment(0.0, 0.8, "it on"), segment(0.8, 13.0, "Friday")],
+            },
+        ]
+        .into_iter()
+        .map(Ok)
+        .collect();
+        let manager = manager_with(mock);
+
+        let (transcript, _) = transcribe_secs(&manager, 40);
+        let transcript = transcript.unwrap();
+        assert_eq!(transcript.text, "we should ship it on Friday");
+        assert_eq!(
+            transcript.segments,
+            vec![
+                segment(0.0, 20.0, "we should"),
+                segment(20.0, 28.0, "ship it on"),
+                segment(27.8, 40.0, "Friday")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_audio_skips_the_engine() {
+        let mock = MockEngine::with_texts(&["unused"]);
+        let calls = mock.calls.clone();
+        let manager = manager_with(mock);
+
+        let (transcript, progress) = transcribe_secs(&manager, 0);
+        assert_eq!(transcript.unwrap().text, "");
+        assert!(calls.lock().unwrap().is_empty());
+        assert!(progress.is_empty());
+    }
+
+    #[test]
+    fn test_engine_errors_propagate() {
+        let mut mock = MockEngine::with_texts(&["First part."]);
+        mock.responses.push_back(Err("out of memory".to_string()));
+        let manager = manager_with(mock);
+
+        let (result, progress) = transcribe_secs(&manager, 40);
+        assert!(result.unwrap_err().to_string().contains("out of memory"));
+        assert_eq!(progress, vec![(1, 2)]);
+    }
+
+    #[test]
+    fn test_long_streamed_tail_is_cleaned_once() {
+        let mock = MockEngine::with_texts(&["Second part.", " Third part. "]);
+        let calls = mock.calls.clone();
+        let mut settings = crate::settings::get_default_settings();
+        // Not idempotent, so a second cleanup would show
+        settings.text_replacements = vec![TextReplacement {
+            pattern: "part".to_string(),
+            replacement: "big part".to_string(),
+            match_type: ReplacementMatch::default(),
+            whole_word: true,
+        }];
+        let manager = manager_with_settings(mock, settings);
+
+        let prefix = StreamedPrefix {
+            samples: 10 * SECOND,
+            text: "First part.".to_string(),
+            overlaps: false,
+        };
+        let text = manager
+            .transcribe_continuing(vec![0.0; 50 * SECOND], prefix)
+            .unwrap();
+        assert_eq!(text, "First big part. Second big part. Third big part.");
+        // Only the 40 s after the prefix reach the engine, in two chunks
+        assert_eq!(*calls.lock().unwrap(), vec![28 * SECOND, 13 * SECOND]);
+    }
+
+    #[test]
+    fn test_retranscribing_with_the_loaded_model_is_chunked() {
+        let mock = MockEngine::with_texts(&["First part.", " Second part. "]);
+        let calls = mock.calls.clone();
+        let manager = manager_with(mock);
+        *manager.current_model_id.lock().unwrap() = Some("mock".to_string());
+
+        let text = manager
+            .transcribe_with_model("mock", vec![0.0; 40 * SECOND])
+            .unwrap();
+        assert_eq!(text, "First part. Second part.");
+        assert_eq!(*calls.lock().unwrap(), vec![28 * SECOND, 13 * SECOND]);
+    }
+
+    #[test]
+    fn test_append_chunk_text() {
+        let mut text = String::new();
+        append_chunk_text(&mut text, "  Hello there ", false);
+        append_chunk_text(&mut text, "", false);
+        append_chunk_text(&mut text, "there general", true);
+        assert_eq!(text, "Hello there general");
+    }
+
+    #[test]
+    fn test_partial_throttle_waits_between_passes() {
+        let mut throttle = PartialThrottle::default();
+        let start = Instant::now();
+        assert!(throttle.is_ready(start));
+
+        // A quick pass waits the minimum interval
+        throttle.record(start, start + Duration::from_millis(100));
+        assert!(!throttle.is_ready(start + Duration::from_millis(500)));
+        assert!(throttle.is_ready(start + Duration::from_millis(850)));
+    }
+
+    #[test]
+    fn test_slow_partial_pass_backs_off() {
+        let mut throttle = PartialThrottle::default();
+        let start = Instant::now();
+
+        // A 2 s pass leaves the engine to the final chunks for another 2 s
+        throttle.record(start, start + Duration::from_secs(2));
+        assert!(!throttle.is_ready(start + Duration::from_secs(3)));
+        assert!(throttle.is_ready(start + Duration::from_secs(4)));
+    }
+}
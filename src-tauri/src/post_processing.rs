@@ -0,0 +1,233 @@
+//! LLM post-processing of transcriptions (REQ-011-013)
+//!
+//! After transcription, the text can optionally be sent through a user-defined
+//! prompt template to an OpenAI-compatible provider (see `llm_client`). The
+//! template references the transcript with the `${output}` placeholder.
+//!
+//! Templates come from the prompt library in `AppSettings::post_process_prompts`;
+//! each `ShortcutBinding` picks one (or none) via `post_process_prompt_id`.
+//!
+//! Post-processing is strictly best-effort: when it is disabled, misconfigured,
+//! fails or times out, the raw transcription is used instead.
+
+use crate::llm_client::send_chat_completion;
+use crate::settings::{AppSettings, PostProcessPrompt};
+use log::{debug, info, warn};
+use std::time::Duration;
+
+/// Placeholder in prompt templates that is replaced with the transcription
+pub const OUTPUT_PLACEHOLDER: &str = "${output}";
+
+/// Binding whose prompt applies to triggers without a stored binding
+/// (in-app dictation, tray menu)
+const FALLBACK_BINDING_ID: &str = "transcribe";
+
+/// Fill a prompt template with the transcribed text.
+///
+/// Templates without a `${output}` placeholder get the text appended after a
+/// blank line, so a plain instruction still works as a template.
+pub fn build_prompt(template: &str, text: &str) -> String {
+    if template.contains(OUTPUT_PLACEHOLDER) {
+        template.replace(OUTPUT_PLACEHOLDER, text)
+    } else {
+        format!("{}\n\n{}", template.trim_end(), text)
+    }
+}
+
+/// Find the prompt selected by a binding.
+///
+/// Bindings that are not stored in settings use the selection of the
+/// `transcribe` binding. Returns `None` when no prompt is selected or the
+/// selected prompt no longer exists.
+pub fn resolve_prompt<'a>(
+    settings: &'a AppSettings,
+    binding_id: &str,
+) -> Option<&'a PostProcessPrompt> {
+    let binding = settings
+        .bindings
+        .get(binding_id)
+        .or_else(|| settings.bindings.get(FALLBACK_BINDING_ID))?;
+    let prompt_id = binding.post_process_prompt_id.as_deref()?;
+
+    let prompt = settings
+        .post_process_prompts
+        .iter()
+        .find(|p| p.id == prompt_id);
+    if prompt.is_none() {
+        warn!(
+            "Binding '{}' references unknown post-processing prompt '{}'",
+            binding.id, prompt_id
+        );
+    }
+    prompt
+}
+
+/// Run the transcription through the configured LLM provider, using the
+/// prompt selected by `binding_id`.
+///
+/// Returns `Some(processed_text)` on success, or `None` when post-processing is
+/// disabled or could not produce a result (the caller keeps the raw text).
+pub async fn post_process_transcription(
+    settings: &AppSettings,
+    binding_id: &str,
+    text: &str,
+) -> Option<String> {
+    if !settings.post_process_enabled
+        || settings.post_process_provider == "none"
+        || text.trim().is_empty()
+    {
+        return None;
+    }
+
+    let Some(provider) = settings
+        .post_process_providers
+        .iter()
+        .find(|p| p.id == settings.post_process_provider)
+    else {
+        warn!(
+            "Post-processing provider '{}' not found, using raw transcription",
+            settings.post_process_provider
+        );
+        return None;
+    };
+
+    let Some(prompt) = resolve_prompt(settings, binding_id) else {
+        debug!("No post-processing prompt for binding '{}'", binding_id);
+        return None;
+    };
+
+    let Some(model) = prompt
+        .model
+        .as_deref()
+        .or_else(|| {
+            settings
+                .post_process_models
+                .get(&provider.id)
+                .map(String::as_str)
+        })
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+    else {
+        warn!(
+            "No post-processing model configured for provider '{}', using raw transcription",
+            provider.id
+        );
+        return None;
+    };
+
+    if prompt.template.trim().is_empty() {
+        warn!(
+            "Post-processing prompt '{}' is empty, using raw transcription",
+            prompt.id
+        );
+        return None;
+    }
+
+    let prompt_text = build_prompt(&prompt.template, text);
+    let timeout = Duration::from_secs(settings.post_process_timeout_secs.max(1) as u64);
+
+    debug!(
+        "Post-processing transcription with prompt '{}' via {} ({}), timeout {}s",
+        prompt.id,
+        provider.label,
+        model,
+        timeout.as_secs()
+    );
+
+    let request = send_chat_completion(
+        provider,
+        settings.post_process_api_key.clone(),
+        model,
+        prompt_text,
+    );
+
+    match tokio::time::timeout(timeout, request).await {
+        Ok(Ok(Some(content))) => {
+            let processed = content.trim();
+            if processed.is_empty() {
+                warn!("Post-processing returned empty text, using raw transcription");
+                None
+            } else {
+                // REQ-020: Redact transcription content from logs
+                info!(
+                    "Post-processing complete: {} chars [REDACTED]",
+                    processed.len()
+                );
+                Some(processed.to_string())
+            }
+        }
+        Ok(Ok(None)) => {
+            warn!("Post-processing response had no content, using raw transcription");
+            None
+        }
+        Ok(Err(e)) => {
+            warn!("Post-processing failed: {}. Using raw transcription.", e);
+            None
+        }
+        Err(_) => {
+            warn!(
+                "Post-processing timed out after {}s, using raw transcription",
+                timeout.as_secs()
+            );
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::get_default_settings;
+
+    fn settings_with_prompt(binding_id: &str, prompt_id: Option<&str>) -> AppSettings {
+        let mut settings = get_default_settings();
+        if let Some(binding) = settings.bindings.get_mut(binding_id) {
+            binding.post_process_prompt_id = prompt_id.map(str::to_string);
+        }
+        settings
+    }
+
+    #[test]
+    fn test_resolve_prompt_uses_binding_selection() {
+        let settings = settings_with_prompt("transcribe", Some("bullet_list"));
+        let prompt = resolve_prompt(&settings, "transcribe").unwrap();
+        assert_eq!(prompt.id, "bullet_list");
+    }
+
+    #[test]
+    fn test_resolve_prompt_none_selected() {
+        let settings = settings_with_prompt("transcribe", None);
+        assert!(resolve_prompt(&settings, "transcribe").is_none());
+    }
+
+    #[test]
+    fn test_resolve_prompt_unknown_prompt_id() {
+        let settings = settings_with_prompt("transcribe", Some("deleted"));
+        assert!(resolve_prompt(&settings, "transcribe").is_none());
+    }
+
+    #[test]
+    fn test_resolve_prompt_unstored_binding_falls_back_to_transcribe() {
+        let settings = settings_with_prompt("transcribe", Some("commit_message"));
+        let prompt = resolve_prompt(&settings, "in_app_dictation").unwrap();
+        assert_eq!(prompt.id, "commit_message");
+    }
+
+    #[test]
+    fn test_build_prompt_replaces_placeholder() {
+        let prompt = build_prompt("Fix this: ${output}", "hello world");
+        assert_eq!(prompt, "Fix this: hello world");
+    }
+
+    #[test]
+    fn test_build_prompt_replaces_every_placeholder() {
+        let prompt = build_prompt("${output} / ${output}", "hi");
+        assert_eq!(prompt, "hi / hi");
+    }
+
+    #[test]
+    fn test_build_prompt_without_placeholder_appends_text() {
+        let prompt = build_prompt("Clean up the transcript.\n", "hello world");
+        assert_eq!(prompt, "Clean up the transcript.\n\nhello world");
+    }
+}
@@ -1,3 +1,4 @@
+use crate::audio_toolkit::{OutputFilters, SpokenCommands, TextReplacement};
 use crate::cloud_stt::CloudSttProvider;
 use log::debug;
 use serde::{Deserialize, Serialize};
@@ -58,6 +59,41 @@ impl ModelUnloadTimeout {
     }
 }
 
+/// How long dictation history entries are kept
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum HistoryRetention {
+    Never, // Don't record history
+    Days7,
+    Days30,
+    Days90,
+    Forever,
+}
+
+impl Default for HistoryRetention {
+    fn default() -> Self {
+        HistoryRetention::Days30
+    }
+}
+
+impl HistoryRetention {
+    /// Maximum entry age in days (None = nothing is pruned). `Never` only stops
+    /// new entries from being saved; existing ones go with `clear_history`.
+    pub fn to_days(self) -> Option<u32> {
+        match self {
+            HistoryRetention::Never => None,
+            HistoryRetention::Days7 => Some(7),
+            HistoryRetention::Days30 => Some(30),
+            HistoryRetention::Days90 => Some(90),
+            HistoryRetention::Forever => None,
+        }
+    }
+
+    pub fn records_history(self) -> bool {
+        self != HistoryRetention::Never
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
 pub struct PostProcessProvider {
     pub id: String,
@@ -69,6 +105,18 @@ pub struct PostProcessProvider {
     pub models_endpoint: Option<String>,
 }
 
+/// Named prompt template for LLM post-processing
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct PostProcessPrompt {
+    pub id: String,
+    pub name: String,
+    /// Template text; `${output}` is replaced with the transcription
+    pub template: String,
+    /// Model override; falls back to the provider's selected model when unset
+    #[serde(default)]
+    pub model: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
 #[serde(rename_all = "snake_case")]
 pub enum SoundTheme {
@@ -95,6 +143,62 @@ impl SoundTheme {
     }
 }
 
+/// Whisper decoding options
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+#[serde(default)]
+pub struct WhisperParams {
+    /// Text Whisper continues from; biases spelling and style (empty = none)
+    pub initial_prompt: String,
+    /// Sampling temperature (0.0 = greedy)
+    pub temperature: f32,
+    /// Beams searched per segment (1 = greedy decoding)
+    pub beam_size: u32,
+    /// Candidates sampled when decoding greedily at a temperature above 0
+    pub best_of: u32,
+    /// Segments with a no-speech probability above this are dropped
+    pub no_speech_threshold: f32,
+    /// Decoder threads (0 = let whisper.cpp decide)
+    pub threads: u32,
+    pub suppress_blank: bool,
+}
+
+impl Default for WhisperParams {
+    fn default() -> Self {
+        Self {
+            initial_prompt: String::new(),
+            temperature: 0.0,
+            beam_size: 3,
+            best_of: 1,
+            no_speech_threshold: 0.6,
+            threads: 0,
+            suppress_blank: true,
+        }
+    }
+}
+
+impl WhisperParams {
+    /// Clamp values from the UI into ranges whisper.cpp accepts
+    pub fn clamped(self) -> Self {
+        Self {
+            temperature: self.temperature.clamp(0.0, 1.0),
+            beam_size: self.beam_size.clamp(1, 8),
+            best_of: self.best_of.clamp(1, 8),
+            no_speech_threshold: self.no_speech_threshold.clamp(0.0, 1.0),
+            threads: self.threads.min(64),
+            ..self
+        }
+    }
+}
+
+/// Per-engine tuning; only the block for the loaded engine's type is used.
+/// Load-time options (Moonshine size, Parakeet quantization) come from the
+/// model catalog, since they must match the downloaded files.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type, Default)]
+#[serde(default)]
+pub struct EngineParams {
+    pub whisper: WhisperParams,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
 pub struct ShortcutBinding {
     pub id: String,
@@ -102,6 +206,37 @@ pub struct ShortcutBinding {
     pub description: String,
     pub default_binding: String,
     pub current_binding: String,
+    // Post-processing prompt applied to text dictated with this binding (None = raw text)
+    #[serde(default)]
+    pub post_process_prompt_id: Option<String>,
+}
+
+/// Id prefix of dictation bindings added by the user ("transcribe_2", ...)
+pub const DICTATION_BINDING_PREFIX: &str = "transcribe_";
+
+/// Whether a binding starts dictation: the built-in `transcribe` binding or
+/// one the user added to dictate with a different post-processing prompt
+pub fn is_dictation_binding(id: &str) -> bool {
+    id == "transcribe" || id.starts_with(DICTATION_BINDING_PREFIX)
+}
+
+/// A new, unassigned dictation binding with the first free id
+pub fn new_dictation_binding(
+    bindings: &HashMap<String, ShortcutBinding>,
+    name: &str,
+) -> ShortcutBinding {
+    let id = (2..)
+        .map(|n| format!("{}{}", DICTATION_BINDING_PREFIX, n))
+        .find(|id| !bindings.contains_key(id))
+        .expect("unbounded range");
+    ShortcutBinding {
+        id,
+        name: name.trim().to_string(),
+        description: "Dictates text and applies its own post-processing prompt.".to_string(),
+        default_binding: String::new(),
+        current_binding: String::new(),
+        post_process_prompt_id: None,
+    }
 }
 
 /// Simplified settings for Voyc - Linux-focused voice dictation
@@ -109,6 +244,17 @@ pub struct ShortcutBinding {
 pub struct AppSettings {
     pub bindings: HashMap<String, ShortcutBinding>,
     pub push_to_talk: bool,
+    // Safety cap on a single recording, in seconds (0 = no limit)
+    #[serde(default = "default_max_recording_duration_secs")]
+    pub max_recording_duration_secs: u32,
+    // Hands-free mode: stop dictation after trailing silence
+    #[serde(default)]
+    pub auto_stop_on_silence: bool,
+    #[serde(default = "default_auto_stop_silence_ms")]
+    pub auto_stop_silence_ms: u32,
+    // Transcribe while recording and show partial transcripts in the overlay
+    #[serde(default)]
+    pub streaming_transcription: bool,
     pub audio_feedback: bool,
     #[serde(default = "default_audio_feedback_volume")]
     pub audio_feedback_volume: f32,
@@ -136,6 +282,21 @@ pub struct AppSettings {
     pub custom_words: Vec<String>,
     #[serde(default = "default_word_correction_threshold")]
     pub word_correction_threshold: f64,
+    // Filler, stutter and hallucination filtering, per language
+    #[serde(default)]
+    pub output_filters: OutputFilters,
+    // Spoken formatting commands ("comma", "new line"), per language
+    #[serde(default)]
+    pub spoken_commands: SpokenCommands,
+    // Languages whose spoken numbers, dates and units are rewritten as written text
+    #[serde(default = "default_itn_languages")]
+    pub itn_languages: Vec<String>,
+    // Spoken phrase -> written text rules, applied in order after filtering
+    #[serde(default)]
+    pub text_replacements: Vec<TextReplacement>,
+    // Per-engine inference parameters
+    #[serde(default)]
+    pub engine_params: EngineParams,
     #[serde(default)]
     pub model_unload_timeout: ModelUnloadTimeout,
     #[serde(default)]
@@ -154,6 +315,12 @@ pub struct AppSettings {
     // Voice Activity Detection settings
     #[serde(default = "default_vad_threshold")]
     pub vad_threshold: f32,
+    #[serde(default = "default_vad_prefill_frames")]
+    pub vad_prefill_frames: u32,
+    #[serde(default = "default_vad_hangover_frames")]
+    pub vad_hangover_frames: u32,
+    #[serde(default = "default_vad_onset_frames")]
+    pub vad_onset_frames: u32,
     // Post-processing settings (REQ-011-013)
     #[serde(default)]
     pub post_process_enabled: bool,
@@ -161,6 +328,25 @@ pub struct AppSettings {
     pub post_process_api_key: String,
     #[serde(default = "default_post_process_provider")]
     pub post_process_provider: String,
+    #[serde(default = "default_post_process_providers")]
+    pub post_process_providers: Vec<PostProcessProvider>,
+    // Selected model per provider id
+    #[serde(default = "default_post_process_models")]
+    pub post_process_models: HashMap<String, String>,
+    // Prompt library; bindings select a prompt by id
+    #[serde(default = "default_post_process_prompts")]
+    pub post_process_prompts: Vec<PostProcessPrompt>,
+    #[serde(default = "default_post_process_timeout_secs")]
+    pub post_process_timeout_secs: u32,
+    // Dictation history (SQLite in the app data dir)
+    #[serde(default)]
+    pub history_retention: HistoryRetention,
+    // Keep the audio of each dictation alongside its history entry
+    #[serde(default)]
+    pub save_recordings: bool,
+    // Disk quota for saved recordings in MB (0 = unlimited)
+    #[serde(default = "default_recordings_quota_mb")]
+    pub recordings_quota_mb: u32,
     // Dictation text editor mode: "append" or "replace"
     #[serde(default = "default_dictation_text_mode")]
     pub dictation_text_mode: String,
@@ -170,14 +356,128 @@ pub struct AppSettings {
     // Log level: "error", "warn", "info", "debug", "trace"
     #[serde(default = "default_log_level")]
     pub log_level: String,
+    // Last migration applied to the stored settings (0 = stored before migrations)
+    #[serde(default)]
+    pub settings_version: u32,
 }
 
 fn default_post_process_provider() -> String {
     "baseten".to_string() // Default per REQ-012
 }
 
+fn default_post_process_providers() -> Vec<PostProcessProvider> {
+    // (id, label, base_url, allow_base_url_edit)
+    [
+        (
+            "baseten",
+            "Baseten",
+            "https://inference.baseten.co/v1",
+            true,
+        ),
+        ("openai", "OpenAI", "https://api.openai.com/v1", false),
+        (
+            "openrouter",
+            "OpenRouter",
+            "https://openrouter.ai/api/v1",
+            false,
+        ),
+        (
+            "anthropic",
+            "Anthropic",
+            "https://api.anthropic.com/v1",
+            false,
+        ),
+        ("groq", "Groq", "https://api.groq.com/openai/v1", false),
+        ("custom", "Custom", "http://localhost:11434/v1", true),
+    ]
+    .into_iter()
+    .map(
+        |(id, label, base_url, allow_base_url_edit)| PostProcessProvider {
+            id: id.to_string(),
+            label: label.to_string(),
+            base_url: base_url.to_string(),
+            allow_base_url_edit,
+            models_endpoint: None,
+        },
+    )
+    .collect()
+}
+
+fn default_post_process_models() -> HashMap<String, String> {
+    let mut models = HashMap::new();
+    models.insert("baseten".to_string(), "llama-3.1-8b".to_string());
+    models.insert("openai".to_string(), "gpt-4o-mini".to_string());
+    models
+}
+
+fn default_post_process_prompts() -> Vec<PostProcessPrompt> {
+    let prompt = |id: &str, name: &str, template: &str| PostProcessPrompt {
+        id: id.to_string(),
+        name: name.to_string(),
+        template: template.to_string(),
+        model: None,
+    };
+
+    vec![
+        prompt(
+            "clean_up",
+            "Clean up grammar",
+            "Clean up the following dictated text. Fix punctuation, capitalization and obvious \
+transcription errors without changing its meaning. Reply with the corrected text only.\n\n${output}",
+        ),
+        prompt(
+            "bullet_list",
+            "Format as bullet list",
+            "Rewrite the following dictated text as a concise bullet list using \"- \" markers. \
+Reply with the list only.\n\n${output}",
+        ),
+        prompt(
+            "commit_message",
+            "Write as a commit message",
+            "Turn the following dictated text into a git commit message: an imperative subject line \
+of at most 72 characters, a blank line, then a short body if needed. Reply with the commit \
+message only.\n\n${output}",
+        ),
+        prompt(
+            "translate_german",
+            "Translate to German",
+            "Translate the following dictated text into German. Reply with the translation \
+only.\n\n${output}",
+        ),
+    ]
+}
+
+fn default_recordings_quota_mb() -> u32 {
+    500
+}
+
+fn default_post_process_timeout_secs() -> u32 {
+    10
+}
+
 fn default_vad_threshold() -> f32 {
-    0.5 // Default sensitivity (0.0 = most sensitive, 1.0 = least sensitive)
+    0.3 // Default sensitivity (0.0 = most sensitive, 1.0 = least sensitive)
+}
+
+// VAD smoothing is counted in 30ms frames
+fn default_vad_prefill_frames() -> u32 {
+    15
+}
+
+fn default_vad_hangover_frames() -> u32 {
+    15
+}
+
+fn default_vad_onset_frames() -> u32 {
+    2
+}
+
+fn default_max_recording_duration_secs() -> u32 {
+    600 // Stop a forgotten toggle-to-talk recording after 10 minutes
+}
+
+fn default_auto_stop_silence_ms() -> u32 {
+    1500
 }
 
 fn default_model() -> String {
@@ -212,6 +512,10 @@ fn default_word_correction_threshold() -> f64 {
     0.18
 }
 
+fn default_itn_languages() -> Vec<String> {
+    vec!["en".to_string()]
+}
+
 fn default_audio_feedback_volume() -> f32 {
     1.0
 }
@@ -250,6 +554,7 @@ pub fn get_default_settings() -> AppSettings {
             description: "Converts your speech into text.".to_string(),
             default_binding: default_shortcut.to_string(),
             current_binding: default_shortcut.to_string(),
+            post_process_prompt_id: Some("clean_up".to_string()),
         },
     );
     bindings.insert(
@@ -260,12 +565,17 @@ pub fn get_default_settings() -> AppSettings {
             description: "Cancels the current recording.".to_string(),
             default_binding: "escape".to_string(),
             current_binding: "escape".to_string(),
+            post_process_prompt_id: None,
         },
     );
 
     AppSettings {
         bindings,
         push_to_talk: true,
+        max_recording_duration_secs: default_max_recording_duration_secs(),
+        auto_stop_on_silence: false,
+        auto_stop_silence_ms: default_auto_stop_silence_ms(),
+        streaming_transcription: false,
         audio_feedback: false,
         audio_feedback_volume: default_audio_feedback_volume(),
         sound_theme: default_sound_theme(),
@@ -280,6 +590,11 @@ pub fn get_default_settings() -> AppSettings {
         selected_language: "auto".to_string(),
         custom_words: Vec::new(),
         word_correction_threshold: default_word_correction_threshold(),
+        output_filters: OutputFilters::default(),
+        spoken_commands: SpokenCommands::default(),
+        itn_languages: default_itn_languages(),
+        text_replacements: Vec::new(),
+        engine_params: EngineParams::default(),
         model_unload_timeout: ModelUnloadTimeout::default(),
         mute_while_recording: false,
         overlay_position: OverlayPosition::default(),
@@ -288,15 +603,57 @@ pub fn get_default_settings() -> AppSettings {
         cloud_stt_api_key: String::new(),
         cloud_stt_fallback_threshold: default_cloud_stt_fallback_threshold(),
         vad_threshold: default_vad_threshold(),
+        vad_prefill_frames: default_vad_prefill_frames(),
+        vad_hangover_frames: default_vad_hangover_frames(),
+        vad_onset_frames: default_vad_onset_frames(),
         post_process_enabled: false,
         post_process_api_key: String::new(),
         post_process_provider: default_post_process_provider(),
+        post_process_providers: default_post_process_providers(),
+        post_process_models: default_post_process_models(),
+        post_process_prompts: default_post_process_prompts(),
+        post_process_timeout_secs: default_post_process_timeout_secs(),
+        history_retention: HistoryRetention::default(),
+        save_recordings: false,
+        recordings_quota_mb: default_recordings_quota_mb(),
         dictation_text_mode: default_dictation_text_mode(),
         theme_mode: default_theme_mode(),
         log_level: default_log_level(),
+        settings_version: SETTINGS_VERSION,
     }
 }
 
+/// Version written by this build; see [`migrate_settings`]
+const SETTINGS_VERSION: u32 = 1;
+
+/// Bring settings stored by an older version up to date. Returns whether
+/// anything changed.
+fn migrate_settings(settings: &mut AppSettings) -> bool {
+    if settings.settings_version >= SETTINGS_VERSION {
+        return false;
+    }
+
+    // 1: vad_threshold was stored with a 0.5 default the recorder never read;
+    // it always ran at 0.3, so keep that unless the user picked another value
+    if settings.settings_version < 1 && settings.vad_threshold == 0.5 {
+        debug!("Migrating unused VAD threshold 0.5 to 0.3");
+        settings.vad_threshold = default_vad_threshold();
+    }
+
+    // 1: bindings stored before per-binding prompts get the prompt a fresh
+    // install gives the transcribe binding
+    if settings.settings_version < 1 {
+        if let Some(binding) = settings.bindings.get_mut("transcribe") {
+            if binding.post_process_prompt_id.is_none() {
+                binding.post_process_prompt_id = Some("clean_up".to_string());
+            }
+        }
+    }
+
+    settings.settings_version = SETTINGS_VERSION;
+    true
+}
+
 pub fn get_settings(app: &AppHandle) -> AppSettings {
     let store = app
         .store(SETTINGS_STORE_PATH)
@@ -333,7 +690,7 @@ pub fn load_or_create_app_settings(app: &AppHandle) -> AppSettings {
             Ok(mut settings) => {
                 debug!("Found existing settings: {:?}", settings);
                 let default_settings = get_default_settings();
-                let mut updated = false;
+                let mut updated = migrate_settings(&mut settings);
 
                 // Merge default bindings into existing settings
                 for (key, value) in default_settings.bindings {
@@ -344,8 +701,21 @@ pub fn load_or_create_app_settings(app: &AppHandle) -> AppSettings {
                     }
                 }
 
+                // Merge default post-processing providers into existing settings
+                for provider in default_settings.post_process_providers {
+                    if !settings
+                        .post_process_providers
+                        .iter()
+                        .any(|p| p.id == provider.id)
+                    {
+                        debug!("Adding missing post-process provider: {}", provider.id);
+                        settings.post_process_providers.push(provider);
+                        updated = true;
+                    }
+                }
+
                 if updated {
-                    debug!("Settings updated with new bindings");
+                    debug!("Settings updated with new defaults");
                     store.set("settings", serde_json::to_value(&settings).unwrap());
                 }
 
@@ -374,3 +744,109 @@ pub fn get_stored_binding(app: &AppHandle, id: &str) -> ShortcutBinding {
     let bindings = get_bindings(app);
     bindings.get(id).unwrap().clone()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dictation_bindings() {
+        assert!(is_dictation_binding("transcribe"));
+        assert!(is_dictation_binding("transcribe_2"));
+        assert!(!is_dictation_binding("cancel"));
+        assert!(!is_dictation_binding("transcriber"));
+    }
+
+    #[test]
+    fn test_new_dictation_binding_takes_free_id() {
+        let mut bindings = get_default_settings().bindings;
+        let first = new_dictation_binding(&bindings, " Commit message ");
+        assert_eq!(first.id, "transcribe_2");
+        assert_eq!(first.name, "Commit message");
+        assert!(first.current_binding.is_empty());
+        assert!(first.post_process_prompt_id.is_none());
+
+        bindings.insert(first.id.clone(), first);
+        assert_eq!(
+            new_dictation_binding(&bindings, "German").id,
+            "transcribe_3"
+        );
+    }
+
+    #[test]
+    fn test_default_settings_need_no_migration() {
+        let mut settings = get_default_settings();
+        assert!(!migrate_settings(&mut settings));
+        assert_eq!(settings.vad_threshold, 0.3);
+    }
+
+    #[test]
+    fn test_migrates_unused_vad_threshold() {
+        let mut settings = AppSettings {
+            vad_threshold: 0.5,
+            settings_version: 0,
+            ..get_default_settings()
+        };
+        assert!(migrate_settings(&mut settings));
+        assert_eq!(settings.vad_threshold, 0.3);
+        assert_eq!(settings.settings_version, SETTINGS_VERSION);
+
+        // A threshold chosen after the migration is left alone
+        settings.vad_threshold = 0.5;
+        assert!(!migrate_settings(&mut settings));
+        assert_eq!(settings.vad_threshold, 0.5);
+    }
+
+    #[test]
+    fn test_keeps_custom_vad_threshold() {
+        let mut settings = AppSettings {
+            vad_threshold: 0.7,
+            settings_version: 0,
+            ..get_default_settings()
+        };
+        migrate_settings(&mut settings);
+        assert_eq!(settings.vad_threshold, 0.7);
+    }
+
+    /// Settings as stored before the prompt library: no prompt per binding
+    fn pre_library_settings() -> AppSettings {
+        let mut settings = AppSettings {
+            settings_version: 0,
+            ..get_default_settings()
+        };
+        for binding in settings.bindings.values_mut() {
+            binding.post_process_prompt_id = None;
+        }
+        settings
+    }
+
+    fn transcribe_prompt(settings: &AppSettings) -> Option<&str> {
+        settings.bindings["transcribe"]
+            .post_process_prompt_id
+            .as_deref()
+    }
+
+    #[test]
+    fn test_existing_bindings_get_default_prompt() {
+        let mut settings = pre_library_settings();
+        assert!(migrate_settings(&mut settings));
+        assert_eq!(transcribe_prompt(&settings), Some("clean_up"));
+        assert!(settings.bindings["cancel"].post_process_prompt_id.is_none());
+        assert_eq!(
+            settings.post_process_prompts.len(),
+            default_post_process_prompts().len()
+        );
+    }
+
+    #[test]
+    fn test_chosen_prompt_is_kept() {
+        let mut settings = pre_library_settings();
+        settings
+            .bindings
+            .get_mut("transcribe")
+            .unwrap()
+            .post_process_prompt_id = Some("bullet_list".to_string());
+        migrate_settings(&mut settings);
+        assert_eq!(transcribe_prompt(&settings), Some("bullet_list"));
+    }
+}
@@ -0,0 +1,559 @@
+//! Local speech-to-text engines
+//!
+//! Every engine the `TranscriptionManager` can run sits behind the
+//! `SpeechEngine` trait, so adding an engine (or a test double) only touches
+//! this module: implement the trait and map its `EngineType` in `new_engine`.
+
+use crate::audio_toolkit::engine_language;
+use crate::managers::model::{EngineType, MoonshineVariant, ParakeetQuantization};
+use crate::settings::{AppSettings, WhisperParams};
+use crate::transcript::{Transcript, TranscriptSegment};
+use anyhow::{anyhow, Result};
+use std::path::Path;
+use transcribe_rs::{
+    engines::{
+        moonshine::{ModelVariant, MoonshineEngine, MoonshineModelParams},
+        parakeet::{
+            ParakeetEngine, ParakeetInferenceParams, ParakeetModelParams, TimestampGranularity,
+        },
+    },
+    TranscriptionEngine, TranscriptionResult,
+};
+use whisper_rs::{
+    FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters, WhisperState,
+};
+
+/// Sample rate every engine expects (16 kHz mono)
+pub const ENGINE_SAMPLE_RATE: u32 = 16000;
+
+/// Prompt tokens whisper.cpp keeps (half the text context); older ones are dropped
+const WHISPER_PROMPT_TOKENS: usize = 224;
+
+/// What an engine supports, so callers can tell which options it will honour
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EngineCapabilities {
+    /// Languages it can transcribe (ISO 639-1); None means multilingual
+    pub languages: Option<&'static [&'static str]>,
+    /// Accepts a language hint instead of detecting the language itself
+    pub language_hint: bool,
+    /// Can translate into English
+    pub translate: bool,
+    /// Reports segment timestamps
+    pub timestamps: bool,
+}
+
+impl EngineCapabilities {
+    pub fn supports_language(&self, language: &str) -> bool {
+        let base = language.split('-').next().unwrap_or(language);
+        self.languages
+            .is_none_or(|languages| language == "auto" || languages.contains(&base))
+    }
+}
+
+/// Per-call options, derived from the user's settings
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TranscribeOptions {
+    /// ISO 639-1 language code; None to auto-detect
+    pub language: Option<String>,
+    pub translate: bool,
+    /// Decoding options for Whisper; other engines ignore them
+    pub whisper: WhisperParams,
+}
+
+impl TranscribeOptions {
+    pub fn from_settings(settings: &AppSettings) -> Self {
+        let language = match settings.selected_language.as_str() {
+            "auto" => None,
+            // Script variants are a post-processing concern; engines use ISO 639-1
+            other => Some(engine_language(other).to_string()),
+        };
+        Self {
+            language,
+            translate: settings.translate_to_english,
+            whisper: WhisperParams {
+                initial_prompt: whisper_prompt(
+                    &settings.engine_params.whisper.initial_prompt,
+                    &settings.custom_words,
+                ),
+                ..settings.engine_params.whisper.clone()
+            },
+        }
+    }
+}
+
+/// Initial prompt listing the custom vocabulary, so Whisper spells names and
+/// jargon right at decode time (`apply_custom_words` still runs afterwards).
+///
+/// The user's own prompt is kept whole; words are added in order until the
+/// token budget is spent.
+fn whisper_prompt(user_prompt: &str, custom_words: &[String]) -> String {
+    let user_prompt = user_prompt.trim();
+    let mut budget = WHISPER_PROMPT_TOKENS.saturating_sub(estimate_tokens(user_prompt));
+
+    let mut words: Vec<&str> = Vec::new();
+    for word in custom_words.iter().map(|w| w.trim()) {
+        if word.is_empty() || words.iter().any(|w| w.eq_ignore_ascii_case(word)) {
+            continue;
+        }
+        // Separator included
+        let cost = estimate_tokens(word) + 1;
+        if cost > budget {
+            break;
+        }
+        budget -= cost;
+        words.push(word);
+    }
+
+    // Whisper weighs the end of the prompt most, so the user's prompt goes last
+    let vocabulary = if words.is_empty() {
+        String::new()
+    } else {
+        format!("{}.", words.join(", "))
+    };
+    [vocabulary.as_str(), user_prompt]
+        .iter()
+        .filter(|part| !part.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Upper-bound guess at Whisper's BPE token count without loading the
+/// tokenizer: about 3 ASCII characters per token, one token per other character
+fn estimate_tokens(text: &str) -> usize {
+    text.split_whitespace()
+        .map(|word| {
+            let ascii = word.chars().filter(char::is_ascii).count();
+            let other = word.chars().count() - ascii;
+            ascii.div_ceil(3) + other
+        })
+        .sum()
+}
+
+/// A local speech-to-text engine
+pub trait SpeechEngine: Send {
+    /// Load model files (a file or directory, depending on the engine)
+    fn load(&mut self, model_path: &Path) -> Result<()>;
+
+    /// Free the model; the engine must be loaded again before use
+    fn unload(&mut self);
+
+    fn capabilities(&self) -> EngineCapabilities;
+
+    /// Transcribe 16 kHz mono samples; options the engine doesn't support are ignored
+    fn transcribe(&mut self, audio: Vec<f32>, options: &TranscribeOptions) -> Result<Transcript>;
+}
+
+/// Create an unloaded engine for a catalog engine type
+pub fn new_engine(engine_type: &EngineType) -> Box<dyn SpeechEngine> {
+    match *engine_type {
+        EngineType::Whisper => Box::new(Whisper { state: None }),
+        EngineType::Parakeet(quantization) => Box::new(Parakeet {
+            engine: ParakeetEngine::new(),
+            quantization,
+        }),
+        EngineType::Moonshine(variant) => Box::new(Moonshine {
+            engine: MoonshineEngine::new(),
+            variant,
+        }),
+    }
+}
+
+/// whisper.cpp through whisper-rs directly: transcribe-rs doesn't expose the
+/// sampling strategy or thread count
+struct Whisper {
+    /// Decoding state of the loaded model; keeps the model alive
+    state: Option<WhisperState>,
+}
+
+/// Parakeet with the precision of its model files
+struct Parakeet {
+    engine: ParakeetEngine,
+    quantization: ParakeetQuantization,
+}
+
+/// Moonshine with the size of its model files
+struct Moonshine {
+    engine: MoonshineEngine,
+    variant: MoonshineVariant,
+}
+
+impl SpeechEngine for Whisper {
+    fn load(&mut self, model_path: &Path) -> Result<()> {
+        let path = model_path
+            .to_str()
+            .ok_or_else(|| anyhow!("Whisper model path is not valid UTF-8: {:?}", model_path))?;
+        let context = WhisperContext::new_with_params(path, WhisperContextParameters::default())
+            .map_err(|e| anyhow!("Failed to load Whisper model: {}", e))?;
+        let state = context
+            .create_state()
+            .map_err(|e| anyhow!("Failed to load Whisper model: {}", e))?;
+        self.state = Some(state);
+        Ok(())
+    }
+
+    fn unload(&mut self) {
+        self.state = None;
+    }
+
+    fn capabilities(&self) -> EngineCapabilities {
+        EngineCapabilities {
+            languages: None,
+            language_hint: true,
+            translate: true,
+            timestamps: true,
+        }
+    }
+
+    fn transcribe(&mut self, audio: Vec<f32>, options: &TranscribeOptions) -> Result<Transcript> {
+        let state = self
+            .state
+            .as_mut()
+            .ok_or_else(|| anyhow!("Whisper model is not loaded"))?;
+        let duration = audio_duration(&audio);
+        let whisper = &options.whisper;
+
+        let mut params = FullParams::new(whisper_strategy(whisper));
+        params.set_language(Some(options.language.as_deref().unwrap_or("auto")));
+        params.set_translate(options.translate);
+        let prompt = whisper.initial_prompt.trim();
+        if !prompt.is_empty() {
+            params.set_initial_prompt(prompt);
+        }
+        params.set_temperature(whisper.temperature);
+        params.set_no_speech_thold(whisper.no_speech_threshold);
+        params.set_suppress_blank(whisper.suppress_blank);
+        if whisper.threads > 0 {
+            params.set_n_threads(whisper.threads as i32);
+        }
+        params.set_print_special(false);
+        params.set_print_progress(false);
+        params.set_print_realtime(false);
+        params.set_print_timestamps(false);
+
+        state
+            .full(params, &audio)
+            .map_err(|e| anyhow!("Whisper transcription failed: {}", e))?;
+
+        let count = state
+            .full_n_segments()
+            .map_err(|e| anyhow!("Whisper transcription failed: {}", e))?;
+        let mut text = String::new();
+        let mut segments = Vec::new();
+        for i in 0..count {
+            let segment_text = state
+                .full_get_segment_text(i)
+                .map_err(|e| anyhow!("Whisper transcription failed: {}", e))?;
+            // Timestamps are in 10 ms steps
+            let start = state.full_get_segment_t0(i).unwrap_or(0) as f32 / 100.0;
+            let end = state.full_get_segment_t1(i).unwrap_or(0) as f32 / 100.0;
+            text.push_str(&segment_text);
+            segments.push(TranscriptSegment {
+                start,
+                end,
+                text: segment_text.trim().to_string(),
+            });
+        }
+        Ok(Transcript::new(text.trim().to_string(), segments, duration))
+    }
+}
+
+/// Beam search when more than one beam is asked for, otherwise greedy decoding
+fn whisper_strategy(params: &WhisperParams) -> SamplingStrategy {
+    if params.beam_size > 1 {
+        SamplingStrategy::BeamSearch {
+            beam_size: params.beam_size as i32,
+            // whisper.cpp's default: no patience-based early stop
+            patience: -1.0,
+        }
+    } else {
+        SamplingStrategy::Greedy {
+            best_of: params.best_of as i32,
+        }
+    }
+}
+
+impl SpeechEngine for Parakeet {
+    fn load(&mut self, model_path: &Path) -> Result<()> {
+        let model_params = match self.quantization {
+            ParakeetQuantization::Int8 => ParakeetModelParams::int8(),
+            ParakeetQuantization::Fp32 => ParakeetModelParams::fp32(),
+        };
+        self.engine
+            .load_model_with_params(model_path, model_params)
+            .map_err(|e| anyhow!("Failed to load Parakeet model: {}", e))
+    }
+
+    fn unload(&mut self) {
+        self.engine.unload_model();
+    }
+
+    fn capabilities(&self) -> EngineCapabilities {
+        EngineCapabilities {
+            languages: None,
+            language_hint: false,
+            translate: false,
+            timestamps: true,
+        }
+    }
+
+    fn transcribe(&mut self, audio: Vec<f32>, _options: &TranscribeOptions) -> Result<Transcript> {
+        let duration = audio_duration(&audio);
+        let params = ParakeetInferenceParams {
+            timestamp_granularity: TimestampGranularity::Segment,
+            ..Default::default()
+        };
+        let result = self
+            .engine
+            .transcribe_samples(audio, Some(params))
+            .map_err(|e| anyhow!("Parakeet transcription failed: {}", e))?;
+        Ok(to_transcript(result, duration))
+    }
+}
+
+impl SpeechEngine for Moonshine {
+    fn load(&mut self, model_path: &Path) -> Result<()> {
+        let variant = match self.variant {
+            MoonshineVariant::Tiny => ModelVariant::Tiny,
+            MoonshineVariant::Base => ModelVariant::Base,
+        };
+        self.engine
+            .load_model_with_params(model_path, MoonshineModelParams::variant(variant))
+            .map_err(|e| anyhow!("Failed to load Moonshine model: {}", e))
+    }
+
+    fn unload(&mut self) {
+        self.engine.unload_model();
+    }
+
+    fn capabilities(&self) -> EngineCapabilities {
+        EngineCapabilities {
+            languages: Some(&["en"]),
+            language_hint: false,
+            translate: false,
+            timestamps: false,
+        }
+    }
+
+    fn transcribe(&mut self, audio: Vec<f32>, _options: &TranscribeOptions) -> Result<Transcript> {
+        let duration = audio_duration(&audio);
+        let result = self
+            .engine
+            .transcribe_samples(audio, None)
+            .map_err(|e| anyhow!("Moonshine transcription failed: {}", e))?;
+        Ok(to_transcript(result, duration))
+    }
+}
+
+fn audio_duration(audio: &[f32]) -> f32 {
+    audio.len() as f32 / ENGINE_SAMPLE_RATE as f32
+}
+
+fn to_transcript(result: TranscriptionResult, duration: f32) -> Transcript {
+    let segments = result
+        .segments
+        .unwrap_or_default()
+        .into_iter()
+        .map(|segment| TranscriptSegment {
+            start: segment.start,
+            end: segment.end,
+            text: segment.text.trim().to_string(),
+        })
+        .collect();
+    Transcript::new(result.text, segments, duration)
+}
+
+/// Scripted engine for unit tests, no model files needed
+#[cfg(test)]
+pub mod mock {
+    use super::*;
+    use std::collections::VecDeque;
+    use std::sync::{Arc, Mutex};
+
+    /// Returns queued transcripts in order (empty ones once the queue runs out)
+    /// and records the length of every audio buffer it was given.
+    #[derive(Default)]
+    pub struct MockEngine {
+        pub loaded: bool,
+        pub responses: VecDeque<Result<Transcript, String>>,
+        /// Sample counts of each `transcribe` call, shared so tests can inspect
+        /// them after the engine was boxed
+        pub calls: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl MockEngine {
+        /// Engine answering each call with the next text; segments span the whole call
+        pub fn with_texts(texts: &[&str]) -> Self {
+            Self {
+                loaded: true,
+                responses: texts.iter().map(|text| Ok(text_only(text))).collect(),
+                ..Default::default()
+            }
+        }
+    }
+
+    fn text_only(text: &str) -> Transcript {
+        Transcript {
+            text: text.to_string(),
+            segments: Vec::new(),
+        }
+    }
+
+    impl SpeechEngine for MockEngine {
+        fn load(&mut self, _model_path: &Path) -> Result<()> {
+            self.loaded = true;
+            Ok(())
+        }
+
+        fn unload(&mut self) {
+            self.loaded = false;
+        }
+
+        fn capabilities(&self) -> EngineCapabilities {
+            EngineCapabilities {
+                languages: Some(&["en"]),
+                language_hint: false,
+                translate: false,
+                timestamps: true,
+            }
+        }
+
+        fn transcribe(
+            &mut self,
+            audio: Vec<f32>,
+            _options: &TranscribeOptions,
+        ) -> Result<Transcript> {
+            if !self.loaded {
+                return Err(anyhow!("Mock model is not loaded"));
+            }
+            self.calls.lock().unwrap().push(audio.len());
+            let duration = audio_duration(&audio);
+            match self.responses.pop_front() {
+                Some(Ok(transcript)) => Ok(Transcript::new(
+                    transcript.text,
+                    transcript.segments,
+                    duration,
+                )),
+                Some(Err(e)) => Err(anyhow!(e)),
+                None => Ok(Transcript::new(String::new(), Vec::new(), duration)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::MockEngine;
+    use super::*;
+    use crate::settings::get_default_settings;
+
+    #[test]
+    fn test_options_from_settings() {
+        let mut settings = get_default_settings();
+        settings.selected_language = "auto".to_string();
+        assert_eq!(TranscribeOptions::from_settings(&settings).language, None);
+
+        settings.selected_language = "zh-Hant".to_string();
+        settings.translate_to_english = true;
+        assert_eq!(
+            TranscribeOptions::from_settings(&settings),
+            TranscribeOptions {
+                language: Some("zh".to_string()),
+                translate: true,
+                whisper: WhisperParams::default(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_options_carry_vocabulary_prompt() {
+        let mut settings = get_default_settings();
+        settings.custom_words = vec!["Voyc".to_string(), "Tauri Specta".to_string()];
+        settings.engine_params.whisper.initial_prompt = " Meeting notes. ".to_string();
+        assert_eq!(
+            TranscribeOptions::from_settings(&settings)
+                .whisper
+                .initial_prompt,
+            "Voyc, Tauri Specta. Meeting notes."
+        );
+    }
+
+    #[test]
+    fn test_whisper_strategy() {
+        let params = WhisperParams::default();
+        assert!(matches!(
+            whisper_strategy(&params),
+            SamplingStrategy::BeamSearch { beam_size: 3, .. }
+        ));
+        let greedy = WhisperParams {
+            beam_size: 1,
+            best_of: 4,
+            ..params
+        };
+        assert!(matches!(
+            whisper_strategy(&greedy),
+            SamplingStrategy::Greedy { best_of: 4 }
+        ));
+    }
+
+    #[test]
+    fn test_whisper_prompt() {
+        let words = |list: &[&str]| list.iter().map(|w| w.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(whisper_prompt("", &[]), "");
+        assert_eq!(whisper_prompt("Hello.", &[]), "Hello.");
+        assert_eq!(
+            whisper_prompt("", &words(&["Kubernetes", " ", "kubernetes", "Zürich"])),
+            "Kubernetes, Zürich."
+        );
+    }
+
+    #[test]
+    fn test_whisper_prompt_respects_token_budget() {
+        let many: Vec<String> = (0..200).map(|i| format!("word{}", i)).collect();
+        let prompt = whisper_prompt("", &many);
+        assert!(estimate_tokens(&prompt) <= WHISPER_PROMPT_TOKENS);
+        // Words are kept in order; the overflow is dropped from the end
+        assert!(prompt.starts_with("word0, word1, "));
+        assert!(!prompt.contains("word199"));
+
+        // A user prompt that fills the budget leaves no room for words
+        let long_prompt = "abc ".repeat(WHISPER_PROMPT_TOKENS);
+        assert_eq!(whisper_prompt(&long_prompt, &many), long_prompt.trim());
+    }
+
+    #[test]
+    fn test_estimate_tokens() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("hello world"), 4);
+        assert_eq!(estimate_tokens("東京"), 2);
+    }
+
+    #[test]
+    fn test_supports_language() {
+        let english_only = MockEngine::default().capabilities();
+        assert!(english_only.supports_language("en"));
+        assert!(english_only.supports_language("auto"));
+        assert!(!english_only.supports_language("de"));
+
+        let multilingual = EngineCapabilities {
+            languages: None,
+            ..english_only
+        };
+        assert!(multilingual.supports_language("zh-Hans"));
+    }
+
+    #[test]
+    fn test_mock_engine_through_trait_object() {
+        let mut engine: Box<dyn SpeechEngine> = Box::new(MockEngine::with_texts(&["hello"]));
+        let options = TranscribeOptions::default();
+
+        let transcript = engine.transcribe(vec![0.0; 8000], &options).unwrap();
+        assert_eq!(transcript.text, "hello");
+        // Engines without segments get one spanning the audio
+        assert_eq!(transcript.segments[0].end, 0.5);
+
+        engine.unload();
+        assert!(engine.transcribe(vec![0.0; 100], &options).is_err());
+    }
+}
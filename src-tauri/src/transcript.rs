@@ -0,0 +1,249 @@
+//! Timestamped transcripts and subtitle export
+//!
+//! Engines report segments (start, end, text) alongside the plain text. File
+//! transcriptions keep them in a `Transcript`, which can be exported as SRT,
+//! WebVTT or timestamped JSON for video editors.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::fmt::Write;
+
+/// A span of speech, with times in seconds from the start of the audio
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Type)]
+pub struct TranscriptSegment {
+    pub start: f32,
+    pub end: f32,
+    pub text: String,
+}
+
+impl TranscriptSegment {
+    /// Shift the segment later by `seconds` (used when stitching chunks)
+    pub fn offset(self, seconds: f32) -> Self {
+        Self {
+            start: self.start + seconds,
+            end: self.end + seconds,
+            ..self
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Type)]
+pub struct Transcript {
+    pub text: String,
+    pub segments: Vec<TranscriptSegment>,
+}
+
+impl Transcript {
+    /// Build a transcript from engine output.
+    ///
+    /// Engines without segment timestamps (Moonshine) get a single segment
+    /// spanning the whole audio, so every transcript can be exported.
+    pub fn new(text: String, segments: Vec<TranscriptSegment>, duration: f32) -> Self {
+        let segments = if segments.is_empty() && !text.trim().is_empty() {
+            vec![TranscriptSegment {
+                start: 0.0,
+                end: duration,
+                text: text.trim().to_string(),
+            }]
+        } else {
+            segments
+        };
+        Self { text, segments }
+    }
+
+    /// Render the transcript in an export format
+    pub fn export(&self, format: TranscriptFormat) -> String {
+        match format {
+            TranscriptFormat::Text => self.text.clone(),
+            TranscriptFormat::Srt => self.to_srt(),
+            TranscriptFormat::Vtt => self.to_vtt(),
+            TranscriptFormat::Json => {
+                serde_json::to_string_pretty(self).unwrap_or_else(|_| "{}".to_string())
+            }
+        }
+    }
+
+    fn to_srt(&self) -> String {
+        let mut out = String::new();
+        for (index, segment) in self.cues().enumerate() {
+            let _ = writeln!(
+                out,
+                "{}\n{} --> {}\n{}\n",
+                index + 1,
+                format_timestamp(segment.start, ','),
+                format_timestamp(segment.end, ','),
+                cue_text(&segment.text)
+            );
+        }
+        out
+    }
+
+    fn to_vtt(&self) -> String {
+        let mut out = String::from("WEBVTT\n\n");
+        for segment in self.cues() {
+            let _ = writeln!(
+                out,
+                "{} --> {}\n{}\n",
+                format_timestamp(segment.start, '.'),
+                format_timestamp(segment.end, '.'),
+                cue_text(&segment.text)
+            );
+        }
+        out
+    }
+
+    /// Segments that make a visible cue
+    fn cues(&self) -> impl Iterator<Item = &TranscriptSegment> {
+        self.segments.iter().filter(|s| !s.text.trim().is_empty())
+    }
+}
+
+/// Export formats for `Transcript::export`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum TranscriptFormat {
+    Text,
+    Srt,
+    Vtt,
+    Json,
+}
+
+impl TranscriptFormat {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "text" | "txt" => Some(Self::Text),
+            "srt" => Some(Self::Srt),
+            "vtt" | "webvtt" => Some(Self::Vtt),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Text => "txt",
+            Self::Srt => "srt",
+            Self::Vtt => "vtt",
+            Self::Json => "json",
+        }
+    }
+}
+
+/// `HH:MM:SS,mmm` (SRT) or `HH:MM:SS.mmm` (WebVTT)
+fn format_timestamp(seconds: f32, millis_separator: char) -> String {
+    let total_ms = (seconds.max(0.0) as f64 * 1000.0).round() as u64;
+    let hours = total_ms / 3_600_000;
+    let minutes = total_ms / 60_000 % 60;
+    let secs = total_ms / 1000 % 60;
+    let millis = total_ms % 1000;
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        hours, minutes, secs, millis_separator, millis
+    )
+}
+
+/// Cue payload without blank lines, which would end the cue early
+fn cue_text(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(start: f32, end: f32, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            start,
+            end,
+            text: text.to_string(),
+        }
+    }
+
+    fn sample() -> Transcript {
+        Transcript {
+            text: "Hello there. General Kenobi.".to_string(),
+            segments: vec![
+                segment(0.0, 1.5, " Hello there."),
+                segment(1.5, 3661.25, "General Kenobi."),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0.0, ','), "00:00:00,000");
+        assert_eq!(format_timestamp(1.5, ','), "00:00:01,500");
+        assert_eq!(format_timestamp(3661.25, '.'), "01:01:01.250");
+        assert_eq!(format_timestamp(-2.0, ','), "00:00:00,000");
+    }
+
+    #[test]
+    fn test_export_srt() {
+        assert_eq!(
+            sample().export(TranscriptFormat::Srt),
+            "1\n00:00:00,000 --> 00:00:01,500\nHello there.\n\n\
+             2\n00:00:01,500 --> 01:01:01,250\nGeneral Kenobi.\n\n"
+        );
+    }
+
+    #[test]
+    fn test_export_vtt() {
+        assert_eq!(
+            sample().export(TranscriptFormat::Vtt),
+            "WEBVTT\n\n\
+             00:00:00.000 --> 00:00:01.500\nHello there.\n\n\
+             00:00:01.500 --> 01:01:01.250\nGeneral Kenobi.\n\n"
+        );
+    }
+
+    #[test]
+    fn test_export_json_round_trips() {
+        let json = sample().export(TranscriptFormat::Json);
+        let parsed: Transcript = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, sample());
+    }
+
+    #[test]
+    fn test_export_skips_empty_segments_and_blank_lines() {
+        let transcript = Transcript {
+            text: "a b".to_string(),
+            segments: vec![segment(0.0, 1.0, "  "), segment(1.0, 2.0, "a\n\nb")],
+        };
+        assert_eq!(
+            transcript.export(TranscriptFormat::Srt),
+            "1\n00:00:01,000 --> 00:00:02,000\na\nb\n\n"
+        );
+    }
+
+    #[test]
+    fn test_new_without_segments_spans_whole_audio() {
+        let transcript = Transcript::new("hello".to_string(), Vec::new(), 4.0);
+        assert_eq!(transcript.segments, vec![segment(0.0, 4.0, "hello")]);
+
+        let empty = Transcript::new(String::new(), Vec::new(), 4.0);
+        assert!(empty.segments.is_empty());
+    }
+
+    #[test]
+    fn test_segment_offset() {
+        assert_eq!(
+            segment(1.0, 2.0, "x").offset(30.0),
+            segment(31.0, 32.0, "x")
+        );
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!(TranscriptFormat::parse("SRT"), Some(TranscriptFormat::Srt));
+        assert_eq!(
+            TranscriptFormat::parse("webvtt"),
+            Some(TranscriptFormat::Vtt)
+        );
+        assert_eq!(TranscriptFormat::parse("docx"), None);
+        assert_eq!(TranscriptFormat::Vtt.extension(), "vtt");
+    }
+}
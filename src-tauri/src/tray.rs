@@ -2,7 +2,7 @@ use crate::settings;
 use tauri::image::Image;
 use tauri::menu::{Menu, MenuItem, PredefinedMenuItem};
 use tauri::tray::TrayIcon;
-use tauri::{AppHandle, Manager, Theme};
+use tauri::{AppHandle, Manager, Theme, WebviewWindow, WebviewWindowBuilder};
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum TrayIconState {
@@ -71,7 +71,10 @@ pub fn get_icon_path(theme: AppTheme, state: TrayIconState) -> &'static str {
 }
 
 pub fn change_tray_icon(app: &AppHandle, icon: TrayIconState) {
-    let tray = app.state::<TrayIcon>();
+    // No tray icon with --no-tray
+    let Some(tray) = app.try_state::<TrayIcon>() else {
+        return;
+    };
     let theme = get_current_theme(app);
 
     let icon_path = get_icon_path(theme, icon.clone());
@@ -185,7 +188,9 @@ pub fn update_tray_menu(app: &AppHandle, state: &TrayIconState) {
         .expect("failed to create menu"),
     };
 
-    let tray = app.state::<TrayIcon>();
+    let Some(tray) = app.try_state::<TrayIcon>() else {
+        return;
+    };
     let _ = tray.set_menu(Some(menu));
     // For colored theme (Linux), disable template mode to preserve colors
     // For macOS dark/light themes, enable template mode for system-tinted icons
@@ -193,18 +198,38 @@ pub fn update_tray_menu(app: &AppHandle, state: &TrayIconState) {
     let _ = tray.set_icon_as_template(use_template);
 }
 
-/// Show the main window
-pub fn show_main_window(app: &AppHandle) {
+/// Create the main window from its `tauri.conf.json` entry
+///
+/// The window has `create: false` so headless mode can skip it; it is built
+/// at startup or, in headless mode, the first time it is requested.
+pub fn create_main_window(app: &AppHandle) -> tauri::Result<WebviewWindow> {
     if let Some(main_window) = app.get_webview_window("main") {
-        // First, ensure the window is visible
-        if let Err(e) = main_window.show() {
-            log::error!("Failed to show window: {}", e);
-        }
-        // Then, bring it to the front and give it focus
-        if let Err(e) = main_window.set_focus() {
-            log::error!("Failed to focus window: {}", e);
+        return Ok(main_window);
+    }
+    let config = app
+        .config()
+        .app
+        .windows
+        .iter()
+        .find(|w| w.label == "main")
+        .cloned()
+        .unwrap_or_default();
+    WebviewWindowBuilder::from_config(app, &config)?.build()
+}
+
+/// Show the main window, creating it if needed
+pub fn show_main_window(app: &AppHandle) {
+    match create_main_window(app) {
+        Ok(main_window) => {
+            // First, ensure the window is visible
+            if let Err(e) = main_window.show() {
+                log::error!("Failed to show window: {}", e);
+            }
+            // Then, bring it to the front and give it focus
+            if let Err(e) = main_window.set_focus() {
+                log::error!("Failed to focus window: {}", e);
+            }
         }
-    } else {
-        log::error!("Main window not found.");
+        Err(e) => log::error!("Failed to create main window: {}", e),
     }
 }
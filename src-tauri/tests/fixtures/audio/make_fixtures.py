@@ -0,0 +1,183 @@
+#!/usr/bin/env python3
+"""Writes the decoder test fixtures without any encoder installed.
+
+    python3 make_fixtures.py [output dir]
+
+tone.mp3             MPEG-1 Layer III, 48 kHz mono, 1 s. Every granule holds a
+                     single spectral line (about 400 Hz), giving a tone that
+                     peaks at 0.5.
+tone-stereo.flac     8 kHz stereo 16-bit, 1 s of a 440 Hz tone peaking at 0.5
+                     on both channels, in verbatim subframes.
+silence-stereo.opus  Ogg/Opus stereo: eight 120 ms packets and two 20 ms
+                     packets whose frames carry no payload, so libopus decodes
+                     them to silence. The header's pre-skip is 0.5 s.
+"""
+import math
+import os
+import struct
+import sys
+
+
+class Bits:
+    def __init__(self):
+        self.bits = []
+
+    def put(self, value, count):
+        for i in range(count - 1, -1, -1):
+            self.bits.append((value >> i) & 1)
+
+    def to_bytes(self):
+        bits = self.bits + [0] * (-len(self.bits) % 8)
+        return bytes(
+            int("".join(map(str, bits[i : i + 8])), 2) for i in range(0, len(bits), 8)
+        )
+
+
+def mp3(path, global_gain=206, line=9, frames=42):
+    # Huffman data of one granule: count1 quadruples coded with table B
+    # (4-bit codes, inverted), then a sign bit per nonzero value
+    granule = Bits()
+    for quad in range(line // 4 + 1):
+        values = [0, 0, 0, 0]
+        if quad == line // 4:
+            values[line % 4] = 1
+        index = values[0] << 3 | values[1] << 2 | values[2] << 1 | values[3]
+        granule.put(~index & 0xF, 4)
+        for value in values:
+            if value:
+                granule.put(0, 1)
+
+    frame_size = 144 * 32000 // 48000
+    frame = Bits()
+    # Header: MPEG-1 Layer III, no CRC, 32 kbps, 48 kHz, no padding, mono
+    frame.put(0xFFF, 12)
+    frame.put(1, 1)
+    frame.put(0b01, 2)
+    frame.put(1, 1)
+    frame.put(0b0001, 4)
+    frame.put(0b01, 2)
+    frame.put(0, 2)
+    frame.put(0b11, 2)
+    frame.put(0, 6)
+    # Side info: main_data_begin, private bits, scfsi, then both granules
+    frame.put(0, 9)
+    frame.put(0, 5)
+    frame.put(0, 4)
+    for _ in range(2):
+        frame.put(len(granule.bits), 12)  # part2_3_length
+        frame.put(0, 9)  # big_values
+        frame.put(global_gain, 8)
+        frame.put(0, 4)  # scalefac_compress
+        frame.put(0, 1)  # window_switching_flag
+        frame.put(0, 15)  # table_select
+        frame.put(0, 7)  # region0_count, region1_count
+        frame.put(0, 2)  # preflag, scalefac_scale
+        frame.put(1, 1)  # count1table_select: table B
+    frame.bits += granule.bits + granule.bits
+    data = frame.to_bytes()
+    assert len(data) <= frame_size
+    with open(path, "wb") as f:
+        f.write((data + bytes(frame_size - len(data))) * frames)
+
+
+def crc8(data):
+    crc = 0
+    for byte in data:
+        crc ^= byte
+        for _ in range(8):
+            crc = ((crc << 1) ^ 0x07) & 0xFF if crc & 0x80 else (crc << 1) & 0xFF
+    return crc
+
+
+def crc16(data):
+    crc = 0
+    for byte in data:
+        crc ^= byte << 8
+        for _ in range(8):
+            crc = ((crc << 1) ^ 0x8005) & 0xFFFF if crc & 0x8000 else (crc << 1) & 0xFFFF
+    return crc
+
+
+def flac(path, rate=8000, block=4096):
+    samples = [
+        int(math.sin(2 * math.pi * 440 * i / rate) * 0.5 * 32767) for i in range(rate)
+    ]
+
+    info = Bits()
+    info.put(block, 16)
+    info.put(block, 16)
+    info.put(0, 48)  # frame sizes unknown
+    info.put(rate, 20)
+    info.put(2 - 1, 3)
+    info.put(16 - 1, 5)
+    info.put(len(samples), 36)
+    streaminfo = info.to_bytes() + bytes(16)  # no MD5
+    out = b"fLaC" + bytes([0x80]) + len(streaminfo).to_bytes(3, "big") + streaminfo
+
+    for number, start in enumerate(range(0, len(samples), block)):
+        chunk = samples[start : start + block]
+        full = len(chunk) == block
+        # 4096 samples, or the size in 16 bits after the header; 8 kHz;
+        # independent stereo; 16 bit; frame number (UTF-8 coded, < 128 here)
+        header = bytearray([0xFF, 0xF8, (0b1100 if full else 0b0111) << 4 | 0b0100])
+        header += bytes([0b0001 << 4 | 0b100 << 1, number])
+        if not full:
+            header += (len(chunk) - 1).to_bytes(2, "big")
+        header.append(crc8(header))
+        body = bytes(header)
+        for _ in range(2):
+            body += b"\x02" + b"".join(struct.pack(">h", s) for s in chunk)
+        out += body + crc16(body).to_bytes(2, "big")
+
+    with open(path, "wb") as f:
+        f.write(out)
+
+
+def ogg_crc(data):
+    crc = 0
+    for byte in data:
+        crc ^= byte << 24
+        for _ in range(8):
+            crc = (
+                ((crc << 1) ^ 0x04C11DB7) & 0xFFFFFFFF
+                if crc & 0x80000000
+                else (crc << 1) & 0xFFFFFFFF
+            )
+    return crc
+
+
+def ogg_page(packets, granule, sequence, flags):
+    lacing = b""
+    for packet in packets:
+        size = len(packet)
+        lacing += b"\xff" * (size // 255) + bytes([size % 255])
+    page = (
+        b"OggS"
+        + bytes([0, flags])
+        + struct.pack("<qIII", granule, 0x766F7963, sequence, 0)
+        + bytes([len(lacing)])
+        + lacing
+        + b"".join(packets)
+    )
+    return page[:22] + struct.pack("<I", ogg_crc(page)) + page[26:]
+
+
+def opus(path, pre_skip=24000):
+    head = b"OpusHead" + struct.pack("<BBHIhB", 1, 2, pre_skip, 48000, 0, 0)
+    vendor = b"voyc test fixture"
+    tags = b"OpusTags" + struct.pack("<I", len(vendor)) + vendor + struct.pack("<I", 0)
+    # TOC: CELT fullband 20 ms, stereo. Code 3 packs six frames (120 ms, the
+    # longest packet) of zero bytes each; code 0 is one empty frame.
+    toc = 31 << 3 | 1 << 2
+    packets = [bytes([toc | 3, 6])] * 8 + [bytes([toc])] * 2
+    with open(path, "wb") as f:
+        f.write(ogg_page([head], 0, 0, 0x02))
+        f.write(ogg_page([tags], 0, 1, 0))
+        f.write(ogg_page(packets, 48000, 2, 0x04))
+
+
+if __name__ == "__main__":
+    out_dir = sys.argv[1] if len(sys.argv) > 1 else os.path.dirname(os.path.abspath(__file__))
+    mp3(os.path.join(out_dir, "tone.mp3"))
+    flac(os.path.join(out_dir, "tone-stereo.flac"))
+    opus(os.path.join(out_dir, "silence-stereo.opus"))
@@ -1,24 +1,1323 @@
+
+// This file was generated by [tauri-specta](https://github.com/oscartbeaumont/tauri-specta). Do not edit this file manually.
+
+/** user-defined commands **/
+
+
+export const commands = {
+async getAppDirPath() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_app_dir_path") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getAppSettings() : Promise<Result<AppSettings, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_app_settings") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getDefaultAppSettings() : Promise<Result<AppSettings, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_default_app_settings") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getLogDirPath() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_log_dir_path") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get current app version
+ */
+async getAppVersion() : Promise<string> {
+    return await TAURI_INVOKE("get_app_version");
+},
+/**
+ * Run user-local updater script to install latest release.
+ */
+async runUserUpdate() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("run_user_update") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async openLogDir() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_log_dir") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async openAppDataDir() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_app_data_dir") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Update a single setting with type-safe value.
+ * 
+ * # Example (TypeScript)
+ * ```typescript
+ * await commands.updateSetting({ key: "push_to_talk", value: true });
+ * await commands.updateSetting({ key: "audio_feedback_volume", value: 0.8 });
+ * ```
+ */
+async updateSetting(pdate: SettingUpdate) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_setting", { pdate }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async cancelOperation() : Promise<void> {
+    await TAURI_INVOKE("cancel_operation");
+},
+/**
+ * Get system color scheme via XDG Desktop Portal.
+ * Returns "dark", "light", or "no-preference".
+ */
+async getSystemColorScheme() : Promise<string> {
+    return await TAURI_INVOKE("get_system_color_scheme");
+},
+/**
+ * Get the actual autostart state from the system (via plugin).
+ * This queries the OS-level autostart configuration, not the saved setting.
+ */
+async getAutostartEnabled() : Promise<Result<boolean, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_autostart_enabled") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set the autostart state directly via the plugin.
+ * This updates both the OS-level autostart and the saved setting.
+ */
+async setAutostartEnabled(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_autostart_enabled", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Sync the saved autostart setting with the actual OS-level autostart state.
+ * This is useful during app initialization to ensure consistency.
+ */
+async syncAutostartState() : Promise<Result<boolean, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("sync_autostart_state") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async checkCustomSounds() : Promise<CustomSounds> {
+    return await TAURI_INVOKE("check_custom_sounds");
+},
+async updateMicrophoneMode(alwaysOn: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_microphone_mode", { alwaysOn }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getMicrophoneMode() : Promise<Result<boolean, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_microphone_mode") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getAvailableMicrophones() : Promise<Result<AudioDevice[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_available_microphones") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setSelectedMicrophone(deviceName: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_selected_microphone", { deviceName }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getSelectedMicrophone() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_selected_microphone") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getAvailableOutputDevices() : Promise<Result<AudioDevice[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_available_output_devices") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setSelectedOutputDevice(deviceName: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_selected_output_device", { deviceName }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getSelectedOutputDevice() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_selected_output_device") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async playTestSound(soundType: string) : Promise<void> {
+    await TAURI_INVOKE("play_test_sound", { soundType });
+},
+async isRecording() : Promise<boolean> {
+    return await TAURI_INVOKE("is_recording");
+},
+async getAvailableModels() : Promise<Result<ModelInfo[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_available_models") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getModelInfo(odelId: string) : Promise<Result<ModelInfo | null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_model_info", { odelId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async downloadModel(odelId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("download_model", { odelId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteModel(odelId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_model", { odelId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setActiveModel(odelId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_active_model", { odelId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getCurrentModel() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_current_model") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getTranscriptionModelStatus() : Promise<Result<string | null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_transcription_model_status") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async isModelLoading() : Promise<Result<boolean, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("is_model_loading") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async hasAnyModelsAvailable() : Promise<Result<boolean, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("has_any_models_available") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async hasAnyModelsOrDownloads() : Promise<Result<boolean, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("has_any_models_or_downloads") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async cancelDownload(odelId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("cancel_download", { odelId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getRecommendedFirstModel() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_recommended_first_model") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setModelUnloadTimeout(imeout: ModelUnloadTimeout) : Promise<void> {
+    await TAURI_INVOKE("set_model_unload_timeout", { imeout });
+},
+async getModelLoadStatus() : Promise<Result<ModelLoadStatus, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_model_load_status") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async unloadModelManually() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("unload_model_manually") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get current cloud STT configuration status
+ */
+async getCloudSttStatus() : Promise<CloudSttStatus> {
+    return await TAURI_INVOKE("get_cloud_stt_status");
+},
+/**
+ * Configure cloud STT settings
+ */
+async setCloudSttConfig(enabled: boolean, provider: CloudSttProvider, apiKey: string, fallbackThreshold: number) : Promise<void> {
+    await TAURI_INVOKE("set_cloud_stt_config", { enabled, provider, apiKey, fallbackThreshold });
+},
+/**
+ * Set only the cloud STT API key (for secure input)
+ */
+async setCloudSttApiKey(apiKey: string) : Promise<void> {
+    await TAURI_INVOKE("set_cloud_stt_api_key", { apiKey });
+},
+/**
+ * Enable or disable cloud STT fallback
+ */
+async setCloudSttEnabled(enabled: boolean) : Promise<void> {
+    await TAURI_INVOKE("set_cloud_stt_enabled", { enabled });
+},
+/**
+ * Set cloud STT provider
+ */
+async setCloudSttProvider(provider: CloudSttProvider) : Promise<void> {
+    await TAURI_INVOKE("set_cloud_stt_provider", { provider });
+},
+/**
+ * Set cloud STT fallback threshold
+ */
+async setCloudSttThreshold(hreshold: number) : Promise<void> {
+    await TAURI_INVOKE("set_cloud_stt_threshold", { hreshold });
+},
+/**
+ * Check if cloud STT is ready to use
+ */
+async isCloudSttAvailable() : Promise<boolean> {
+    return await TAURI_INVOKE("is_cloud_stt_available");
+},
+/**
+ * Transcribe audio with cloud fallback support
+ * This is an async command that performs local transcription and falls back to cloud if needed
+ */
+async transcribeWithFallback(audio: number[]) : Promise<Result<TranscriptionResultWithFallback, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("transcribe_with_fallback", { audio }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
- * TypeScript bindings for Tauri commands
- * This file is auto-generated by tauri-specta during development builds
- * Manual stub provided for standalone TypeScript checking
+ * Transcribe audio using only cloud STT (bypass local model)
  */
+async transcribeCloudOnly(audio: number[]) : Promise<Result<TranscriptionResultWithFallback, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("transcribe_cloud_only", { audio }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Transcribe an audio file (WAV, FLAC, MP3, OGG/Vorbis, OGG/Opus) offline with the loaded model
+ * 
+ * Progress is reported through `transcription-progress` events. The
+ * result keeps segment timestamps for `export_transcript`.
+ */
+async transcribeFile(path: string) : Promise<Result<Transcript, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("transcribe_file", { path }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Write a transcript to `path` as plain text, SRT, WebVTT or timestamped JSON
+ */
+async exportTranscript(ranscript: Transcript, format: TranscriptFormat, path: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_transcript", { ranscript, format, path }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * List history entries, most recent first
+ */
+async getHistoryEntries(limit: number | null, offset: number | null) : Promise<Result<HistoryEntry[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_history_entries", { limit, offset }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Full-text search over history entries, best matches first
+ */
+async searchHistory(query: string, limit: number | null) : Promise<Result<HistoryEntry[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("search_history", { query, limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteHistoryEntry(id: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_history_entry", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async clearHistory() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("clear_history") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Path of the saved audio for an entry, if a recording was kept
+ */
+async getHistoryRecordingPath(id: number) : Promise<Result<string | null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_history_recording_path", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Alternative transcripts stored for an entry, oldest first
+ */
+async getHistoryAlternatives(id: number) : Promise<Result<AlternativeTranscript[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_history_alternatives", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Re-transcribe an entry's saved recording with another model or cloud provider
+ * and store the result as an alternative transcript.
+ * 
+ * The live dictation engine is left as is: other local models are loaded into a
+ * temporary engine, and the loaded model queues behind live dictation.
+ */
+async retranscribeHistoryEntry(id: number, arget: RetranscribeTarget) : Promise<Result<AlternativeTranscript, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("retranscribe_history_entry", { id, arget }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set how long history is kept; entries outside the new period are pruned immediately.
+ * `Never` stops saving new entries but keeps existing ones (see `clear_history`).
+ */
+async setHistoryRetention(retention: HistoryRetention) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_history_retention", { retention }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set the model used for a post-processing provider
+ */
+async setPostProcessModel(providerId: string, odel: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_post_process_model", { providerId, odel }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set the base URL of a post-processing provider that allows editing it
+ */
+async setPostProcessBaseUrl(providerId: string, baseUrl: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_post_process_base_url", { providerId, baseUrl }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * List the models offered by a post-processing provider
+ */
+async fetchPostProcessModels(providerId: string) : Promise<Result<string[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("fetch_post_process_models", { providerId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Add a prompt to the library, or replace the prompt with the same id
+ */
+async savePostProcessPrompt(prompt: PostProcessPrompt) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_post_process_prompt", { prompt }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Remove a prompt from the library; bindings using it fall back to raw text
+ */
+async deletePostProcessPrompt(promptId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_post_process_prompt", { promptId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Select the prompt applied to a binding's dictations (`None` = no post-processing)
+ */
+async setBindingPostProcessPrompt(bindingId: string, promptId: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_binding_post_process_prompt", { bindingId, promptId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Inject text into the currently focused application.
+ * 
+ * This command copies the text to the clipboard and attempts to simulate
+ * a paste keystroke using ydotool or wtype. If no paste tool is available,
+ * the text remains in the clipboard for manual paste.
+ * 
+ * @task T027
+ * @epic T026
+ * 
+ * # Arguments
+ * * `app` - Tauri AppHandle
+ * * `text` - Text to inject
+ * 
+ * # Returns
+ * * `InjectionResultResponse` with success status and method used
+ */
+async injectText(ext: string) : Promise<InjectionResultResponse> {
+    return await TAURI_INVOKE("inject_text", { ext });
+},
+/**
+ * Check which paste tools are available on the system.
+ * 
+ * This command checks for the presence of ydotool and wtype, which are
+ * used for simulating paste keystrokes on Wayland.
+ * 
+ * @task T027
+ * @epic T026
+ * 
+ * # Returns
+ * * `PasteToolsStatus` indicating which tools are available
+ */
+async checkPasteTools() : Promise<PasteToolsStatus> {
+    return await TAURI_INVOKE("check_paste_tools");
+},
+/**
+ * Updates a binding's shortcut.
+ * 
+ * This command unregisters the old shortcut, updates the settings,
+ * and registers the new shortcut.
+ * 
+ * Note: On Wayland, this updates the stored preference but users must
+ * configure the actual shortcuts in System Settings.
+ * 
+ * # Arguments
+ * 
+ * * `binding_id` - The binding identifier (e.g., "transcribe", "cancel")
+ * * `new_binding` - The new shortcut string (e.g., "ctrl+space", "escape")
+ * 
+ * # Returns
+ * 
+ * * `Ok(())` - The binding was updated successfully
+ * * `Err(String)` - An error occurred during the update
+ */
+async updateBinding(bindingId: string, newBinding: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_binding", { bindingId, newBinding }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Suspends a binding temporarily.
+ * 
+ * This is useful when the user wants to record a new shortcut and the
+ * existing shortcut would interfere with the recording process.
+ * 
+ * Note: On Wayland, shortcuts cannot be suspended as they're managed by the portal.
+ * This command still tracks the suspension state for UI consistency.
+ * 
+ * # Arguments
+ * 
+ * * `binding_id` - The binding identifier to suspend
+ * 
+ * # Returns
+ * 
+ * * `Ok(())` - The binding was suspended successfully
+ * * `Err(String)` - An error occurred during suspension
+ */
+async suspendBinding(bindingId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("suspend_binding", { bindingId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Resumes a suspended binding.
+ * 
+ * This re-registers the shortcut that was previously suspended.
+ * 
+ * # Arguments
+ * 
+ * * `binding_id` - The binding identifier to resume
+ * 
+ * # Returns
+ * 
+ * * `Ok(())` - The binding was resumed successfully
+ * * `Err(String)` - An error occurred during resumption
+ */
+async resumeBinding(bindingId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("resume_binding", { bindingId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Adds a dictation shortcut with its own post-processing prompt.
+ * 
+ * The shortcut starts unassigned; set its keys with `update_binding` and its
+ * prompt with `set_binding_post_process_prompt`.
+ * 
+ * # Arguments
+ * 
+ * * `name` - Display name of the new shortcut
+ * 
+ * # Returns
+ * 
+ * * `Ok(ShortcutBinding)` - The new binding
+ * * `Err(String)` - The name was empty
+ */
+async addDictationBinding(name: string) : Promise<Result<ShortcutBinding, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_dictation_binding", { name }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Removes a dictation shortcut added with `add_dictation_binding`.
+ * 
+ * # Arguments
+ * 
+ * * `binding_id` - The binding identifier to remove
+ * 
+ * # Returns
+ * 
+ * * `Ok(())` - The binding was removed
+ * * `Err(String)` - The binding is built in or doesn't exist
+ */
+async removeDictationBinding(bindingId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("remove_dictation_binding", { bindingId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Registers all shortcuts from settings.
+ * 
+ * This command reads the current application settings and registers
+ * all non-empty shortcut bindings with the global shortcut system.
+ * It is typically called during application initialization.
+ * 
+ * On Wayland, this registers actions with the XDG Desktop Portal.
+ * On X11, this registers specific key combinations.
+ * 
+ * # Returns
+ * 
+ * * `Ok(())` - All shortcuts were registered successfully
+ * * `Err(String)` - An error occurred during registration
+ */
+async registerAllShortcuts() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("register_all_shortcuts") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Checks if a binding is currently suspended.
+ * 
+ * # Arguments
+ * 
+ * * `binding_id` - The binding identifier to check
+ * 
+ * # Returns
+ * 
+ * * `true` if the binding is suspended
+ * * `false` if the binding is active or doesn't exist
+ */
+async isBindingSuspended(bindingId: string) : Promise<boolean> {
+    return await TAURI_INVOKE("is_binding_suspended", { bindingId });
+},
+/**
+ * Gets information about the shortcut backend.
+ * 
+ * This tells the frontend whether shortcuts are configured via:
+ * - X11: Traditional key grabbing (user sets shortcuts in the app)
+ * - Wayland Portal: XDG Desktop Portal (user sets shortcuts in System Settings)
+ * 
+ * # Returns
+ * 
+ * Information about the current shortcut backend
+ */
+async getShortcutBackendInfo() : Promise<ShortcutBackendInfo> {
+    return await TAURI_INVOKE("get_shortcut_backend_info");
+},
+/**
+ * Opens the system settings for configuring shortcuts.
+ * 
+ * On GNOME, this opens Settings > Applications
+ * On KDE, this opens System Settings > Shortcuts
+ * 
+ * This is primarily useful on Wayland where shortcuts must be configured
+ * in System Settings rather than in the app.
+ * 
+ * # Returns
+ * 
+ * * `Ok(())` - Settings were opened successfully
+ * * `Err(String)` - Failed to open settings or not on Linux
+ */
+async openShortcutSettings() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_shortcut_settings") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Start dictation recording
+ * 
+ * Called when the hotkey is pressed. Begins recording audio.
+ * 
+ * @task DICTATION
+ * @epic T026
+ */
+async startDictation(bindingId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("start_dictation", { bindingId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Stop dictation and process
+ * 
+ * Called when the hotkey is released. Stops recording, transcribes audio,
+ * and injects the text into the focused application.
+ * 
+ * @task DICTATION
+ * @epic T026
+ */
+async stopDictation(bindingId: string) : Promise<Result<DictationResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("stop_dictation", { bindingId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Cancel ongoing dictation
+ * 
+ * Cancels any ongoing recording without processing. The recorded audio
+ * is discarded.
+ * 
+ * @task DICTATION
+ * @epic T026
+ */
+async cancelDictation() : Promise<void> {
+    await TAURI_INVOKE("cancel_dictation");
+},
+/**
+ * Check if dictation is currently active
+ * 
+ * Returns true if dictation is in progress (recording or transcribing).
+ * 
+ * @task DICTATION
+ * @epic T026
+ */
+async isDictationActive() : Promise<boolean> {
+    return await TAURI_INVOKE("is_dictation_active");
+},
+/**
+ * Get current dictation state
+ * 
+ * Returns the current state of the dictation workflow.
+ * 
+ * @task DICTATION
+ * @epic T026
+ */
+async getDictationState() : Promise<DictationState> {
+    return await TAURI_INVOKE("get_dictation_state");
+},
+/**
+ * Start in-app dictation recording
+ * 
+ * This is a simplified version for the in-app dictation interface.
+ * Does not require a binding ID - uses a fixed internal ID.
+ * 
+ * @task IN_APP_DICTATION
+ */
+async startInAppDictation() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("start_in_app_dictation") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Stop in-app dictation and return text (without injecting)
+ * 
+ * This version returns the transcribed text directly without attempting
+ * to inject it into another application. Perfect for the in-app dictation
+ * interface where the user can copy the text manually.
+ * 
+ * @task IN_APP_DICTATION
+ */
+async stopInAppDictation() : Promise<Result<DictationResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("stop_in_app_dictation") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Cancel in-app dictation
+ * 
+ * Cancels any ongoing in-app recording without processing.
+ * 
+ * @task IN_APP_DICTATION
+ */
+async cancelInAppDictation() : Promise<void> {
+    await TAURI_INVOKE("cancel_in_app_dictation");
+}
+}
 
-import { invoke } from "@tauri-apps/api/core";
+/** user-defined events **/
 
-// Command interface matching Rust backend
-export const commands = {
-  /**
-   * Cancel any ongoing operation
-   */
-  cancelOperation: async (): Promise<void> => {
-    await invoke("cancel_operation");
-  },
-
-  /**
-   * Cancel ongoing dictation
-   */
-  cancelDictation: async (): Promise<void> => {
-    await invoke("cancel_dictation");
-  },
+
+
+/** user-defined constants **/
+
+
+
+/** user-defined types **/
+
+/**
+ * Another transcription of a history entry's recording
+ */
+export type AlternativeTranscript = { id: number; entry_id: number; 
+/**
+ * Unix timestamp in seconds
+ */
+timestamp: number; text: string; 
+/**
+ * Local model used (None for cloud providers)
+ */
+model_id: string | null; 
+/**
+ * Cloud provider used (None for local models)
+ */
+provider: string | null; duration_ms: number }
+/**
+ * Simplified settings for Voyc - Linux-focused voice dictation
+ */
+export type AppSettings = { bindings: Partial<{ [key in string]: ShortcutBinding }>; push_to_talk: boolean; max_recording_duration_secs?: number; auto_stop_on_silence?: boolean; auto_stop_silence_ms?: number; streaming_transcription?: boolean; audio_feedback: boolean; audio_feedback_volume?: number; sound_theme?: SoundTheme; start_hidden?: boolean; autostart_enabled?: boolean; update_checks_enabled?: boolean; selected_model?: string; always_on_microphone?: boolean; selected_microphone?: string | null; selected_output_device?: string | null; translate_to_english?: boolean; selected_language?: string; custom_words?: string[]; word_correction_threshold?: number; output_filters?: OutputFilters; spoken_commands?: SpokenCommands; itn_languages?: string[]; text_replacements?: TextReplacement[]; engine_params?: EngineParams; model_unload_timeout?: ModelUnloadTimeout; mute_while_recording?: boolean; overlay_position?: OverlayPosition; cloud_stt_enabled?: boolean; cloud_stt_provider?: CloudSttProvider; cloud_stt_api_key?: string; cloud_stt_fallback_threshold?: number; vad_threshold?: number; vad_prefill_frames?: number; vad_hangover_frames?: number; vad_onset_frames?: number; post_process_enabled?: boolean; post_process_api_key?: string; post_process_provider?: string; post_process_providers?: PostProcessProvider[]; post_process_models?: Partial<{ [key in string]: string }>; post_process_prompts?: PostProcessPrompt[]; post_process_timeout_secs?: number; history_retention?: HistoryRetention; save_recordings?: boolean; recordings_quota_mb?: number; dictation_text_mode?: string; theme_mode?: string; log_level?: string; settings_version?: number }
+export type AudioDevice = { index: string; name: string; is_default: boolean }
+/**
+ * Supported cloud STT providers
+ */
+export type CloudSttProvider = "open_ai" | "eleven_labs"
+/**
+ * Cloud STT fallback status
+ */
+export type CloudSttStatus = { enabled: boolean; provider: CloudSttProvider; has_api_key: boolean; fallback_threshold: number }
+/**
+ * What a spoken formatting command does
+ */
+export type CommandAction = 
+/**
+ * Punctuation attached to the previous word, e.g. "," or "?"
+ */
+{ type: "punctuation"; text: string } | { type: "new_line" } | { type: "new_paragraph" } | { type: "open_quote" } | { type: "close_quote" } | 
+/**
+ * Starts a "• " item on a new line
+ */
+{ type: "bullet_point" } | 
+/**
+ * Uppercase the following words until `CapsOff`
+ */
+{ type: "caps_on" } | { type: "caps_off" }
+/**
+ * Formatting commands of one language
+ */
+export type CommandVocabulary = { 
+/**
+ * Word that makes the following command literal ("literal comma"); empty for none
+ */
+escape_word: string; commands: SpokenCommand[] }
+export type CustomSounds = { start: boolean; stop: boolean }
+/**
+ * Result of a dictation operation
+ */
+export type DictationResult = { 
+/**
+ * Whether the operation succeeded
+ */
+success: boolean; 
+/**
+ * The transcribed text (empty if failed or no audio)
+ */
+text: string; 
+/**
+ * Error message if operation failed
+ */
+error: string | null; 
+/**
+ * Whether cloud fallback was used
+ */
+used_fallback: boolean; 
+/**
+ * Provider used (None = local, Some = cloud provider name)
+ */
+provider: string | null; 
+/**
+ * Duration in milliseconds
+ */
+duration_ms: number }
+/**
+ * Dictation state for tracking workflow progress
+ */
+export type DictationState = "idle" | "recording" | "transcribing"
+/**
+ * Per-engine tuning; only the block for the loaded engine's type is used.
+ * Load-time options (Moonshine size, Parakeet quantization) come from the
+ * model catalog, since they must match the downloaded files.
+ */
+export type EngineParams = { whisper: WhisperParams }
+/**
+ * Engine a model runs on, with the load-time options its files need
+ */
+export type EngineType = "Whisper" | { Parakeet: ParakeetQuantization } | { Moonshine: MoonshineVariant }
+/**
+ * A recorded dictation
+ */
+export type HistoryEntry = { id: number; 
+/**
+ * Unix timestamp in seconds
+ */
+timestamp: number; 
+/**
+ * Final text (after post-processing)
+ */
+text: string; 
+/**
+ * Transcription before post-processing
+ */
+raw_text: string; post_processed: boolean; model_id: string | null; provider: string | null; used_fallback: boolean; duration_ms: number; latency: LatencyMetrics; 
+/**
+ * Whether the dictation audio was saved (see `get_history_recording_path`)
+ */
+has_recording: boolean }
+/**
+ * How long dictation history entries are kept
+ */
+export type HistoryRetention = "never" | "days_7" | "days_30" | "days_90" | "forever"
+/**
+ * Response structure for text injection result.
+ * 
+ * @task T027
+ * @epic T026
+ */
+export type InjectionResultResponse = { 
+/**
+ * Whether the injection was successful (includes clipboard_only as success)
+ */
+success: boolean; 
+/**
+ * The method used: "ydotool", "wtype", or "clipboard_only"
+ */
+method: string | null; 
+/**
+ * Error message if injection failed completely
+ */
+error: string | null }
+/**
+ * Latency metrics for performance tracking (REQ-016)
+ */
+export type LatencyMetrics = { capture_ms: number; transcription_ms: number; post_process_ms: number; injection_ms: number; total_ms: number }
+export type ModelInfo = { id: string; name: string; description: string; filename: string; url: string | null; size_mb: number; is_downloaded: boolean; is_downloading: boolean; partial_size: number; is_directory: boolean; engine_type: EngineType; accuracy_score: number; speed_score: number }
+export type ModelLoadStatus = { is_loaded: boolean; current_model: string | null }
+export type ModelUnloadTimeout = "never" | "immediately" | "min_2" | "min_5" | "min_10" | "min_15" | "hour_1" | "sec_5"
+/**
+ * Moonshine model size; must match the model files
+ */
+export type MoonshineVariant = "tiny" | "base"
+/**
+ * User settings for cleaning up transcripts
+ */
+export type OutputFilters = { remove_filler_words: boolean; 
+/**
+ * Collapse 3+ repetitions of a 1-2 letter word ("I I I think")
+ */
+collapse_stutters: boolean; detect_hallucinations: boolean; 
+/**
+ * Added to the built-in fillers of every language
+ */
+extra_filler_words: string[]; 
+/**
+ * Added to the built-in hallucination phrases of every language
+ */
+extra_hallucinations: string[] }
+export type OverlayPosition = "none" | "top" | "bottom"
+/**
+ * Precision the Parakeet ONNX weights were exported at
+ */
+export type ParakeetQuantization = "int_8" | "fp_32"
+/**
+ * Status of available paste tools on the system.
+ * 
+ * @task T027
+ * @epic T026
+ */
+export type PasteToolsStatus = { 
+/**
+ * Whether ydotool is available
+ */
+ydotool_available: boolean; 
+/**
+ * Whether wtype is available
+ */
+wtype_available: boolean; 
+/**
+ * Whether any paste tool is available
+ */
+any_available: boolean }
+/**
+ * Named prompt template for LLM post-processing
+ */
+export type PostProcessPrompt = { id: string; name: string; 
+/**
+ * Template text; `${output}` is replaced with the transcription
+ */
+template: string; 
+/**
+ * Model override; falls back to the provider's selected model when unset
+ */
+model?: string | null }
+export type PostProcessProvider = { id: string; label: string; base_url: string; allow_base_url_edit?: boolean; models_endpoint?: string | null }
+/**
+ * How a replacement's pattern is matched against the transcript
+ */
+export type ReplacementMatch = 
+/**
+ * Literal phrase, case-sensitive
+ */
+"exact" | 
+/**
+ * Literal phrase, ignoring case
+ */
+"case_insensitive" | 
+/**
+ * Regular expression; the replacement may reference groups as `$1`
+ */
+"regex"
+/**
+ * What to re-transcribe a saved recording with
+ */
+export type RetranscribeTarget = 
+/**
+ * A downloaded local model id
+ */
+{ kind: "model"; value: string } | 
+/**
+ * A cloud STT provider (uses the configured cloud API key)
+ */
+{ kind: "cloud"; value: CloudSttProvider }
+/**
+ * Strongly-typed setting update enum - each variant has the correct type.
+ * This provides type safety, works with specta for TypeScript generation,
+ * and follows SOLID principles (Open/Closed - add new variants without modifying existing code).
+ */
+export type SettingUpdate = { key: "push_to_talk"; value: boolean } | { key: "max_recording_duration_secs"; value: number } | { key: "auto_stop_on_silence"; value: boolean } | { key: "auto_stop_silence_ms"; value: number } | { key: "streaming_transcription"; value: boolean } | { key: "audio_feedback"; value: boolean } | { key: "audio_feedback_volume"; value: number } | { key: "start_hidden"; value: boolean } | { key: "autostart_enabled"; value: boolean } | { key: "update_checks_enabled"; value: boolean } | { key: "translate_to_english"; value: boolean } | { key: "selected_language"; value: string } | { key: "mute_while_recording"; value: boolean } | { key: "always_on_microphone"; value: boolean } | { key: "cloud_stt_enabled"; value: boolean } | { key: "dictation_text_mode"; value: string } | { key: "theme_mode"; value: string } | { key: "log_level"; value: string } | { key: "sound_theme"; value: string } | { key: "vad_threshold"; value: number } | { key: "vad_prefill_frames"; value: number } | { key: "vad_hangover_frames"; value: number } | { key: "vad_onset_frames"; value: number } | { key: "post_process_enabled"; value: boolean } | { key: "post_process_provider"; value: string } | { key: "post_process_api_key"; value: string } | { key: "post_process_timeout_secs"; value: number } | { key: "save_recordings"; value: boolean } | { key: "recordings_quota_mb"; value: number } | { key: "engine_params"; value: EngineParams } | { key: "text_replacements"; value: TextReplacement[] } | { key: "output_filters"; value: OutputFilters } | { key: "itn_languages"; value: string[] } | { key: "spoken_commands"; value: SpokenCommands }
+/**
+ * Information about the shortcut backend for the frontend
+ */
+export type ShortcutBackendInfo = { 
+/**
+ * The backend being used: "x11", "wayland_portal", or "unavailable"
+ */
+backend: string; 
+/**
+ * Whether shortcuts must be configured in system settings (true for Wayland)
+ */
+requires_system_settings: boolean; 
+/**
+ * The current platform
+ */
+platform: string; 
+/**
+ * Display server description (Linux only)
+ */
+display_server: string | null; 
+/**
+ * Human-readable message about the shortcut configuration
+ */
+message: string }
+export type ShortcutBinding = { id: string; name: string; description: string; default_binding: string; current_binding: string; post_process_prompt_id?: string | null }
+export type SoundTheme = "marimba" | "pop" | "custom"
+/**
+ * A spoken phrase ("new line") and the formatting it stands for
+ */
+export type SpokenCommand = { phrase: string; action: CommandAction }
+/**
+ * User settings for spoken formatting commands
+ */
+export type SpokenCommands = { 
+/**
+ * Off by default: "period" or "colon" are ordinary words in most dictation
+ */
+enabled: boolean; 
+/**
+ * Vocabularies by language code, replacing the built-in one
+ */
+vocabularies: Partial<{ [key in string]: CommandVocabulary }> }
+/**
+ * A user rule turning a spoken phrase into written text ("voice see" → "Voyc")
+ */
+export type TextReplacement = { pattern: string; replacement: string; match_type?: ReplacementMatch; 
+/**
+ * Don't match inside longer words (literal phrases only; regexes bring their own `\b`)
+ */
+whole_word?: boolean }
+export type Transcript = { text: string; segments: TranscriptSegment[] }
+/**
+ * Export formats for `Transcript::export`
+ */
+export type TranscriptFormat = "text" | "srt" | "vtt" | "json"
+/**
+ * A span of speech, with times in seconds from the start of the audio
+ */
+export type TranscriptSegment = { start: number; end: number; text: string }
+/**
+ * Detailed transcription result with confidence and fallback information
+ */
+export type TranscriptionResultWithFallback = { 
+/**
+ * The final transcribed text
+ */
+text: string; 
+/**
+ * Estimated confidence of the transcription (0.0-1.0)
+ */
+confidence: number; 
+/**
+ * Whether cloud fallback was triggered
+ */
+used_fallback: boolean; 
+/**
+ * Which provider was used (None = local, Some = cloud provider name)
+ */
+provider: string | null; 
+/**
+ * Total processing time in milliseconds
+ */
+duration_ms: number }
+/**
+ * Whisper decoding options
+ */
+export type WhisperParams = { 
+/**
+ * Text Whisper continues from; biases spelling and style (empty = none)
+ */
+initial_prompt: string; 
+/**
+ * Sampling temperature (0.0 = greedy)
+ */
+temperature: number; 
+/**
+ * Beams searched per segment (1 = greedy decoding)
+ */
+beam_size: number; 
+/**
+ * Candidates sampled when decoding greedily at a temperature above 0
+ */
+best_of: number; 
+/**
+ * Segments with a no-speech probability above this are dropped
+ */
+no_speech_threshold: number; 
+/**
+ * Decoder threads (0 = let whisper.cpp decide)
+ */
+threads: number; suppress_blank: boolean }
+
+/** tauri-specta globals **/
+
+import {
+	invoke as TAURI_INVOKE,
+	Channel as TAURI_CHANNEL,
+} from "@tauri-apps/api/core";
+import * as TAURI_API_EVENT from "@tauri-apps/api/event";
+import { type WebviewWindow as __WebviewWindow__ } from "@tauri-apps/api/webviewWindow";
+
+type __EventObj__<T> = {
+	listen: (
+		cb: TAURI_API_EVENT.EventCallback<T>,
+	) => ReturnType<typeof TAURI_API_EVENT.listen<T>>;
+	once: (
+		cb: TAURI_API_EVENT.EventCallback<T>,
+	) => ReturnType<typeof TAURI_API_EVENT.once<T>>;
+	emit: null extends T
+		? (payload?: T) => ReturnType<typeof TAURI_API_EVENT.emit>
+		: (payload: T) => ReturnType<typeof TAURI_API_EVENT.emit>;
 };
+
+export type Result<T, E> =
+	| { status: "ok"; data: T }
+	| { status: "error"; error: E };
+
+function __makeEvents__<T extends Record<string, any>>(
+	mappings: Record<keyof T, string>,
+) {
+	return new Proxy(
+		{} as unknown as {
+			[K in keyof T]: __EventObj__<T[K]> & {
+				(handle: __WebviewWindow__): __EventObj__<T[K]>;
+			};
+		},
+		{
+			get: (_, event) => {
+				const name = mappings[event as keyof T];
+
+				return new Proxy((() => {}) as any, {
+					apply: (_, __, [window]: [__WebviewWindow__]) => ({
+						listen: (arg: any) => window.listen(name, arg),
+						once: (arg: any) => window.once(name, arg),
+						emit: (arg: any) => window.emit(name, arg),
+					}),
+					get: (_, command: keyof __EventObj__<any>) => {
+						switch (command) {
+							case "listen":
+								return (arg: any) => TAURI_API_EVENT.listen(name, arg);
+							case "once":
+								return (arg: any) => TAURI_API_EVENT.once(name, arg);
+							case "emit":
+								return (arg: any) => TAURI_API_EVENT.emit(name, arg);
+						}
+					},
+				});
+			},
+		},
+	);
+}
@@ -1,14 +1,48 @@
 /**
  * Hotkey Settings Panel for Voyc
- * Contains: Toggle dictation hotkey, Terminal paste hotkey
+ * Contains: Toggle dictation hotkey, Terminal paste hotkey, added dictation hotkeys
  * Adapted from Handy's GlobalShortcutInput patterns
  */
 
-import React from "react";
+import React, { useState } from "react";
 import { SettingsGroup } from "../ui/SettingsGroup";
 import { GlobalShortcutInput } from "./GlobalShortcutInput";
+import { useSettings } from "../../hooks/useSettings";
+import { commands } from "../../bindings";
+import type { ShortcutBinding } from "../../stores/settingsStore";
+
+// Ids of dictation bindings added by the user (see DICTATION_BINDING_PREFIX)
+const DICTATION_BINDING_PREFIX = "transcribe_";
 
 export const HotkeySettings: React.FC = () => {
+  const { getSetting, refreshSettings } = useSettings();
+  const [newName, setNewName] = useState("");
+
+  const bindings = (getSetting("bindings") as Record<string, ShortcutBinding>) ?? {};
+  const addedBindings = Object.values(bindings)
+    .filter((b) => b.id.startsWith(DICTATION_BINDING_PREFIX))
+    .sort((a, b) => a.id.localeCompare(b.id, undefined, { numeric: true }));
+
+  const addBinding = async () => {
+    if (!newName.trim()) return;
+    const result = await commands.addDictationBinding(newName);
+    if (result.status === "error") {
+      console.error("Failed to add dictation shortcut:", result.error);
+      return;
+    }
+    setNewName("");
+    await refreshSettings();
+  };
+
+  const removeBinding = async (bindingId: string) => {
+    const result = await commands.removeDictationBinding(bindingId);
+    if (result.status === "error") {
+      console.error("Failed to remove dictation shortcut:", result.error);
+      return;
+    }
+    await refreshSettings();
+  };
+
   return (
     <div className="max-w-3xl w-full mx-auto space-y-6">
       <SettingsGroup title="Keyboard Shortcuts">
@@ -23,6 +57,51 @@ export const HotkeySettings: React.FC = () => {
           grouped={true}
         />
       </SettingsGroup>
+
+      <SettingsGroup
+        title="Dictation Shortcuts"
+        description="Extra dictation hotkeys, each with its own post-processing prompt"
+      >
+        {addedBindings.map((binding) => (
+          <div key={binding.id} className="flex items-center">
+            <div className="flex-1">
+              <GlobalShortcutInput
+                shortcutId={binding.id}
+                descriptionMode="tooltip"
+                grouped={true}
+              />
+            </div>
+            <button
+              onClick={() => removeBinding(binding.id)}
+              className="ml-2 px-2 py-1 text-xs rounded bg-mid-gray/10 hover:bg-red-500/20"
+            >
+              Remove
+            </button>
+          </div>
+        ))}
+        <div className="flex items-center gap-2 p-3">
+          <input
+            type="text"
+            value={newName}
+            onChange={(e) => setNewName(e.target.value)}
+            onKeyDown={(e) => {
+              if (e.key === "Enter") addBinding();
+            }}
+            placeholder="Name, e.g. Commit message"
+            className="flex-1 px-3 py-2 text-sm rounded-lg bg-mid-gray/10 border border-mid-gray/20 focus:border-logo-primary focus:outline-none"
+          />
+          <button
+            onClick={addBinding}
+            disabled={!newName.trim()}
+            className="px-3 py-2 text-sm rounded-lg bg-logo-primary/20 hover:bg-logo-primary/30 disabled:opacity-50"
+          >
+            Add shortcut
+          </button>
+        </div>
+        <p className="px-3 pb-3 text-xs text-text/50">
+          Choose each shortcut's prompt under Post-Processing.
+        </p>
+      </SettingsGroup>
     </div>
   );
 };
@@ -3,11 +3,18 @@
  * Configure LLM post-processing for smart formatting (REQ-011-013)
  */
 
-import React from "react";
+import React, { useState } from "react";
 import { SettingsGroup } from "../ui/SettingsGroup";
 import { ToggleSwitch } from "../ui/ToggleSwitch";
 import { Select } from "../ui/Select";
 import { useSettings } from "../../hooks/useSettings";
+import { commands } from "../../bindings";
+import type { PostProcessPrompt, ShortcutBinding } from "../../stores/settingsStore";
+
+const NO_PROMPT = "__none__";
+
+// Built-in "transcribe" plus dictation bindings added under Keyboard Shortcuts
+const isDictationBinding = (id: string) => id === "transcribe" || id.startsWith("transcribe_");
 
 const POST_PROCESS_PROVIDERS = [
   { value: "baseten", label: "Baseten (LLaMA - Default)" },
@@ -16,11 +23,37 @@ const POST_PROCESS_PROVIDERS = [
 ];
 
 export const PostProcessingSettings: React.FC = () => {
-  const { getSetting, updateSetting, isUpdating } = useSettings();
+  const { getSetting, updateSetting, isUpdating, refreshSettings } = useSettings();
 
   const postProcessEnabled = (getSetting("post_process_enabled") as boolean) ?? false;
   const postProcessProvider = (getSetting("post_process_provider") as string) ?? "baseten";
   const postProcessApiKey = (getSetting("post_process_api_key") as string) ?? "";
+  const prompts = (getSetting("post_process_prompts") as PostProcessPrompt[]) ?? [];
+  const bindings = (getSetting("bindings") as Record<string, ShortcutBinding>) ?? {};
+  const dictationBindings = Object.values(bindings)
+    .filter((b) => isDictationBinding(b.id))
+    .sort((a, b) => a.id.localeCompare(b.id, undefined, { numeric: true }));
+  const [editedPromptId, setEditedPromptId] = useState<string | null>(null);
+  const selectedPrompt =
+    prompts.find((p) => p.id === editedPromptId) ??
+    prompts.find((p) => p.id === bindings["transcribe"]?.post_process_prompt_id) ??
+    prompts[0];
+
+  const selectPrompt = async (bindingId: string, promptId: string | null) => {
+    const result = await commands.setBindingPostProcessPrompt(bindingId, promptId);
+    if (result.status === "error") {
+      console.error("Failed to select prompt:", result.error);
+    }
+    await refreshSettings();
+  };
+
+  const saveTemplate = async (prompt: PostProcessPrompt, template: string) => {
+    const result = await commands.savePostProcessPrompt({ ...prompt, template });
+    if (result.status === "error") {
+      console.error("Failed to save prompt:", result.error);
+    }
+    await refreshSettings();
+  };
 
   return (
     <SettingsGroup
@@ -72,6 +105,63 @@ export const PostProcessingSettings: React.FC = () => {
           </div>
         )}
 
+        {postProcessProvider !== "none" && (
+          <div className="space-y-2">
+            <div>
+              <span className="text-sm font-medium">Prompts</span>
+              <p className="text-xs text-text/50">
+                Each dictation shortcut applies its own prompt to the text it dictates
+              </p>
+            </div>
+            {dictationBindings.map((binding) => (
+              <div key={binding.id} className="flex items-center justify-between">
+                <span className="text-sm">{binding.name}</span>
+                <Select
+                  value={binding.post_process_prompt_id ?? NO_PROMPT}
+                  onChange={(value) => {
+                    if (value) selectPrompt(binding.id, value === NO_PROMPT ? null : value);
+                  }}
+                  options={[
+                    { value: NO_PROMPT, label: "None (raw text)" },
+                    ...prompts.map((p) => ({ value: p.id, label: p.name })),
+                  ]}
+                  disabled={!postProcessEnabled}
+                />
+              </div>
+            ))}
+            {selectedPrompt && (
+              <>
+                <div className="flex items-center justify-between pt-2">
+                  <span className="text-sm font-medium">Edit template</span>
+                  <Select
+                    value={selectedPrompt.id}
+                    onChange={(value) => {
+                      if (value) setEditedPromptId(value);
+                    }}
+                    options={prompts.map((p) => ({ value: p.id, label: p.name }))}
+                    disabled={!postProcessEnabled}
+                  />
+                </div>
+                <textarea
+                  key={selectedPrompt.id}
+                  defaultValue={selectedPrompt.template}
+                  onBlur={(e) => {
+                    if (e.target.value !== selectedPrompt.template) {
+                      saveTemplate(selectedPrompt, e.target.value);
+                    }
+                  }}
+                  rows={4}
+                  className="w-full px-3 py-2 text-sm rounded-lg bg-mid-gray/10 border border-mid-gray/20 focus:border-logo-primary focus:outline-none"
+                  disabled={!postProcessEnabled}
+                />
+                <p className="text-xs text-text/50">
+                  Use {"${output}"} where the transcription should go
+                </p>
+              </>
+            )}
+          </div>
+        )}
+
         <div className="p-3 rounded-lg bg-mid-gray/5 text-xs text-text/60">
           <p className="font-medium mb-1">How it works:</p>
           <ul className="list-disc list-inside space-y-1">
@@ -79,6 +169,7 @@ export const PostProcessingSettings: React.FC = () => {
             <li>Adds punctuation, capitalization, and structure</li>
             <li>Baseten targets &lt;250ms latency (REQ-017)</li>
             <li>Can fix common speech-to-text errors</li>
+            <li>Falls back to the raw transcription on errors or timeouts</li>
           </ul>
         </div>
       </div>
@@ -15,7 +15,7 @@ export const VadThresholdSlider: React.FC<VadThresholdSliderProps> = ({
   grouped = false,
 }) => {
   const { getSetting, updateSetting } = useSettings();
-  const vadThreshold = (getSetting("vad_threshold") as number) ?? 0.5;
+  const vadThreshold = (getSetting("vad_threshold") as number) ?? 0.3;
 
   const getSensitivityLabel = (value: number): string => {
     if (value < 0.3) return "High";
@@ -10,10 +10,14 @@ import { commands } from "@/bindings";
 
 type OverlayState = "recording" | "transcribing";
 
+// Partial transcripts are shown from the end; the overlay only fits a few words
+const MAX_PARTIAL_CHARS = 28;
+
 const RecordingOverlay: React.FC = () => {
   const [isVisible, setIsVisible] = useState(false);
   const [state, setState] = useState<OverlayState>("recording");
   const [levels, setLevels] = useState<number[]>(Array(16).fill(0));
+  const [partial, setPartial] = useState("");
   const smoothedLevelsRef = useRef<number[]>(Array(16).fill(0));
 
   useEffect(() => {
@@ -22,6 +26,9 @@ const RecordingOverlay: React.FC = () => {
       const unlistenShow = await listen("show-overlay", async (event) => {
         const overlayState = event.payload as OverlayState;
         setState(overlayState);
+        if (overlayState === "recording") {
+          setPartial("");
+        }
         setIsVisible(true);
       });
 
@@ -44,11 +51,20 @@ const RecordingOverlay: React.FC = () => {
         setLevels(smoothed.slice(0, 9));
       });
 
+      // Listen for streaming transcription updates
+      const unlistenPartial = await listen<string>(
+        "partial-transcript",
+        (event) => {
+          setPartial(event.payload.trim());
+        },
+      );
+
       // Cleanup function
       return () => {
         unlistenShow();
         unlistenHide();
         unlistenLevel();
+        unlistenPartial();
       };
     };
 
@@ -68,7 +84,14 @@ const RecordingOverlay: React.FC = () => {
       <div className="overlay-left">{getIcon()}</div>
 
       <div className="overlay-middle">
-        {state === "recording" && (
+        {state === "recording" && partial && (
+          <div className="partial-text">
+            {partial.length > MAX_PARTIAL_CHARS
+              ? `…${partial.slice(-MAX_PARTIAL_CHARS)}`
+              : partial}
+          </div>
+        )}
+        {state === "recording" && !partial && (
           <div className="bars-container">
             {levels.map((v, i) => (
               <div
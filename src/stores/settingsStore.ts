@@ -7,6 +7,7 @@
 import { create } from "zustand";
 import { subscribeWithSelector } from "zustand/middleware";
 import { invoke } from "@tauri-apps/api/core";
+import { commands } from "../bindings";
 
 // Type definitions matching Rust backend (src-tauri/src/settings.rs)
 export type ModelUnloadTimeout =
@@ -27,14 +28,91 @@ export interface ShortcutBinding {
   description: string;
   default_binding: string;
   current_binding: string;
+  post_process_prompt_id: string | null;
 }
 
 export type OverlayPosition = "top" | "bottom" | "none";
 export type CloudSttProvider = "openai" | "elevenlabs";
+export type HistoryRetention = "never" | "days_7" | "days_30" | "days_90" | "forever";
+
+export interface PostProcessProvider {
+  id: string;
+  label: string;
+  base_url: string;
+  allow_base_url_edit: boolean;
+  models_endpoint: string | null;
+}
+
+export interface PostProcessPrompt {
+  id: string;
+  name: string;
+  template: string;
+  model: string | null;
+}
+
+export type ReplacementMatch = "exact" | "case_insensitive" | "regex";
+
+export interface TextReplacement {
+  pattern: string;
+  replacement: string;
+  match_type: ReplacementMatch;
+  whole_word: boolean;
+}
+
+export interface WhisperParams {
+  initial_prompt: string;
+  temperature: number;
+  beam_size: number;
+  best_of: number;
+  no_speech_threshold: number;
+  threads: number;
+  suppress_blank: boolean;
+}
+
+export interface EngineParams {
+  whisper: WhisperParams;
+}
+
+export interface OutputFilters {
+  remove_filler_words: boolean;
+  collapse_stutters: boolean;
+  detect_hallucinations: boolean;
+  extra_filler_words: string[];
+  extra_hallucinations: string[];
+}
+
+export type CommandAction =
+  | { type: "punctuation"; text: string }
+  | { type: "new_line" }
+  | { type: "new_paragraph" }
+  | { type: "open_quote" }
+  | { type: "close_quote" }
+  | { type: "bullet_point" }
+  | { type: "caps_on" }
+  | { type: "caps_off" };
+
+export interface SpokenCommand {
+  phrase: string;
+  action: CommandAction;
+}
+
+export interface CommandVocabulary {
+  escape_word: string;
+  commands: SpokenCommand[];
+}
+
+export interface SpokenCommands {
+  enabled: boolean;
+  vocabularies: Record<string, CommandVocabulary>;
+}
 
 export interface AppSettings {
   bindings: Record<string, ShortcutBinding>;
   push_to_talk: boolean;
+  max_recording_duration_secs: number;
+  auto_stop_on_silence: boolean;
+  auto_stop_silence_ms: number;
+  streaming_transcription: boolean;
   audio_feedback: boolean;
   audio_feedback_volume: number;
   sound_theme: SoundTheme;
@@ -49,6 +127,11 @@ export interface AppSettings {
   selected_language: string;
   custom_words: string[];
   word_correction_threshold: number;
+  output_filters: OutputFilters;
+  spoken_commands: SpokenCommands;
+  itn_languages: string[];
+  text_replacements: TextReplacement[];
+  engine_params: EngineParams;
   model_unload_timeout: ModelUnloadTimeout;
   mute_while_recording: boolean;
   overlay_position: OverlayPosition;
@@ -59,16 +142,29 @@ export interface AppSettings {
   cloud_stt_fallback_threshold: number;
   // VAD settings
   vad_threshold: number;
+  vad_prefill_frames: number;
+  vad_hangover_frames: number;
+  vad_onset_frames: number;
   // Post-processing settings
   post_process_enabled: boolean;
   post_process_api_key: string;
   post_process_provider: string;
+  post_process_providers: PostProcessProvider[];
+  post_process_models: Record<string, string>;
+  post_process_prompts: PostProcessPrompt[];
+  post_process_timeout_secs: number;
+  // Dictation history
+  history_retention: HistoryRetention;
+  save_recordings: boolean;
+  recordings_quota_mb: number;
   // Dictation text editor mode
   dictation_text_mode: "append" | "replace";
   // Theme mode
   theme_mode: "system" | "light" | "dark";
   // Log level
   log_level: "error" | "warn" | "info" | "debug" | "trace";
+  // Last settings migration applied
+  settings_version: number;
 }
 
 export interface AudioDevice {
@@ -126,6 +222,10 @@ const DEFAULT_AUDIO_DEVICE: AudioDevice = {
 // Voyc uses a generic update_setting command with key/value pairs
 const SETTING_KEYS_REQUIRING_BACKEND_UPDATE: (keyof AppSettings)[] = [
   "push_to_talk",
+  "max_recording_duration_secs",
+  "auto_stop_on_silence",
+  "auto_stop_silence_ms",
+  "streaming_transcription",
   "audio_feedback",
   "audio_feedback_volume",
   "start_hidden",
@@ -138,6 +238,21 @@ const SETTING_KEYS_REQUIRING_BACKEND_UPDATE: (keyof AppSettings)[] = [
   "theme_mode",
   "log_level",
   "sound_theme",
+  "vad_threshold",
+  "vad_prefill_frames",
+  "vad_hangover_frames",
+  "vad_onset_frames",
+  "post_process_enabled",
+  "post_process_provider",
+  "post_process_api_key",
+  "post_process_timeout_secs",
+  "save_recordings",
+  "recordings_quota_mb",
+  "engine_params",
+  "text_replacements",
+  "output_filters",
+  "itn_languages",
+  "spoken_commands",
 ];
 
 export const useSettingsStore = create<SettingsStore>()(
@@ -275,6 +390,9 @@ export const useSettingsStore = create<SettingsStore>()(
               ? "default"
               : (value as string);
           await invoke("set_selected_output_device", { deviceName });
+        } else if (key === "history_retention") {
+          const result = await commands.setHistoryRetention(value as HistoryRetention);
+          if (result.status === "error") throw new Error(result.error);
         } else if (SETTING_KEYS_REQUIRING_BACKEND_UPDATE.includes(key)) {
           // Use generic update_setting command for supported keys
           await invoke("update_setting", {